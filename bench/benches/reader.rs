@@ -103,5 +103,28 @@ fn bench_peek_eq(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_peek_eq, bench_consume, bench_read_num);
+fn bench_take_until(c: &mut Criterion) {
+    let mut buf = vec![b'a'; 64 * 1024];
+    *buf.last_mut().unwrap() = b'\n';
+
+    c.bench_function("take_until_u8_64k", |b| {
+        b.iter(|| {
+            input(black_box(&buf[..]))
+                .read_all(|r: &mut BytesReader<'_, Invalid>| {
+                    let line = r.take_until(b'\n')?;
+                    r.skip(1)?;
+                    Ok(line)
+                })
+                .unwrap();
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_peek_eq,
+    bench_consume,
+    bench_read_num,
+    bench_take_until
+);
 criterion_main!(benches);
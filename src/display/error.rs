@@ -14,9 +14,13 @@ note: error span is not within the error input indicating the
 /// Provides configurable [`error::Details`] formatting.
 #[derive(Clone)]
 #[must_use = "error displays must be written"]
+#[allow(clippy::struct_excessive_bools)]
 pub struct ErrorDisplay<'a, T> {
     error: &'a T,
     banner: bool,
+    description: bool,
+    additional: bool,
+    backtrace: bool,
     format: PreferredFormat,
     input_max_width: usize,
 }
@@ -36,6 +40,9 @@ where
             error,
             format,
             banner: false,
+            description: true,
+            additional: true,
+            backtrace: true,
             input_max_width: DEFAULT_MAX_WIDTH,
         }
     }
@@ -55,6 +62,25 @@ where
         self
     }
 
+    /// Set whether or not the `failed to ...` description line should be
+    /// printed.
+    pub fn description(mut self, value: bool) -> Self {
+        self.description = value;
+        self
+    }
+
+    /// Set whether or not the `additional:` section should be printed.
+    pub fn additional(mut self, value: bool) -> Self {
+        self.additional = value;
+        self
+    }
+
+    /// Set whether or not the `backtrace:` section should be printed.
+    pub fn backtrace(mut self, value: bool) -> Self {
+        self.backtrace = value;
+        self
+    }
+
     /// Set the `max-width` for wrapping error output.
     pub fn input_max_width(mut self, value: usize) -> Self {
         self.input_max_width = value;
@@ -81,17 +107,24 @@ where
         let input = self.error.input();
         let root = self.error.backtrace().root();
         // Write description
-        w.write_str("failed to ")?;
-        root.operation().description(w)?;
-        w.write_str(": ")?;
-        self.error.description(w)?;
-        w.write_char('\n')?;
+        if self.description {
+            w.write_str("failed to ")?;
+            root.operation().description(w)?;
+            w.write_str(": ")?;
+            self.error.description(w)?;
+            w.write_char('\n')?;
+        }
         // Write inputs
         let input_display = self.configure_input_display(input.display());
         let input = input.into_bytes();
         if let Some(expected_value) = self.error.expected() {
             let expected_display = self.configure_input_display(expected_value.display());
-            w.write_str("expected:\n")?;
+            w.write_str("expected")?;
+            if let Some(kind) = expected_value.set_kind() {
+                w.write_char(' ')?;
+                w.write_str(kind)?;
+            }
+            w.write_str(":\n")?;
             write_input(w, expected_display, false)?;
             w.write_str("in:\n")?;
         }
@@ -103,6 +136,18 @@ where
             write_input(w, input_display, false)?;
         }
         // Write additional
+        if self.additional {
+            self.write_additional(w, &input)?;
+        }
+        // Write context backtrace
+        if self.backtrace {
+            self.write_backtrace(w)?;
+        }
+        Ok(())
+    }
+
+    fn write_additional(&self, w: &mut dyn Write, input: &Bytes<'i>) -> fmt::Result {
+        let root = self.error.backtrace().root();
         w.write_str("additional:\n  ")?;
         if let Some(span_range) = root.span.range_of(input.span()) {
             if matches!(
@@ -110,7 +155,7 @@ where
                 PreferredFormat::Str | PreferredFormat::StrCjk | PreferredFormat::BytesAscii
             ) {
                 w.write_str("error line: ")?;
-                w.write_usize(line_offset(&input, span_range.start))?;
+                w.write_usize(line_offset(input, span_range.start))?;
                 w.write_str(", ")?;
             }
             w.write_str("error offset: ")?;
@@ -123,8 +168,10 @@ where
             w.write_str("input: ")?;
             DisplayBase::fmt(&input.span(), w)?;
         }
-        w.write_char('\n')?;
-        // Write context backtrace
+        w.write_char('\n')
+    }
+
+    fn write_backtrace(&self, w: &mut dyn Write) -> fmt::Result {
         w.write_str("backtrace:")?;
         let mut child_index = 1;
         let mut last_parent_depth = 0;
@@ -2,9 +2,11 @@ use crate::fmt::{self, Write};
 use crate::input::{Input, PrivateExt, Span};
 
 use super::section::{Section, SectionOpt};
-use super::unit::{byte_display_width, byte_display_write, char_display_width, char_display_write};
+use super::unit::{
+    byte_display_width, byte_display_write, char_display_width, char_display_write, UnitOpt,
+};
 
-const DEFAULT_SECTION_OPTION: SectionOpt = SectionOpt::HeadTail { width: 1024 };
+const DEFAULT_SECTION_OPTION: SectionOpt<'static> = SectionOpt::HeadTail { width: 1024 };
 
 /// Preferred [`Input`] formats.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -52,7 +54,9 @@ pub struct InputDisplay<'i> {
     underline: bool,
     format: PreferredFormat,
     section: Option<Section<'i>>,
-    section_opt: SectionOpt,
+    section_opt: SectionOpt<'i>,
+    unit_opt: UnitOpt,
+    hexdump: bool,
 }
 
 impl<'i> InputDisplay<'i> {
@@ -69,6 +73,8 @@ impl<'i> InputDisplay<'i> {
             underline: false,
             section: None,
             section_opt: DEFAULT_SECTION_OPTION,
+            unit_opt: UnitOpt::DEFAULT,
+            hexdump: false,
         }
     }
 
@@ -107,6 +113,69 @@ impl<'i> InputDisplay<'i> {
         self
     }
 
+    /// Expand tabs to `width` spaces when formatting as a UTF-8 `str`.
+    ///
+    /// By default tabs are escaped as `\t`. Expanded tabs are accounted for
+    /// when computing sections and underlines, so the underline stays aligned
+    /// with the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let formatted = dangerous::input("a\tb").display().str_hint().expand_tabs(4).to_string();
+    ///
+    /// assert_eq!(formatted, r#""a    b""#);
+    /// ```
+    pub fn expand_tabs(mut self, width: usize) -> Self {
+        self.section = None;
+        self.unit_opt.tab_width = Some(width);
+        self
+    }
+
+    /// Write bytes as uppercase hex.
+    ///
+    /// By default bytes are written as lowercase hex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let formatted = dangerous::input(&[0xAA, 0xBB]).display().hex_uppercase(true).to_string();
+    ///
+    /// assert_eq!(formatted, "[AA BB]");
+    /// ```
+    pub fn hex_uppercase(mut self, value: bool) -> Self {
+        self.unit_opt.hex_uppercase = value;
+        self
+    }
+
+    /// Set the separator written between bytes.
+    ///
+    /// By default bytes are separated by a space. The display width of the
+    /// separator is accounted for when computing sections.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let formatted = dangerous::input(&[0xAA, 0xBB, 0xCC])
+    ///     .display()
+    ///     .hex_uppercase(true)
+    ///     .separator(':')
+    ///     .to_string();
+    ///
+    /// assert_eq!(formatted, "[AA:BB:CC]");
+    /// ```
+    pub fn separator(mut self, separator: char) -> Self {
+        self.section = None;
+        self.unit_opt.byte_separator = separator;
+        self
+    }
+
     /// Show a `width` of [`Input`] at the head of the input and at the tail.
     ///
     /// # Example
@@ -122,6 +191,7 @@ impl<'i> InputDisplay<'i> {
     pub fn head_tail(mut self, width: usize) -> Self {
         self.section = None;
         self.section_opt = SectionOpt::HeadTail { width };
+        self.hexdump = false;
         self
     }
 
@@ -140,6 +210,7 @@ impl<'i> InputDisplay<'i> {
     pub fn head(mut self, width: usize) -> Self {
         self.section = None;
         self.section_opt = SectionOpt::Head { width };
+        self.hexdump = false;
         self
     }
 
@@ -158,11 +229,19 @@ impl<'i> InputDisplay<'i> {
     pub fn tail(mut self, width: usize) -> Self {
         self.section = None;
         self.section_opt = SectionOpt::Tail { width };
+        self.hexdump = false;
         self
     }
 
     /// Show a `width` of input [`Input`] targeting a span.
     ///
+    /// The span may come from anywhere, for example one stored from an
+    /// error's [`CoreContext`] or an AST, and is resolved against the input
+    /// being displayed. If the span is not within the input, nothing is
+    /// highlighted.
+    ///
+    /// [`CoreContext`]: crate::error::CoreContext
+    ///
     /// # Example
     ///
     /// ```
@@ -178,6 +257,34 @@ impl<'i> InputDisplay<'i> {
     pub fn span(mut self, span: Span, width: usize) -> Self {
         self.section = None;
         self.section_opt = SectionOpt::Span { width, span };
+        self.hexdump = false;
+        self
+    }
+
+    /// Show a `width` of [`Input`] targeting multiple spans.
+    ///
+    /// The section shown starts before the first span and covers as many of
+    /// the spans as fit within the width. Each span within the input is
+    /// highlighted when underlining, and spans not within the input are
+    /// ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let full = b"(hello world]";
+    /// let input = dangerous::input(full);
+    /// let spans = [full[..1].into(), full[12..].into()];
+    /// let display = input.display().str_hint().spans(&spans, 32);
+    ///
+    /// assert_eq!(display.to_string(), r#""(hello world]""#);
+    /// assert_eq!(display.underline().to_string(), " ^           ^ ");
+    /// ```
+    pub fn spans(mut self, spans: &'i [Span], width: usize) -> Self {
+        self.section = None;
+        self.section_opt = SectionOpt::Spans { width, spans };
+        self.hexdump = false;
         self
     }
 
@@ -196,19 +303,105 @@ impl<'i> InputDisplay<'i> {
     pub fn full(mut self) -> Self {
         self.section = None;
         self.section_opt = SectionOpt::Full;
+        self.hexdump = false;
+        self
+    }
+
+    /// Shows all of the elements in the [`Input`] as a multi-line hex dump.
+    ///
+    /// Each row is made up of the offset of the row, up to sixteen bytes in
+    /// hex and the same bytes as ASCII, with non-graphic characters written
+    /// as `.`. The display width is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let input = dangerous::input(b"hello\n");
+    /// let formatted = input.display().hexdump().to_string();
+    ///
+    /// assert_eq!(
+    ///     formatted,
+    ///     "00000000  68 65 6c 6c 6f 0a                                 |hello.|"
+    /// );
+    /// ```
+    pub fn hexdump(mut self) -> Self {
+        self.section = None;
+        self.section_opt = SectionOpt::Full;
+        self.hexdump = true;
         self
     }
 
     /// Compute the sections of input to display.
     pub fn prepare(mut self) -> Self {
-        let computed = self.section_opt.compute(self.input, self.format);
+        let computed = self
+            .section_opt
+            .compute(self.input, self.format, self.unit_opt);
         self.section = Some(computed);
         self
     }
+
+    /// Writes the formatted [`Input`] to a [`Write`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`core::fmt::Error`] if failed to write.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let mut formatted = String::new();
+    /// dangerous::input(b"hello").display().write_to(&mut formatted).unwrap();
+    ///
+    /// assert_eq!(formatted, "[68 65 6c 6c 6f]");
+    /// ```
+    pub fn write_to(&self, w: &mut dyn Write) -> fmt::Result {
+        fmt::DisplayBase::fmt(self, w)
+    }
+
+    /// Writes the formatted [`Input`] to an [`io::Write`] without an
+    /// intermediate `String`.
+    ///
+    /// [`io::Write`]: std::io::Write
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the writer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let mut formatted = Vec::new();
+    /// dangerous::input(b"hello").display().write_io(&mut formatted).unwrap();
+    ///
+    /// assert_eq!(formatted, b"[68 65 6c 6c 6f]");
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn write_io(&self, w: &mut dyn std::io::Write) -> std::io::Result<()> {
+        let mut adapter = IoWrite {
+            inner: w,
+            error: None,
+        };
+        match self.write_to(&mut adapter) {
+            Ok(()) => Ok(()),
+            Err(_) => Err(adapter.error.unwrap_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::Other, "formatter error")
+            })),
+        }
+    }
 }
 
 impl<'i> fmt::DisplayBase for InputDisplay<'i> {
     fn fmt(&self, w: &mut dyn Write) -> fmt::Result {
+        if self.hexdump {
+            return write_hexdump(w, self.input, self.unit_opt.hex_uppercase);
+        }
         match &self.section {
             None => self.clone().prepare().fmt(w),
             Some(section) => section.write(w, self.underline),
@@ -216,6 +409,48 @@ impl<'i> fmt::DisplayBase for InputDisplay<'i> {
     }
 }
 
+const HEXDUMP_ROW_LEN: usize = 16;
+
+fn write_hexdump(w: &mut dyn Write, input: &[u8], hex_uppercase: bool) -> fmt::Result {
+    for (i, row) in input.chunks(HEXDUMP_ROW_LEN).enumerate() {
+        if i != 0 {
+            w.write_char('\n')?;
+        }
+        let offset = (i * HEXDUMP_ROW_LEN) as u64;
+        let offset_bytes = offset.to_be_bytes();
+        // Offsets are written with at least eight hex digits.
+        let offset_bytes = if offset > u64::from(u32::MAX) {
+            &offset_bytes[..]
+        } else {
+            &offset_bytes[4..]
+        };
+        for b in offset_bytes.iter().copied() {
+            byte_display_write(b, false, hex_uppercase, w)?;
+        }
+        w.write_char(' ')?;
+        for j in 0..HEXDUMP_ROW_LEN {
+            if j % 8 == 0 {
+                w.write_char(' ')?;
+            }
+            match row.get(j) {
+                Some(b) => byte_display_write(*b, false, hex_uppercase, w)?,
+                None => w.write_str("  ")?,
+            }
+            w.write_char(' ')?;
+        }
+        w.write_str(" |")?;
+        for b in row.iter().copied() {
+            if b.is_ascii_graphic() || b == b' ' {
+                w.write_char(b as char)?;
+            } else {
+                w.write_char('.')?;
+            }
+        }
+        w.write_char('|')?;
+    }
+    Ok(())
+}
+
 impl<'i> fmt::Debug for InputDisplay<'i> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::DisplayBase::fmt(self, f)
@@ -234,21 +469,24 @@ pub(super) struct InputWriter<'a> {
     w: &'a mut dyn Write,
     underline: bool,
     full: &'a [u8],
-    span: Option<Span>,
+    spans: &'a [Span],
+    opt: UnitOpt,
 }
 
 impl<'a> InputWriter<'a> {
     pub(super) fn new(
         w: &'a mut dyn Write,
         full: &'a [u8],
-        span: Option<Span>,
+        spans: &'a [Span],
         underline: bool,
+        opt: UnitOpt,
     ) -> Self {
         Self {
             w,
             underline,
             full,
-            span,
+            spans,
+            opt,
         }
     }
 
@@ -270,7 +508,7 @@ impl<'a> InputWriter<'a> {
         self.write_bytes_open(left)?;
         self.write_bytes(left, show_ascii)?;
         self.write_space(1)?;
-        self.write_more(is_span_overlapping_end(left, self.span))?;
+        self.write_more(is_span_overlapping_end(left, self.spans))?;
         self.write_space(1)?;
         self.write_bytes(right, show_ascii)?;
         self.write_bytes_close(right)
@@ -279,20 +517,20 @@ impl<'a> InputWriter<'a> {
     fn write_bytes_open(&mut self, bytes: &[u8]) -> fmt::Result {
         if has_more_before(bytes, self.full) {
             self.write_delim('[', false)?;
-            self.write_more(is_span_overlapping_start(bytes, self.span))?;
+            self.write_more(is_span_overlapping_start(bytes, self.spans))?;
             self.write_space(1)
         } else {
-            self.write_delim('[', is_span_pointing_to_start(bytes, self.span))
+            self.write_delim('[', is_span_pointing_to_start(bytes, self.spans))
         }
     }
 
     fn write_bytes_close(&mut self, bytes: &[u8]) -> fmt::Result {
         if has_more_after(bytes, self.full) {
             self.write_space(1)?;
-            self.write_more(is_span_overlapping_end(bytes, self.span))?;
+            self.write_more(is_span_overlapping_end(bytes, self.spans))?;
             self.write_delim(']', false)
         } else {
-            self.write_delim(']', is_span_pointing_to_end(bytes, self.span))
+            self.write_delim(']', is_span_pointing_to_end(bytes, self.spans))
         }
     }
 
@@ -302,7 +540,7 @@ impl<'a> InputWriter<'a> {
             self.write_byte(byte, bytes, show_ascii)?;
         }
         for (i, byte) in (1..bytes.len()).zip(iter) {
-            self.write_space(1)?;
+            self.write_separator()?;
             self.write_byte(byte, &bytes[i..], show_ascii)?;
         }
         Ok(())
@@ -311,13 +549,13 @@ impl<'a> InputWriter<'a> {
     fn write_byte(&mut self, byte: u8, remaining: &[u8], show_ascii: bool) -> fmt::Result {
         if self.underline {
             let byte_display_width = byte_display_width(byte, show_ascii);
-            if is_section_start_span_or_within(remaining, self.span) {
+            if is_section_start_span_or_within(remaining, self.spans) {
                 self.write_underline(byte_display_width)
             } else {
                 self.write_space(byte_display_width)
             }
         } else {
-            byte_display_write(byte, show_ascii, self.w)
+            byte_display_write(byte, show_ascii, self.opt.hex_uppercase, self.w)
         }
     }
 
@@ -335,7 +573,7 @@ impl<'a> InputWriter<'a> {
         self.write_str(left, cjk)?;
         self.write_delim('"', false)?;
         self.write_space(1)?;
-        self.write_more(is_span_overlapping_end(left.as_bytes(), self.span))?;
+        self.write_more(is_span_overlapping_end(left.as_bytes(), self.spans))?;
         self.write_space(1)?;
         self.write_delim('"', false)?;
         self.write_str(right, cjk)?;
@@ -345,11 +583,11 @@ impl<'a> InputWriter<'a> {
     fn write_str_open(&mut self, s: &str) -> fmt::Result {
         let bytes = s.as_bytes();
         if has_more_before(bytes, self.full) {
-            self.write_more(is_span_overlapping_start(bytes, self.span))?;
+            self.write_more(is_span_overlapping_start(bytes, self.spans))?;
             self.write_space(1)?;
             self.write_delim('"', false)
         } else {
-            self.write_delim('"', is_span_pointing_to_start(bytes, self.span))
+            self.write_delim('"', is_span_pointing_to_start(bytes, self.spans))
         }
     }
 
@@ -358,20 +596,20 @@ impl<'a> InputWriter<'a> {
         if has_more_after(bytes, self.full) {
             self.write_delim('"', false)?;
             self.write_space(1)?;
-            self.write_more(is_span_overlapping_end(bytes, self.span))
+            self.write_more(is_span_overlapping_end(bytes, self.spans))
         } else {
-            self.write_delim('"', is_span_pointing_to_end(bytes, self.span))
+            self.write_delim('"', is_span_pointing_to_end(bytes, self.spans))
         }
     }
 
     fn write_str(&mut self, s: &str, cjk: bool) -> fmt::Result {
         let bytes = s.as_bytes();
         if self.underline {
-            if is_span_start_within_section(bytes, self.span) {
+            if is_span_start_within_section(bytes, self.spans) {
                 let mut offset = 0;
                 for c in s.chars() {
-                    let char_display_width = char_display_width(c, cjk);
-                    if is_section_start_span_or_within(&bytes[offset..], self.span) {
+                    let char_display_width = char_display_width(c, cjk, self.opt.tab_width);
+                    if is_section_start_span_or_within(&bytes[offset..], self.spans) {
                         self.write_underline(char_display_width)?;
                     } else {
                         self.write_space(char_display_width)?;
@@ -380,12 +618,12 @@ impl<'a> InputWriter<'a> {
                 }
             } else {
                 for c in s.chars() {
-                    self.write_space(char_display_width(c, cjk))?;
+                    self.write_space(char_display_width(c, cjk, self.opt.tab_width))?;
                 }
             }
         } else {
             for c in s.chars() {
-                char_display_write(c, self.w)?;
+                char_display_write(c, self.opt.tab_width, self.w)?;
             }
         }
         Ok(())
@@ -418,6 +656,14 @@ impl<'a> InputWriter<'a> {
         }
     }
 
+    fn write_separator(&mut self) -> fmt::Result {
+        if self.underline {
+            self.write_space(self.opt.byte_separator_cost())
+        } else {
+            self.w.write_char(self.opt.byte_separator)
+        }
+    }
+
     fn write_space(&mut self, len: usize) -> fmt::Result {
         self.write_char_len(' ', len)
     }
@@ -442,29 +688,61 @@ fn has_more_after(bytes: &[u8], full: &[u8]) -> bool {
     Span::from(full).is_overlapping_end_of(bytes.into())
 }
 
-fn is_span_start_within_section(bytes: &[u8], span: Option<Span>) -> bool {
-    span.map_or(false, |span| span.is_start_within(bytes.into()))
+fn is_span_start_within_section(bytes: &[u8], spans: &[Span]) -> bool {
+    spans.iter().any(|span| span.is_start_within(bytes.into()))
 }
 
-fn is_section_start_span_or_within(bytes: &[u8], span: Option<Span>) -> bool {
-    span.map_or(false, |span| {
-        let parent = Span::from(bytes);
-        span.is_start_of(parent) || parent.is_start_within(span)
-    })
+fn is_section_start_span_or_within(bytes: &[u8], spans: &[Span]) -> bool {
+    let parent = Span::from(bytes);
+    spans
+        .iter()
+        .any(|span| span.is_start_of(parent) || parent.is_start_within(*span))
 }
 
-fn is_span_overlapping_end(bytes: &[u8], span: Option<Span>) -> bool {
-    span.map_or(false, |span| span.is_overlapping_end_of(bytes.into()))
+fn is_span_overlapping_end(bytes: &[u8], spans: &[Span]) -> bool {
+    spans
+        .iter()
+        .any(|span| span.is_overlapping_end_of(bytes.into()))
 }
 
-fn is_span_overlapping_start(bytes: &[u8], span: Option<Span>) -> bool {
-    span.map_or(false, |span| span.is_overlapping_start_of(bytes.into()))
+fn is_span_overlapping_start(bytes: &[u8], spans: &[Span]) -> bool {
+    spans
+        .iter()
+        .any(|span| span.is_overlapping_start_of(bytes.into()))
 }
 
-fn is_span_pointing_to_start(bytes: &[u8], span: Option<Span>) -> bool {
-    span.map_or(false, |span| span.is_start_of(bytes.into()))
+fn is_span_pointing_to_start(bytes: &[u8], spans: &[Span]) -> bool {
+    spans.iter().any(|span| span.is_start_of(bytes.into()))
 }
 
-fn is_span_pointing_to_end(bytes: &[u8], span: Option<Span>) -> bool {
-    span.map_or(false, |span| span.is_end_of(bytes.into()))
+fn is_span_pointing_to_end(bytes: &[u8], spans: &[Span]) -> bool {
+    spans.iter().any(|span| span.is_end_of(bytes.into()))
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+/// Adapts an [`io::Write`](std::io::Write) to a [`Write`], keeping the first
+/// I/O error as [`fmt::Error`] carries none.
+#[cfg(feature = "std")]
+struct IoWrite<'a> {
+    inner: &'a mut dyn std::io::Write,
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl Write for IoWrite<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.write_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    fn write_usize(&mut self, v: usize) -> fmt::Result {
+        self.write_u64(v as u64)
+    }
 }
@@ -60,6 +60,64 @@ pub trait Write {
     /// Returns a [`core::fmt::Error`] if failed to write.
     fn write_usize(&mut self, v: usize) -> Result;
 
+    /// Writes an isize into this writer, returning whether the write succeeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`core::fmt::Error`] if failed to write.
+    fn write_isize(&mut self, v: isize) -> Result {
+        if v < 0 {
+            self.write_char('-')?;
+        }
+        self.write_usize(v.unsigned_abs())
+    }
+
+    /// Writes a u64 into this writer, returning whether the write succeeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`core::fmt::Error`] if failed to write.
+    fn write_u64(&mut self, v: u64) -> Result {
+        self.write_num(v, 10)
+    }
+
+    /// Writes a u64 in the provided radix into this writer, returning whether
+    /// the write succeeded.
+    ///
+    /// Digits above 9 are written as lowercase letters, without any prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`core::fmt::Error`] if failed to write or the radix is not
+    /// within `2..=36`.
+    fn write_num(&mut self, v: u64, radix: u32) -> Result {
+        if !(2..=36).contains(&radix) {
+            return Err(core::fmt::Error);
+        }
+        // Enough digits for a u64 in base 2.
+        let mut buf = [0_u8; 64];
+        let mut start = buf.len();
+        let mut v = v;
+        loop {
+            start -= 1;
+            // Won't truncate as the remainder is within the radix.
+            #[allow(clippy::cast_possible_truncation)]
+            let d = (v % u64::from(radix)) as u32;
+            buf[start] = match char::from_digit(d, radix) {
+                Some(c) => c as u8,
+                None => return Err(core::fmt::Error),
+            };
+            v /= u64::from(radix);
+            if v == 0 {
+                break;
+            }
+        }
+        for &b in &buf[start..] {
+            self.write_char(b as char)?;
+        }
+        Ok(())
+    }
+
     /// Writes a byte as hex into this writer, returning whether the write
     /// succeeded.
     ///
@@ -97,6 +155,18 @@ where
         (**self).write_usize(v)
     }
 
+    fn write_isize(&mut self, v: isize) -> Result {
+        (**self).write_isize(v)
+    }
+
+    fn write_u64(&mut self, v: u64) -> Result {
+        (**self).write_u64(v)
+    }
+
+    fn write_num(&mut self, v: u64, radix: u32) -> Result {
+        (**self).write_num(v, radix)
+    }
+
     fn write_hex(&mut self, b: u8) -> Result {
         (**self).write_hex(b)
     }
@@ -114,6 +184,31 @@ impl<'a> Write for Formatter<'a> {
     fn write_usize(&mut self, v: usize) -> Result {
         core::fmt::Display::fmt(&v, self)
     }
+
+    fn write_isize(&mut self, v: isize) -> Result {
+        core::fmt::Display::fmt(&v, self)
+    }
+
+    fn write_u64(&mut self, v: u64) -> Result {
+        core::fmt::Display::fmt(&v, self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Write for alloc::string::String {
+    fn write_str(&mut self, s: &str) -> Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> Result {
+        self.push(c);
+        Ok(())
+    }
+
+    fn write_usize(&mut self, v: usize) -> Result {
+        core::fmt::Write::write_fmt(self, format_args!("{}", v))
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
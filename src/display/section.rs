@@ -4,15 +4,16 @@
 // | tail      | `.. "a"`     | `[.. 97]`    | `[.. 'a']`     |
 // | head-tail | `"a" .. "a"` | `[97 .. 97]` | `['a' .. 'a']` |
 // | span      | `.. "a" ..`  | `[.. 97 ..]` | `[.. 'a' ..]`  |
+// | spans     | `.. "a" ..`  | `[.. 97 ..]` | `[.. 'a' ..]`  |
 
-use core::str;
+use core::{slice, str};
 
 use crate::fmt::{self, Write};
 use crate::input::Span;
 use crate::util::utf8;
 
 use super::input::{InputWriter, PreferredFormat};
-use super::unit::UnitIter;
+use super::unit::{UnitIter, UnitOpt};
 
 const MIN_WIDTH: usize = 16;
 const SPACE_COST: usize = 1;
@@ -22,22 +23,29 @@ const HEAD_TAIL_HAS_MORE_COST: usize = SIDE_HAS_MORE_COST;
 const STR_HEAD_TAIL_HAS_MORE_COST: usize = SIDE_HAS_MORE_COST + DELIM_PAIR_COST + SPACE_COST;
 
 #[derive(Copy, Clone)]
-pub(super) enum SectionOpt {
+pub(super) enum SectionOpt<'a> {
     Full,
     Head { width: usize },
     Tail { width: usize },
     HeadTail { width: usize },
     Span { width: usize, span: Span },
+    Spans { width: usize, spans: &'a [Span] },
 }
 
-impl SectionOpt {
-    pub(super) fn compute(self, input: &[u8], format: PreferredFormat) -> Section<'_> {
+impl<'a> SectionOpt<'a> {
+    pub(super) fn compute(
+        self,
+        input: &'a [u8],
+        format: PreferredFormat,
+        opt: UnitOpt,
+    ) -> Section<'a> {
         match self {
-            Self::Full => Section::from_full(input, format),
-            Self::Head { width } => Section::from_head(input, width, format),
-            Self::Tail { width } => Section::from_tail(input, width, format),
-            Self::HeadTail { width } => Section::from_head_tail(input, width, format),
-            Self::Span { width, span } => Section::from_span(input, span, width, format),
+            Self::Full => Section::from_full(input, format, opt),
+            Self::Head { width } => Section::from_head(input, width, format, opt),
+            Self::Tail { width } => Section::from_tail(input, width, format, opt),
+            Self::HeadTail { width } => Section::from_head_tail(input, width, format, opt),
+            Self::Span { width, span } => Section::from_span(input, span, width, format, opt),
+            Self::Spans { width, spans } => Section::from_spans(input, spans, width, format, opt),
         }
     }
 }
@@ -63,15 +71,33 @@ enum Visible<'a> {
     BytesAsciiPair(&'a [u8], &'a [u8]),
 }
 
+#[derive(Copy, Clone)]
+enum Highlight<'a> {
+    None,
+    Span(Span),
+    Spans(&'a [Span]),
+}
+
+impl Highlight<'_> {
+    fn as_slice(&self) -> &[Span] {
+        match self {
+            Self::None => &[],
+            Self::Span(span) => slice::from_ref(span),
+            Self::Spans(spans) => spans,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(super) struct Section<'a> {
     full: &'a [u8],
     visible: Visible<'a>,
-    span: Option<Span>,
+    highlight: Highlight<'a>,
+    opt: UnitOpt,
 }
 
 impl<'a> Section<'a> {
-    pub(super) fn from_full(full: &'a [u8], format: PreferredFormat) -> Self {
+    pub(super) fn from_full(full: &'a [u8], format: PreferredFormat, opt: UnitOpt) -> Self {
         let visible = match format {
             PreferredFormat::Bytes => Visible::Bytes(full),
             PreferredFormat::BytesAscii => Visible::BytesAscii(full),
@@ -93,91 +119,155 @@ impl<'a> Section<'a> {
         Self {
             full,
             visible,
-            span: None,
+            highlight: Highlight::None,
+            opt,
         }
     }
 
-    pub(super) fn from_head(full: &'a [u8], width: usize, format: PreferredFormat) -> Self {
+    pub(super) fn from_head(
+        full: &'a [u8],
+        width: usize,
+        format: PreferredFormat,
+        opt: UnitOpt,
+    ) -> Self {
         let width = init_width(width);
         let visible = match format {
-            PreferredFormat::Bytes => take_bytes_head(full, width, false),
-            PreferredFormat::BytesAscii => take_bytes_head(full, width, true),
-            PreferredFormat::Str => take_str_head(full, width, false),
-            PreferredFormat::StrCjk => take_str_head(full, width, true),
+            PreferredFormat::Bytes => take_bytes_head(full, width, false, opt),
+            PreferredFormat::BytesAscii => take_bytes_head(full, width, true, opt),
+            PreferredFormat::Str => take_str_head(full, width, false, opt),
+            PreferredFormat::StrCjk => take_str_head(full, width, true, opt),
         };
         Self {
             full,
             visible,
-            span: None,
+            highlight: Highlight::None,
+            opt,
         }
     }
 
-    pub(super) fn from_tail(full: &'a [u8], width: usize, format: PreferredFormat) -> Self {
+    pub(super) fn from_tail(
+        full: &'a [u8],
+        width: usize,
+        format: PreferredFormat,
+        opt: UnitOpt,
+    ) -> Self {
         let width = init_width(width);
         let visible = match format {
-            PreferredFormat::Bytes => take_bytes_tail(full, width, false),
-            PreferredFormat::BytesAscii => take_bytes_tail(full, width, true),
-            PreferredFormat::Str => take_str_tail(full, width, false),
-            PreferredFormat::StrCjk => take_str_tail(full, width, true),
+            PreferredFormat::Bytes => take_bytes_tail(full, width, false, opt),
+            PreferredFormat::BytesAscii => take_bytes_tail(full, width, true, opt),
+            PreferredFormat::Str => take_str_tail(full, width, false, opt),
+            PreferredFormat::StrCjk => take_str_tail(full, width, true, opt),
         };
         Self {
             full,
             visible,
-            span: None,
+            highlight: Highlight::None,
+            opt,
         }
     }
 
-    pub(super) fn from_head_tail(full: &'a [u8], width: usize, format: PreferredFormat) -> Self {
+    pub(super) fn from_head_tail(
+        full: &'a [u8],
+        width: usize,
+        format: PreferredFormat,
+        opt: UnitOpt,
+    ) -> Self {
         let width = init_width(width);
         let visible = match format {
-            PreferredFormat::Bytes => take_bytes_head_tail(full, width, false),
-            PreferredFormat::BytesAscii => take_bytes_head_tail(full, width, true),
-            PreferredFormat::Str => take_str_head_tail(full, width, false),
-            PreferredFormat::StrCjk => take_str_head_tail(full, width, true),
+            PreferredFormat::Bytes => take_bytes_head_tail(full, width, false, opt),
+            PreferredFormat::BytesAscii => take_bytes_head_tail(full, width, true, opt),
+            PreferredFormat::Str => take_str_head_tail(full, width, false, opt),
+            PreferredFormat::StrCjk => take_str_head_tail(full, width, true, opt),
         };
         Self {
             full,
             visible,
-            span: None,
+            highlight: Highlight::None,
+            opt,
         }
     }
 
     pub(super) fn from_span(
+        full: &'a [u8],
+        span: Span,
+        width: usize,
+        format: PreferredFormat,
+        opt: UnitOpt,
+    ) -> Self {
+        Self::from_span_to(full, span, None, width, format, opt)
+    }
+
+    pub(super) fn from_spans(
+        full: &'a [u8],
+        spans: &'a [Span],
+        width: usize,
+        format: PreferredFormat,
+        opt: UnitOpt,
+    ) -> Self {
+        // Target the section at the span covering all of the spans within the
+        // input.
+        let mut covering: Option<(usize, usize)> = None;
+        for span in spans {
+            if let Some(range) = span.range_of(full.into()) {
+                covering = Some(match covering {
+                    Some((start, end)) => (start.min(range.start), end.max(range.end)),
+                    None => (range.start, range.end),
+                });
+            }
+        }
+        match covering {
+            Some((start, end)) => {
+                let covering = Span::from(&full[start..end]);
+                let mut section = Self::from_span_to(full, covering, Some(end), width, format, opt);
+                section.highlight = Highlight::Spans(spans);
+                section
+            }
+            None => Self::from_head_tail(full, width, format, opt),
+        }
+    }
+
+    /// If `cover_end` is provided, the section will attempt to include the
+    /// input from the span up to the end offset.
+    fn from_span_to(
         full: &'a [u8],
         mut span: Span,
+        cover_end: Option<usize>,
         width: usize,
         format: PreferredFormat,
+        opt: UnitOpt,
     ) -> Self {
         let span_offset = if let Some(span_range) = span.range_of(full.into()) {
             span_range.start
         } else {
-            return Self::from_head_tail(full, width, format);
+            return Self::from_head_tail(full, width, format, opt);
         };
         let width = init_width(width);
         if span.is_empty() {
             if span.is_start_of(full.into()) {
                 let visible = match format {
-                    PreferredFormat::Bytes => take_bytes_head(full, width, false),
-                    PreferredFormat::BytesAscii => take_bytes_head(full, width, true),
-                    PreferredFormat::Str => take_str_head(full, width, false),
-                    PreferredFormat::StrCjk => take_str_head(full, width, true),
+                    PreferredFormat::Bytes => take_bytes_head(full, width, false, opt),
+                    PreferredFormat::BytesAscii => take_bytes_head(full, width, true, opt),
+                    PreferredFormat::Str => take_str_head(full, width, false, opt),
+                    PreferredFormat::StrCjk => take_str_head(full, width, true, opt),
                 };
                 return Self {
                     full,
                     visible,
-                    span: Some(span),
+                    highlight: Highlight::Span(span),
+                    opt,
                 };
             } else if span.is_end_of(full.into()) {
                 let visible = match format {
-                    PreferredFormat::Bytes => take_bytes_tail(full, width, false),
-                    PreferredFormat::BytesAscii => take_bytes_tail(full, width, true),
-                    PreferredFormat::Str => take_str_tail(full, width, false),
-                    PreferredFormat::StrCjk => take_str_tail(full, width, true),
+                    PreferredFormat::Bytes => take_bytes_tail(full, width, false, opt),
+                    PreferredFormat::BytesAscii => take_bytes_tail(full, width, true, opt),
+                    PreferredFormat::Str => take_str_tail(full, width, false, opt),
+                    PreferredFormat::StrCjk => take_str_tail(full, width, true, opt),
                 };
                 return Self {
                     full,
                     visible,
-                    span: Some(span),
+                    highlight: Highlight::Span(span),
+                    opt,
                 };
             }
             span = span.start();
@@ -194,21 +284,24 @@ impl<'a> Section<'a> {
             }
             _ => format,
         };
+        let span_range = (span_offset, cover_end);
         let visible = match format {
-            PreferredFormat::Bytes => take_bytes_span(full, span_offset, width, false),
-            PreferredFormat::BytesAscii => take_bytes_span(full, span_offset, width, true),
-            PreferredFormat::Str => take_str_span(full, span_offset, width, false),
-            PreferredFormat::StrCjk => take_str_span(full, span_offset, width, true),
+            PreferredFormat::Bytes => take_bytes_span(full, span_range, width, false, opt),
+            PreferredFormat::BytesAscii => take_bytes_span(full, span_range, width, true, opt),
+            PreferredFormat::Str => take_str_span(full, span_range, width, false, opt),
+            PreferredFormat::StrCjk => take_str_span(full, span_range, width, true, opt),
         };
         Self {
             full,
             visible,
-            span: Some(span),
+            highlight: Highlight::Span(span),
+            opt,
         }
     }
 
     pub(super) fn write(&self, w: &mut dyn Write, underline: bool) -> fmt::Result {
-        let mut writer = InputWriter::new(w, self.full, self.span, underline);
+        let spans = self.highlight.as_slice();
+        let mut writer = InputWriter::new(w, self.full, spans, underline, self.opt);
         match self.visible {
             Visible::Bytes(bytes) => writer.write_bytes_side(bytes, false),
             Visible::BytesAscii(bytes) => writer.write_bytes_side(bytes, true),
@@ -231,9 +324,17 @@ fn init_width(width: usize) -> usize {
     }
 }
 
-fn take_str_span(bytes: &[u8], span_offset: usize, width: usize, cjk: bool) -> Visible<'_> {
-    let iter = UnitIter::new_char(bytes, cjk);
-    if let Ok((start, end)) = take_span(iter, span_offset, width, false) {
+type SpanRange = (usize, Option<usize>);
+
+fn take_str_span(
+    bytes: &[u8],
+    span_range: SpanRange,
+    width: usize,
+    cjk: bool,
+    opt: UnitOpt,
+) -> Visible<'_> {
+    let iter = UnitIter::new_char(bytes, cjk, opt.tab_width);
+    if let Ok((start, end)) = take_span(iter, span_range, width, 0) {
         // SAFETY: all chars are checked from the char iterator
         let s = unsafe { utf8::from_unchecked(&bytes[start..end]) };
         if cjk {
@@ -242,18 +343,19 @@ fn take_str_span(bytes: &[u8], span_offset: usize, width: usize, cjk: bool) -> V
             Visible::Str(s)
         }
     } else {
-        take_bytes_span(bytes, span_offset, width, true)
+        take_bytes_span(bytes, span_range, width, true, opt)
     }
 }
 
 fn take_bytes_span(
     bytes: &[u8],
-    span_offset: usize,
+    span_range: SpanRange,
     width: usize,
     show_ascii: bool,
+    opt: UnitOpt,
 ) -> Visible<'_> {
     let iter = UnitIter::new_byte(bytes, show_ascii);
-    let (start, end) = take_span(iter, span_offset, width, true).unwrap();
+    let (start, end) = take_span(iter, span_range, width, opt.byte_separator_cost()).unwrap();
     if show_ascii {
         Visible::BytesAscii(&bytes[start..end])
     } else {
@@ -261,9 +363,9 @@ fn take_bytes_span(
     }
 }
 
-fn take_str_head(bytes: &[u8], width: usize, cjk: bool) -> Visible<'_> {
-    let iter = UnitIter::new_char(bytes, cjk);
-    if let Ok((len, _)) = take_head(iter, width, false) {
+fn take_str_head(bytes: &[u8], width: usize, cjk: bool, opt: UnitOpt) -> Visible<'_> {
+    let iter = UnitIter::new_char(bytes, cjk, opt.tab_width);
+    if let Ok((len, _)) = take_head(iter, width, 0) {
         // SAFETY: all chars are checked from the char iterator
         let s = unsafe { utf8::from_unchecked(&bytes[..len]) };
         if cjk {
@@ -272,13 +374,13 @@ fn take_str_head(bytes: &[u8], width: usize, cjk: bool) -> Visible<'_> {
             Visible::Str(s)
         }
     } else {
-        take_bytes_head(bytes, width, true)
+        take_bytes_head(bytes, width, true, opt)
     }
 }
 
-fn take_bytes_head(bytes: &[u8], width: usize, show_ascii: bool) -> Visible<'_> {
+fn take_bytes_head(bytes: &[u8], width: usize, show_ascii: bool, opt: UnitOpt) -> Visible<'_> {
     let iter = UnitIter::new_byte(bytes, show_ascii);
-    let (len, _) = take_head(iter, width, true).unwrap();
+    let (len, _) = take_head(iter, width, opt.byte_separator_cost()).unwrap();
     if show_ascii {
         Visible::BytesAscii(&bytes[..len])
     } else {
@@ -286,9 +388,9 @@ fn take_bytes_head(bytes: &[u8], width: usize, show_ascii: bool) -> Visible<'_>
     }
 }
 
-fn take_str_tail(bytes: &[u8], width: usize, cjk: bool) -> Visible<'_> {
-    let iter = UnitIter::new_char(bytes, cjk);
-    if let Ok((len, _)) = take_tail(iter, width, false) {
+fn take_str_tail(bytes: &[u8], width: usize, cjk: bool, opt: UnitOpt) -> Visible<'_> {
+    let iter = UnitIter::new_char(bytes, cjk, opt.tab_width);
+    if let Ok((len, _)) = take_tail(iter, width, 0) {
         let offset = bytes.len() - len;
         // SAFETY: all chars are checked from the char iterator
         let s = unsafe { utf8::from_unchecked(&bytes[offset..]) };
@@ -298,13 +400,13 @@ fn take_str_tail(bytes: &[u8], width: usize, cjk: bool) -> Visible<'_> {
             Visible::Str(s)
         }
     } else {
-        take_bytes_tail(bytes, width, true)
+        take_bytes_tail(bytes, width, true, opt)
     }
 }
 
-fn take_bytes_tail(bytes: &[u8], width: usize, show_ascii: bool) -> Visible<'_> {
+fn take_bytes_tail(bytes: &[u8], width: usize, show_ascii: bool, opt: UnitOpt) -> Visible<'_> {
     let iter = UnitIter::new_byte(bytes, show_ascii);
-    let (len, _) = take_tail(iter, width, true).unwrap();
+    let (len, _) = take_tail(iter, width, opt.byte_separator_cost()).unwrap();
     let offset = bytes.len() - len;
     if show_ascii {
         Visible::BytesAscii(&bytes[offset..])
@@ -313,9 +415,9 @@ fn take_bytes_tail(bytes: &[u8], width: usize, show_ascii: bool) -> Visible<'_>
     }
 }
 
-fn take_str_head_tail(bytes: &[u8], width: usize, cjk: bool) -> Visible<'_> {
-    let iter = UnitIter::new_char(bytes, cjk);
-    if let Ok((start, end)) = take_head_tail(iter, width, false, STR_HEAD_TAIL_HAS_MORE_COST) {
+fn take_str_head_tail(bytes: &[u8], width: usize, cjk: bool, opt: UnitOpt) -> Visible<'_> {
+    let iter = UnitIter::new_char(bytes, cjk, opt.tab_width);
+    if let Ok((start, end)) = take_head_tail(iter, width, 0, STR_HEAD_TAIL_HAS_MORE_COST) {
         // SAFETY: all chars are checked from the char iterator
         unsafe {
             if start == end {
@@ -333,12 +435,18 @@ fn take_str_head_tail(bytes: &[u8], width: usize, cjk: bool) -> Visible<'_> {
             return Visible::StrPair(left, right);
         }
     }
-    take_bytes_head_tail(bytes, width, true)
+    take_bytes_head_tail(bytes, width, true, opt)
 }
 
-fn take_bytes_head_tail(bytes: &[u8], width: usize, show_ascii: bool) -> Visible<'_> {
+fn take_bytes_head_tail(bytes: &[u8], width: usize, show_ascii: bool, opt: UnitOpt) -> Visible<'_> {
     let iter = UnitIter::new_byte(bytes, show_ascii);
-    let (start, end) = take_head_tail(iter, width, true, HEAD_TAIL_HAS_MORE_COST).unwrap();
+    let (start, end) = take_head_tail(
+        iter,
+        width,
+        opt.byte_separator_cost(),
+        HEAD_TAIL_HAS_MORE_COST,
+    )
+    .unwrap();
     if start == end {
         if show_ascii {
             Visible::BytesAscii(bytes)
@@ -362,27 +470,27 @@ fn take_bytes_head_tail(bytes: &[u8], width: usize, show_ascii: bool) -> Visible
 fn take_head(
     iter: UnitIter<'_>,
     width: usize,
-    space_separated: bool,
+    separator_cost: usize,
 ) -> Result<(usize, usize), ()> {
-    take_side(iter, width, space_separated)
+    take_side(iter, width, separator_cost)
 }
 
 /// Returns `Result<(length, remaining), ()>`
 fn take_tail(
     iter: UnitIter<'_>,
     width: usize,
-    space_separated: bool,
+    separator_cost: usize,
 ) -> Result<(usize, usize), ()> {
-    take_side(iter.rev(), width, space_separated)
+    take_side(iter.rev(), width, separator_cost)
 }
 
 /// Returns `Result<(length, remaining), ()>`
 fn take_side(
     mut iter: UnitIter<'_>,
     width: usize,
-    space_separated: bool,
+    separator_cost: usize,
 ) -> Result<(usize, usize), ()> {
-    let mut calc = Calculator::new(width, SIDE_HAS_MORE_COST, space_separated);
+    let mut calc = Calculator::new(width, SIDE_HAS_MORE_COST, separator_cost);
     let mut len = 0;
     while let Some(unit_result) = iter.next_front() {
         match unit_result {
@@ -403,13 +511,13 @@ fn take_side(
 fn take_head_tail(
     mut iter: UnitIter<'_>,
     width: usize,
-    space_separated: bool,
+    separator_cost: usize,
     has_more_cost: usize,
 ) -> Result<(usize, usize), ()> {
     let mut front = true;
     let mut front_len = 0;
     let mut back_offset = iter.as_slice().len();
-    let mut calc = Calculator::new(width, has_more_cost, space_separated);
+    let mut calc = Calculator::new(width, has_more_cost, separator_cost);
     loop {
         let section_result = if front {
             iter.next_front()
@@ -440,29 +548,36 @@ fn take_head_tail(
 /// Returns `Result<(start, end), ()>`
 fn take_span(
     iter: UnitIter<'_>,
-    span_offset: usize,
+    (span_offset, cover_end): SpanRange,
     width: usize,
-    space_separated: bool,
+    separator_cost: usize,
 ) -> Result<(usize, usize), ()> {
+    if let Some(cover_end) = cover_end {
+        if let Some(range) =
+            take_covering(iter.clone(), span_offset, cover_end, width, separator_cost)?
+        {
+            return Ok(range);
+        }
+    }
     // Attempt to get 1/3 of the total width before the span.
     let init_backward_width = width / 3 + SIDE_HAS_MORE_COST;
     let backward_offset = iter.as_slice().len() - span_offset;
     let init_backward_iter = iter.clone().skip_tail_bytes(backward_offset);
     let (init_head_len, head_remaining_width) =
-        take_tail(init_backward_iter, init_backward_width, space_separated)?;
+        take_tail(init_backward_iter, init_backward_width, separator_cost)?;
     // Attempt to get 2/3 plus what couldn't be taken from before.
     let forward_width = width
         .saturating_sub(init_backward_width)
         .saturating_add(head_remaining_width);
     let forward_iter = iter.clone().skip_head_bytes(span_offset);
-    let (tail_len, tail_remaining_width) = take_head(forward_iter, forward_width, space_separated)?;
+    let (tail_len, tail_remaining_width) = take_head(forward_iter, forward_width, separator_cost)?;
     // If we had some remaining width from the span onwards, see if we can use it before.
     let head_len = if tail_remaining_width > 0 {
         let backward_iter = iter.skip_tail_bytes(backward_offset);
         let backward_width = width
             .saturating_sub(forward_width)
             .saturating_add(tail_remaining_width);
-        let (head_len, _) = take_tail(backward_iter, backward_width, space_separated)?;
+        let (head_len, _) = take_tail(backward_iter, backward_width, separator_cost)?;
         head_len
     } else {
         init_head_len
@@ -470,21 +585,76 @@ fn take_span(
     Ok((span_offset - head_len, span_offset + tail_len))
 }
 
+/// Returns `Result<Some((start, end)), ()>` if all of the input from `start`
+/// to `end` fits within the width.
+fn take_covering(
+    iter: UnitIter<'_>,
+    start: usize,
+    end: usize,
+    width: usize,
+    separator_cost: usize,
+) -> Result<Option<(usize, usize)>, ()> {
+    let len = iter.as_slice().len();
+    // Reserve the cost of the has more on either side of the covered input.
+    let (head_has_more_cost, head_separator_cost) = if start > 0 {
+        (SIDE_HAS_MORE_COST, separator_cost)
+    } else {
+        (0, 0)
+    };
+    let (tail_has_more_cost, tail_separator_cost) = if end < len {
+        (SIDE_HAS_MORE_COST, separator_cost)
+    } else {
+        (0, 0)
+    };
+    let cover_width = width.saturating_sub(head_has_more_cost + tail_has_more_cost);
+    let cover_iter = iter
+        .clone()
+        .skip_head_bytes(start)
+        .skip_tail_bytes(len - end);
+    let (cover_len, cover_remaining_width) = take_head(cover_iter, cover_width, separator_cost)?;
+    if cover_len < end - start {
+        return Ok(None);
+    }
+    // Split what remains between after and before the covered input,
+    // accounting for the separators joining them.
+    let sides_width =
+        cover_remaining_width.saturating_sub(head_separator_cost + tail_separator_cost);
+    let forward_iter = iter.clone().skip_head_bytes(end);
+    let forward_width = (sides_width - sides_width / 2) + tail_has_more_cost;
+    let (init_tail_len, tail_remaining_width) =
+        take_head(forward_iter.clone(), forward_width, separator_cost)?;
+    // Use whatever couldn't be taken after before.
+    let backward_iter = iter.skip_tail_bytes(len - start);
+    let backward_width =
+        (sides_width / 2 + head_has_more_cost).saturating_add(tail_remaining_width);
+    let (head_len, head_remaining_width) =
+        take_tail(backward_iter, backward_width, separator_cost)?;
+    // If there is still some remaining width before, see if we can use it after.
+    let tail_len = if head_remaining_width > 0 && tail_remaining_width == 0 {
+        let forward_width = forward_width.saturating_add(head_remaining_width);
+        let (tail_len, _) = take_head(forward_iter, forward_width, separator_cost)?;
+        tail_len
+    } else {
+        init_tail_len
+    };
+    Ok(Some((start - head_len, end + tail_len)))
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 struct Calculator {
     budget: usize,
     is_first: bool,
     has_more_cost: usize,
-    space_separated: bool,
+    separator_cost: usize,
 }
 
 impl Calculator {
-    fn new(budget: usize, has_more_cost: usize, space_separated: bool) -> Self {
+    fn new(budget: usize, has_more_cost: usize, separator_cost: usize) -> Self {
         Self {
             budget,
             has_more_cost,
-            space_separated,
+            separator_cost,
             is_first: true,
         }
     }
@@ -495,15 +665,11 @@ impl Calculator {
 
     fn next(&mut self, display_cost: usize, has_next: bool) -> bool {
         // Make sure we have room for the separator if any
-        let unit_cost = if self.space_separated {
-            if self.is_first {
-                self.is_first = false;
-                display_cost
-            } else {
-                SPACE_COST + display_cost
-            }
-        } else {
+        let unit_cost = if self.is_first {
+            self.is_first = false;
             display_cost
+        } else {
+            self.separator_cost + display_cost
         };
         // Make sure we have room for the has more
         let required = if has_next {
@@ -584,7 +750,7 @@ mod tests {
             display: $display:expr,
         }) => {{
             let full = $input;
-            let section = Section::$from($input, $display.len(), $format);
+            let section = Section::$from($input, $display.len(), $format, UnitOpt::DEFAULT);
             let input = InputDisplay::new(&input(&full[..]))
                 .format($format)
                 .$input_section($display.len());
@@ -603,7 +769,8 @@ mod tests {
         }) => {{
             let full = $input;
             let span = &full[$range];
-            let section = Section::from_span(full, span.into(), $display.len(), $format);
+            let section =
+                Section::from_span(full, span.into(), $display.len(), $format, UnitOpt::DEFAULT);
             let input = InputDisplay::new(&input(&full[..]))
                 .format($format)
                 .span(span.into(), $display.len());
@@ -4,6 +4,32 @@ use unicode_width::UnicodeWidthChar;
 use crate::fmt::{self, Write};
 use crate::util::utf8::CharIter;
 
+///////////////////////////////////////////////////////////////////////////////
+// Unit options
+
+/// Options for how units are displayed.
+#[derive(Copy, Clone)]
+pub(super) struct UnitOpt {
+    /// Expand tabs to this many spaces when displaying chars.
+    pub(super) tab_width: Option<usize>,
+    /// Written between each displayed byte.
+    pub(super) byte_separator: char,
+    /// Write bytes as uppercase hex.
+    pub(super) hex_uppercase: bool,
+}
+
+impl UnitOpt {
+    pub(super) const DEFAULT: Self = Self {
+        tab_width: None,
+        byte_separator: ' ',
+        hex_uppercase: false,
+    };
+
+    pub(super) fn byte_separator_cost(self) -> usize {
+        unicode_width(self.byte_separator, false)
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Byte display
 
@@ -19,7 +45,12 @@ pub(super) fn byte_display_width(b: u8, show_ascii: bool) -> usize {
     }
 }
 
-pub(super) fn byte_display_write(b: u8, show_ascii: bool, w: &mut dyn Write) -> fmt::Result {
+pub(super) fn byte_display_write(
+    b: u8,
+    show_ascii: bool,
+    hex_uppercase: bool,
+    w: &mut dyn Write,
+) -> fmt::Result {
     if show_ascii {
         match b {
             b'\"' => w.write_str("'\\\"'"),
@@ -32,14 +63,33 @@ pub(super) fn byte_display_write(b: u8, show_ascii: bool, w: &mut dyn Write) ->
                 w.write_char(b as char)?;
                 w.write_char('\'')
             }
-            b => w.write_hex(b),
+            b => write_hex(b, hex_uppercase, w),
         }
+    } else {
+        write_hex(b, hex_uppercase, w)
+    }
+}
+
+fn write_hex(b: u8, uppercase: bool, w: &mut dyn Write) -> fmt::Result {
+    if uppercase {
+        for c in [b >> 4, b & 0x0F] {
+            w.write_char(
+                char::from_digit(u32::from(c), 16)
+                    .unwrap()
+                    .to_ascii_uppercase(),
+            )?;
+        }
+        Ok(())
     } else {
         w.write_hex(b)
     }
 }
 
-fn byte_next_front(bytes: &mut &[u8], show_ascii: bool) -> Option<Result<Unit, ()>> {
+fn byte_next_front(
+    bytes: &mut &[u8],
+    show_ascii: bool,
+    _tab_width: Option<usize>,
+) -> Option<Result<Unit, ()>> {
     if bytes.is_empty() {
         None
     } else {
@@ -49,7 +99,11 @@ fn byte_next_front(bytes: &mut &[u8], show_ascii: bool) -> Option<Result<Unit, (
     }
 }
 
-fn byte_next_back(bytes: &mut &[u8], show_ascii: bool) -> Option<Result<Unit, ()>> {
+fn byte_next_back(
+    bytes: &mut &[u8],
+    show_ascii: bool,
+    _tab_width: Option<usize>,
+) -> Option<Result<Unit, ()>> {
     if bytes.is_empty() {
         None
     } else {
@@ -63,14 +117,33 @@ fn byte_next_back(bytes: &mut &[u8], show_ascii: bool) -> Option<Result<Unit, ()
 ///////////////////////////////////////////////////////////////////////////////
 // Char display
 
-pub(super) fn char_display_width(c: char, cjk: bool) -> usize {
-    c.escape_debug()
-        .fold(0, |acc, c| acc + unicode_width(c, cjk))
+pub(super) fn char_display_width(c: char, cjk: bool, tab_width: Option<usize>) -> usize {
+    match (c, tab_width) {
+        ('\t', Some(tab_width)) => tab_width,
+        _ => c
+            .escape_debug()
+            .fold(0, |acc, c| acc + unicode_width(c, cjk)),
+    }
 }
 
-pub(super) fn char_display_write(c: char, w: &mut dyn Write) -> fmt::Result {
-    for c in c.escape_debug() {
-        w.write_char(c)?;
+pub(super) fn char_display_write(
+    c: char,
+    tab_width: Option<usize>,
+    w: &mut dyn Write,
+) -> fmt::Result {
+    match (c, tab_width) {
+        // Tabs are expanded to spaces so the width written is exactly the
+        // width accounted for when computing sections and underlines.
+        ('\t', Some(tab_width)) => {
+            for _ in 0..tab_width {
+                w.write_char(' ')?;
+            }
+        }
+        _ => {
+            for c in c.escape_debug() {
+                w.write_char(c)?;
+            }
+        }
     }
     Ok(())
 }
@@ -87,20 +160,32 @@ fn unicode_width(_c: char, _cjk: bool) -> usize {
     1
 }
 
-fn char_next_front(bytes: &mut &[u8], cjk: bool) -> Option<Result<Unit, ()>> {
+fn char_next_front(
+    bytes: &mut &[u8],
+    cjk: bool,
+    tab_width: Option<usize>,
+) -> Option<Result<Unit, ()>> {
     let mut iter = CharIter::new(bytes);
-    let result = iter
-        .next()
-        .map(|result| result.map(|c| Unit::unicode(c, cjk)).map_err(drop));
+    let result = iter.next().map(|result| {
+        result
+            .map(|c| Unit::unicode(c, cjk, tab_width))
+            .map_err(drop)
+    });
     *bytes = iter.as_slice();
     result
 }
 
-fn char_next_back(bytes: &mut &[u8], cjk: bool) -> Option<Result<Unit, ()>> {
+fn char_next_back(
+    bytes: &mut &[u8],
+    cjk: bool,
+    tab_width: Option<usize>,
+) -> Option<Result<Unit, ()>> {
     let mut iter = CharIter::new(bytes);
-    let result = iter
-        .next_back()
-        .map(|result| result.map(|c| Unit::unicode(c, cjk)).map_err(drop));
+    let result = iter.next_back().map(|result| {
+        result
+            .map(|c| Unit::unicode(c, cjk, tab_width))
+            .map_err(drop)
+    });
     *bytes = iter.as_slice();
     result
 }
@@ -122,20 +207,21 @@ impl Unit {
         }
     }
 
-    pub(super) fn unicode(c: char, cjk: bool) -> Self {
+    pub(super) fn unicode(c: char, cjk: bool, tab_width: Option<usize>) -> Self {
         Self {
-            display_cost: char_display_width(c, cjk),
+            display_cost: char_display_width(c, cjk, tab_width),
             len_utf8: c.len_utf8(),
         }
     }
 }
 
-type UnitIterFn = fn(&mut &[u8], bool) -> Option<Result<Unit, ()>>;
+type UnitIterFn = fn(&mut &[u8], bool, Option<usize>) -> Option<Result<Unit, ()>>;
 
 #[derive(Clone)]
 pub(super) struct UnitIter<'a> {
     bytes: &'a [u8],
     modifier: bool,
+    tab_width: Option<usize>,
     next_front: UnitIterFn,
     next_back: UnitIterFn,
 }
@@ -145,15 +231,17 @@ impl<'a> UnitIter<'a> {
         Self {
             bytes,
             modifier: show_ascii,
+            tab_width: None,
             next_front: byte_next_front,
             next_back: byte_next_back,
         }
     }
 
-    pub(super) fn new_char(bytes: &'a [u8], cjk: bool) -> Self {
+    pub(super) fn new_char(bytes: &'a [u8], cjk: bool, tab_width: Option<usize>) -> Self {
         Self {
             bytes,
             modifier: cjk,
+            tab_width,
             next_front: char_next_front,
             next_back: char_next_back,
         }
@@ -168,17 +256,18 @@ impl<'a> UnitIter<'a> {
     }
 
     pub(super) fn next_front(&mut self) -> Option<Result<Unit, ()>> {
-        (self.next_front)(&mut self.bytes, self.modifier)
+        (self.next_front)(&mut self.bytes, self.modifier, self.tab_width)
     }
 
     pub(super) fn next_back(&mut self) -> Option<Result<Unit, ()>> {
-        (self.next_back)(&mut self.bytes, self.modifier)
+        (self.next_back)(&mut self.bytes, self.modifier, self.tab_width)
     }
 
     pub(super) fn rev(self) -> Self {
         Self {
             bytes: self.bytes,
             modifier: self.modifier,
+            tab_width: self.tab_width,
             next_front: self.next_back,
             next_back: self.next_front,
         }
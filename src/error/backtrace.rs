@@ -1,9 +1,13 @@
 #[cfg(feature = "alloc")]
-use alloc::{boxed::Box, vec::Vec};
-#[cfg(feature = "alloc")]
-use core::iter;
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::any::Any;
+use core::ops::Range;
+use core::{iter, str};
+
+use crate::fmt;
+use crate::input::Span;
 
-use super::{Context, CoreContext};
+use super::{Context, CoreContext, Operation};
 
 /// Implemented for walkable stacks of [`Context`]s collected from an error.
 pub trait Backtrace: 'static {
@@ -11,12 +15,96 @@ pub trait Backtrace: 'static {
     fn root(&self) -> CoreContext;
 
     /// Return the total number of contexts.
+    ///
+    /// This matches the number of contexts visited by [`Backtrace::walk()`],
+    /// including any entry for contexts that were not collected.
     fn count(&self) -> usize;
 
     /// Walk the context backtrace, starting with the highest context to the root.
     ///
     /// Returns `true` if all of the stack available was walked, `false` if not.
     fn walk<'a>(&'a self, f: &mut BacktraceWalker<'a>) -> bool;
+
+    /// Returns an iterator over the frames of the backtrace.
+    ///
+    /// Frames are returned in the same order as [`Backtrace::walk()`] visits
+    /// them, borrowing the contexts stored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Expected};
+    /// use dangerous::error::{Backtrace, Details};
+    ///
+    /// let error = dangerous::input(b"hello")
+    ///     .read_all::<_, _, Expected<'_>>(|r| r.consume(b"world"))
+    ///     .unwrap_err();
+    ///
+    /// let mut operation = String::new();
+    /// let frame = error.backtrace().frames().last().unwrap();
+    /// frame.operation().description(&mut operation).unwrap();
+    ///
+    /// assert_eq!(operation, "consume input");
+    /// ```
+    fn frames(&self) -> BacktraceFrames<'_>;
+
+    /// Returns the source of an [`External`] error held by the highest
+    /// context that has one.
+    ///
+    /// Sources are held by contexts, so are only available from backtraces
+    /// that keep the contexts pushed, such as [`FullBacktrace`].
+    ///
+    /// [`External`]: crate::error::External
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+
+    /// Writes the backtrace as a JSON array of contexts in walk order.
+    ///
+    /// Each context is written as `{ "operation": string, "expected": string
+    /// }`, with `expected` as `null` if the context has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`fmt::Error`] if a context failed to write its operation or
+    /// expected value. No partially written document is returned.
+    ///
+    /// [`fmt::Error`]: core::fmt::Error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Expected};
+    /// use dangerous::error::{Backtrace, Details};
+    ///
+    /// let error = dangerous::input(b"hello")
+    ///     .read_all::<_, _, Expected<'_>>(|r| r.consume(b"world"))
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(
+    ///     error.backtrace().to_json().unwrap(),
+    ///     r#"[{"operation":"read all input","expected":null},{"operation":"consume input","expected":"exact value"}]"#,
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn to_json(&self) -> Result<String, fmt::Error> {
+        let mut json = String::from("[");
+        let complete = self.walk(&mut |_, context| {
+            if json.len() > 1 {
+                json.push(',');
+            }
+            write_json_context(&mut json, context).is_ok()
+        });
+        if complete {
+            json.push(']');
+            Ok(json)
+        } else {
+            Err(fmt::Error)
+        }
+    }
 }
 
 /// Implemented for [`Backtrace`] builders.
@@ -49,6 +137,254 @@ pub trait BacktraceBuilder {
 /// depth` value.
 pub type BacktraceWalker<'a> = dyn FnMut(usize, &dyn Context) -> bool + 'a;
 
+/// An iterator over the frames of a [`Backtrace`].
+///
+/// Returned from [`Backtrace::frames()`]. Frames borrow the contexts stored in
+/// the backtrace and are returned in the same order as [`Backtrace::walk()`]
+/// visits them, starting with the highest context to the root.
+pub struct BacktraceFrames<'a> {
+    root: &'a dyn Context,
+    stack: FrameStack<'a>,
+    elided: Option<&'a ElidedContexts>,
+    /// The number of stack items, including the root, not yet scanned.
+    unscanned: usize,
+    /// Child contexts of the last parent returned, walked from the end.
+    children: Range<usize>,
+    depth: usize,
+    remaining: usize,
+}
+
+impl<'a> BacktraceFrames<'a> {
+    /// Creates an iterator over the frames of a root context and the contexts
+    /// pushed on top of it, in the order they were pushed.
+    ///
+    /// Frames are returned in the order [`Backtrace::walk()`] is expected to
+    /// follow, for implementing [`Backtrace::frames()`].
+    pub fn new(root: &'a dyn Context, stack: &'a [&'a dyn Context]) -> Self {
+        Self::from_stack(root, FrameStack::Borrowed(stack), None)
+    }
+
+    fn from_stack(
+        root: &'a dyn Context,
+        stack: FrameStack<'a>,
+        elided: Option<&'a ElidedContexts>,
+    ) -> Self {
+        let elided = elided.filter(|elided| elided.count > 0);
+        let unscanned = stack.len() + 1;
+        Self {
+            root,
+            stack,
+            elided,
+            unscanned,
+            children: 0..0,
+            depth: 0,
+            remaining: unscanned + usize::from(elided.is_some()),
+        }
+    }
+
+    fn item(&self, index: usize) -> &'a dyn Context {
+        match index.checked_sub(1) {
+            Some(index) => self.stack.get(index),
+            None => self.root,
+        }
+    }
+
+    fn next_frame(&mut self) -> Option<BacktraceFrame<'a>> {
+        if let Some(elided) = self.elided.take() {
+            self.depth += 1;
+            return Some(BacktraceFrame::new(self.depth, elided));
+        }
+        // Children of the last parent are walked after it, from the top.
+        if let Some(index) = self.children.next_back() {
+            return Some(BacktraceFrame::new(self.depth, self.item(index)));
+        }
+        let run_end = self.unscanned;
+        while let Some(index) = self.unscanned.checked_sub(1) {
+            self.unscanned = index;
+            let context = self.item(index);
+            if !context.is_child() {
+                self.depth += 1;
+                self.children = index + 1..run_end;
+                return Some(BacktraceFrame::new(self.depth, context));
+            }
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for BacktraceFrames<'a> {
+    type Item = BacktraceFrame<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.next_frame()?;
+        self.remaining -= 1;
+        Some(frame)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for BacktraceFrames<'_> {}
+
+impl fmt::Debug for BacktraceFrames<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BacktraceFrames")
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+/// A frame of a [`Backtrace`] returned from [`BacktraceFrames`].
+#[derive(Copy, Clone)]
+pub struct BacktraceFrame<'a> {
+    depth: usize,
+    context: &'a dyn Context,
+}
+
+impl<'a> BacktraceFrame<'a> {
+    fn new(depth: usize, context: &'a dyn Context) -> Self {
+        Self { depth, context }
+    }
+
+    /// Returns the parent depth of the frame starting from `1`.
+    ///
+    /// See [`BacktraceWalker`] for how parent depths are shared with child
+    /// contexts.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the frame's [`Context`].
+    #[must_use]
+    pub fn context(&self) -> &'a dyn Context {
+        self.context
+    }
+
+    /// Returns the frame's [`Operation`].
+    #[must_use]
+    pub fn operation(&self) -> &'a dyn Operation {
+        self.context.operation()
+    }
+
+    /// Returns `true` if the frame's context has an expected value.
+    #[must_use]
+    pub fn has_expected(&self) -> bool {
+        self.context.has_expected()
+    }
+
+    /// Writes the expected value of the frame's context.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`fmt::Error`] if failed to write to the formatter, or if
+    /// the context has no expected value.
+    ///
+    /// [`fmt::Error`]: core::fmt::Error
+    pub fn expected(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        self.context.expected(w)
+    }
+}
+
+impl fmt::Debug for BacktraceFrame<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Description<'a>(&'a dyn Operation);
+
+        impl fmt::Debug for Description<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("\"")?;
+                self.0.description(f)?;
+                f.write_str("\"")
+            }
+        }
+
+        f.debug_struct("BacktraceFrame")
+            .field("depth", &self.depth)
+            .field("operation", &Description(self.operation()))
+            .finish()
+    }
+}
+
+/// Contexts pushed on top of a root, in the order they were pushed.
+#[derive(Copy, Clone)]
+enum FrameStack<'a> {
+    Borrowed(&'a [&'a dyn Context]),
+    #[cfg(feature = "alloc")]
+    Boxed(&'a [Box<dyn Context>]),
+    Inline(&'a [StackContext]),
+}
+
+impl<'a> FrameStack<'a> {
+    fn len(self) -> usize {
+        match self {
+            Self::Borrowed(stack) => stack.len(),
+            #[cfg(feature = "alloc")]
+            Self::Boxed(stack) => stack.len(),
+            Self::Inline(stack) => stack.len(),
+        }
+    }
+
+    fn get(self, index: usize) -> &'a dyn Context {
+        match self {
+            Self::Borrowed(stack) => stack[index],
+            #[cfg(feature = "alloc")]
+            Self::Boxed(stack) => stack[index].as_ref(),
+            Self::Inline(stack) => &stack[index],
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn write_json_context(json: &mut String, context: &dyn Context) -> fmt::Result {
+    json.push_str("{\"operation\":\"");
+    context.operation().description(&mut JsonEscape(json))?;
+    json.push_str("\",\"expected\":");
+    if context.has_expected() {
+        json.push('"');
+        context.expected(&mut JsonEscape(json))?;
+        json.push('"');
+    } else {
+        json.push_str("null");
+    }
+    json.push('}');
+    Ok(())
+}
+
+/// Writes escaped JSON string contents.
+#[cfg(feature = "alloc")]
+struct JsonEscape<'a>(&'a mut String);
+
+#[cfg(feature = "alloc")]
+impl fmt::Write for JsonEscape<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        match c {
+            '"' => self.0.push_str("\\\""),
+            '\\' => self.0.push_str("\\\\"),
+            '\n' => self.0.push_str("\\n"),
+            '\r' => self.0.push_str("\\r"),
+            '\t' => self.0.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                self.0.push_str("\\u00");
+                // Won't truncate as the char is below 0x20.
+                #[allow(clippy::cast_possible_truncation)]
+                self.write_hex(c as u8)?;
+            }
+            c => self.0.push(c),
+        }
+        Ok(())
+    }
+
+    fn write_usize(&mut self, v: usize) -> fmt::Result {
+        fmt::Write::write_usize(self.0, v)
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Root context backtrace
 
@@ -79,12 +415,18 @@ impl Backtrace for RootBacktrace {
     fn walk<'a>(&'a self, f: &mut BacktraceWalker<'a>) -> bool {
         f(1, &self.context)
     }
+
+    fn frames(&self) -> BacktraceFrames<'_> {
+        BacktraceFrames::new(&self.context, &[])
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
 // Full backtrace
 
 /// A [`Backtrace`] that contains all [`Context`]s collected.
+///
+/// See [`LimitedBacktrace`] to cap the number of contexts collected.
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub struct FullBacktrace {
@@ -117,32 +459,410 @@ impl Backtrace for FullBacktrace {
     }
 
     fn walk<'a>(&'a self, f: &mut BacktraceWalker<'a>) -> bool {
-        let root_as_dyn: &dyn Context = &self.root;
-        let stack_iter = self.stack.iter().map(|context| {
-            let context: &dyn Context = context.as_ref();
-            context
-        });
-        let items_iter = iter::once(root_as_dyn).chain(stack_iter).rev();
-        let child_iter = &mut items_iter.clone().filter(|context| context.is_child());
-        let mut depth = 0;
-        let mut children_skipped = 0;
-        // Starts from the top context, with children before their parent.
-        for context in items_iter {
-            if context.is_child() {
-                children_skipped += 1;
-            } else {
-                depth += 1;
-                if !f(depth, context) {
+        walk_contexts(&self.root, boxed_contexts(&self.stack), None, f)
+    }
+
+    fn frames(&self) -> BacktraceFrames<'_> {
+        BacktraceFrames::from_stack(&self.root, FrameStack::Boxed(&self.stack), None)
+    }
+
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.stack.iter().rev().find_map(|context| context.source())
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn boxed_contexts(
+    stack: &[Box<dyn Context>],
+) -> impl DoubleEndedIterator<Item = &dyn Context> + Clone {
+    stack.iter().map(|context| {
+        let context: &dyn Context = context.as_ref();
+        context
+    })
+}
+
+/// Walks the root and the contexts pushed on top of it, starting with any
+/// elided contexts.
+fn walk_contexts<'a, I>(
+    root: &'a dyn Context,
+    stack: I,
+    elided: Option<&'a ElidedContexts>,
+    f: &mut BacktraceWalker<'_>,
+) -> bool
+where
+    I: DoubleEndedIterator<Item = &'a dyn Context> + Clone,
+{
+    let items_iter = iter::once(root).chain(stack).rev();
+    let child_iter = &mut items_iter.clone().filter(|context| context.is_child());
+    let mut depth = 0;
+    if let Some(elided) = elided.filter(|elided| elided.count > 0) {
+        depth += 1;
+        if !f(depth, elided) {
+            return false;
+        }
+    }
+    let mut children_skipped = 0;
+    // Starts from the top context, with children before their parent.
+    for context in items_iter {
+        if context.is_child() {
+            children_skipped += 1;
+        } else {
+            depth += 1;
+            if !f(depth, context) {
+                return false;
+            }
+            for child in child_iter.take(children_skipped) {
+                if !f(depth, child) {
                     return false;
                 }
-                for child in child_iter.take(children_skipped) {
-                    if !f(depth, child) {
-                        return false;
-                    }
-                }
-                children_skipped = 0;
             }
+            children_skipped = 0;
         }
-        true
+    }
+    true
+}
+
+/// Coalesced contexts pushed once a backtrace was full.
+#[derive(Copy, Clone)]
+struct ElidedContexts {
+    count: usize,
+}
+
+impl ElidedContexts {
+    /// Returns the number of frames walked for the elided contexts.
+    fn frame_count(self) -> usize {
+        usize::from(self.count > 0)
+    }
+}
+
+impl Context for ElidedContexts {
+    fn operation(&self) -> &dyn Operation {
+        self
+    }
+}
+
+impl Operation for ElidedContexts {
+    fn description(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        w.write_str("... ")?;
+        w.write_usize(self.count)?;
+        if self.count == 1 {
+            w.write_str(" more context")
+        } else {
+            w.write_str(" more contexts")
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Limited backtrace
+
+/// A [`Backtrace`] like [`FullBacktrace`] that collects at most `N` contexts
+/// on top of the root.
+///
+/// Useful with recursive grammars where collecting every context would bloat
+/// error output. Contexts pushed once the backtrace is full are coalesced into
+/// a single `... N more contexts` entry, walked before the collected contexts.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Expected, Input};
+/// use dangerous::error::{Backtrace, Details, LimitedBacktrace};
+///
+/// let error = dangerous::input(b"hello")
+///     .read_all::<_, _, Expected<'_, LimitedBacktrace<0>>>(|r| r.consume(b"world"))
+///     .unwrap_err();
+///
+/// assert_eq!(
+///     error.backtrace().to_json().unwrap(),
+///     r#"[{"operation":"... 1 more context","expected":null},{"operation":"consume input","expected":"exact value"}]"#,
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct LimitedBacktrace<const N: usize> {
+    root: CoreContext,
+    stack: Vec<Box<dyn Context>>,
+    elided: ElidedContexts,
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> LimitedBacktrace<N> {
+    /// Returns the number of contexts that were not collected.
+    #[must_use]
+    pub fn elided(&self) -> usize {
+        self.elided.count
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> BacktraceBuilder for LimitedBacktrace<N> {
+    fn from_root(context: CoreContext) -> Self {
+        Self {
+            root: context,
+            stack: Vec::with_capacity(N.min(32)),
+            elided: ElidedContexts { count: 0 },
+        }
+    }
+
+    fn push(&mut self, context: impl Context) {
+        if self.stack.len() < N {
+            self.stack.push(Box::new(context));
+        } else {
+            self.elided.count += 1;
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> Backtrace for LimitedBacktrace<N> {
+    fn root(&self) -> CoreContext {
+        self.root
+    }
+
+    fn count(&self) -> usize {
+        self.stack.len() + 1 + self.elided.frame_count()
+    }
+
+    fn walk<'a>(&'a self, f: &mut BacktraceWalker<'a>) -> bool {
+        walk_contexts(
+            &self.root,
+            boxed_contexts(&self.stack),
+            Some(&self.elided),
+            f,
+        )
+    }
+
+    fn frames(&self) -> BacktraceFrames<'_> {
+        BacktraceFrames::from_stack(
+            &self.root,
+            FrameStack::Boxed(&self.stack),
+            Some(&self.elided),
+        )
+    }
+
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.stack.iter().rev().find_map(|context| context.source())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Stack backtrace
+
+/// The maximum byte length of text kept for a [`StackBacktrace`] context.
+const STACK_TEXT_LEN: usize = 64;
+
+/// A [`Backtrace`] that stores up to `N` contexts on top of the root inline,
+/// without allocating.
+///
+/// As contexts can't be stored as is without allocating, the operation
+/// description and expected value of each are written into fixed length
+/// buffers and truncated if too long. Contexts pushed once the backtrace is
+/// full are coalesced into a single `... N more contexts` entry, walked before
+/// the collected contexts.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Expected, Input};
+/// use dangerous::error::{Backtrace, Details, StackBacktrace};
+///
+/// let error = dangerous::input(b"hello")
+///     .read_all::<_, _, Expected<'_, StackBacktrace<4>>>(|r| {
+///         r.context("greeting", |r| r.consume(b"world"))
+///     })
+///     .unwrap_err();
+///
+/// assert_eq!(error.backtrace().count(), 3);
+/// ```
+pub struct StackBacktrace<const N: usize> {
+    root: CoreContext,
+    stack: [StackContext; N],
+    len: usize,
+    elided: ElidedContexts,
+}
+
+impl<const N: usize> StackBacktrace<N> {
+    /// Returns the number of contexts that were pushed once the backtrace was
+    /// full and were not collected.
+    #[must_use]
+    pub fn elided(&self) -> usize {
+        self.elided.count
+    }
+}
+
+impl<const N: usize> BacktraceBuilder for StackBacktrace<N> {
+    fn from_root(context: CoreContext) -> Self {
+        Self {
+            root: context,
+            stack: [StackContext::EMPTY; N],
+            len: 0,
+            elided: ElidedContexts { count: 0 },
+        }
+    }
+
+    fn push(&mut self, context: impl Context) {
+        match self.stack.get_mut(self.len) {
+            Some(slot) => {
+                *slot = StackContext::from_context(&context);
+                self.len += 1;
+            }
+            None => self.elided.count += 1,
+        }
+    }
+}
+
+impl<const N: usize> Backtrace for StackBacktrace<N> {
+    fn root(&self) -> CoreContext {
+        self.root
+    }
+
+    fn count(&self) -> usize {
+        self.len + 1 + self.elided.frame_count()
+    }
+
+    fn walk<'a>(&'a self, f: &mut BacktraceWalker<'a>) -> bool {
+        let stack_iter = self.stack[..self.len].iter().map(|context| {
+            let context: &dyn Context = context;
+            context
+        });
+        walk_contexts(&self.root, stack_iter, Some(&self.elided), f)
+    }
+
+    fn frames(&self) -> BacktraceFrames<'_> {
+        BacktraceFrames::from_stack(
+            &self.root,
+            FrameStack::Inline(&self.stack[..self.len]),
+            Some(&self.elided),
+        )
+    }
+}
+
+/// A context stored inline within a [`StackBacktrace`].
+#[derive(Copy, Clone)]
+struct StackContext {
+    span: Option<Span>,
+    is_child: bool,
+    operation: StackText,
+    expected: Option<StackText>,
+}
+
+impl StackContext {
+    const EMPTY: Self = Self {
+        span: None,
+        is_child: false,
+        operation: StackText::EMPTY,
+        expected: None,
+    };
+
+    fn from_context(context: &dyn Context) -> Self {
+        let mut operation = StackText::EMPTY;
+        let _ = context.operation().description(&mut operation);
+        let expected = if context.has_expected() {
+            let mut expected = StackText::EMPTY;
+            let _ = context.expected(&mut expected);
+            Some(expected)
+        } else {
+            None
+        };
+        Self {
+            span: context.span(),
+            is_child: context.is_child(),
+            operation,
+            expected,
+        }
+    }
+}
+
+impl Context for StackContext {
+    fn span(&self) -> Option<Span> {
+        self.span
+    }
+
+    fn operation(&self) -> &dyn Operation {
+        &self.operation
+    }
+
+    fn has_expected(&self) -> bool {
+        self.expected.is_some()
+    }
+
+    fn expected(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        match &self.expected {
+            Some(expected) => w.write_str(expected.as_str()),
+            None => Err(fmt::Error),
+        }
+    }
+
+    fn is_child(&self) -> bool {
+        self.is_child
+    }
+}
+
+/// Text written into a fixed length buffer, truncated on a char boundary.
+#[derive(Copy, Clone)]
+struct StackText {
+    buf: [u8; STACK_TEXT_LEN],
+    len: usize,
+    truncated: bool,
+}
+
+impl StackText {
+    const EMPTY: Self = Self {
+        buf: [0; STACK_TEXT_LEN],
+        len: 0,
+        truncated: false,
+    };
+
+    fn as_str(&self) -> &str {
+        // SAFETY: only whole UTF-8 encoded strs are written to the buffer.
+        unsafe { str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+}
+
+impl Operation for StackText {
+    fn description(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        w.write_str(self.as_str())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl core::fmt::Write for StackText {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if self.truncated {
+            return Ok(());
+        }
+        let mut len = s.len().min(STACK_TEXT_LEN - self.len);
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+        // Once anything doesn't fit, nothing after it is written.
+        self.truncated = len < s.len();
+        self.buf[self.len..self.len + len].copy_from_slice(&s.as_bytes()[..len]);
+        self.len += len;
+        Ok(())
+    }
+}
+
+impl fmt::Write for StackText {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        core::fmt::Write::write_str(self, s)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        core::fmt::Write::write_char(self, c)
+    }
+
+    fn write_usize(&mut self, v: usize) -> fmt::Result {
+        core::fmt::Write::write_fmt(self, format_args!("{}", v))
     }
 }
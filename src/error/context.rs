@@ -35,6 +35,18 @@ pub trait Context: 'static + Send + Sync {
     fn is_child(&self) -> bool {
         false
     }
+
+    /// Returns the source of an [`External`] error if the context holds one.
+    ///
+    /// See [`WithContext::with_source()`].
+    ///
+    /// [`External`]: crate::error::External
+    /// [`WithContext::with_source()`]: crate::error::WithContext::with_source()
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
 }
 
 /// Operation that failed within a context.
@@ -85,6 +97,103 @@ impl Operation for &'static str {
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Value context
+
+/// A [`Context`] with an expected value known only at runtime.
+///
+/// This is the owned equivalent of using a `&'static str` as a context, for
+/// example to include the name of the field being parsed.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Expected};
+/// use dangerous::error::ValueContext;
+///
+/// let field = "name";
+/// let error = dangerous::input(b"")
+///     .read_all::<_, _, Expected<'_>>(|r| {
+///         r.context(ValueContext::new(format!("field {}", field)), |r| r.read())
+///     })
+///     .unwrap_err();
+///
+/// assert!(error.to_string().contains("(expected field name)"));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone)]
+pub struct ValueContext {
+    expected: alloc::string::String,
+}
+
+#[cfg(feature = "alloc")]
+impl ValueContext {
+    /// Create a new `ValueContext` given the expected value.
+    pub fn new(expected: impl Into<alloc::string::String>) -> Self {
+        Self {
+            expected: expected.into(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Context for ValueContext {
+    fn operation(&self) -> &dyn Operation {
+        &CoreOperation::Context
+    }
+
+    fn has_expected(&self) -> bool {
+        true
+    }
+
+    fn expected(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        w.write_str(&self.expected)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Source context
+
+/// A child [`Context`] holding the source of an [`External`] error.
+///
+/// The source is described as the expected value.
+///
+/// [`External`]: crate::error::External
+#[cfg(feature = "std")]
+pub(crate) struct SourceContext(pub(crate) alloc::boxed::Box<dyn std::error::Error + Send + Sync>);
+
+#[cfg(feature = "std")]
+impl Context for SourceContext {
+    fn operation(&self) -> &dyn Operation {
+        &CoreOperation::Context
+    }
+
+    fn has_expected(&self) -> bool {
+        true
+    }
+
+    fn expected(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        struct Adapter<'a>(&'a mut dyn fmt::Write);
+
+        impl core::fmt::Write for Adapter<'_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0.write_str(s)
+            }
+        }
+
+        core::fmt::write(&mut Adapter(w), format_args!("{}", self.0))
+    }
+
+    fn is_child(&self) -> bool {
+        true
+    }
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&*self.0)
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // External context
 
@@ -223,12 +332,16 @@ pub enum CoreOperation {
     ReadPartial,
     // Consuming
     Consume,
+    ConsumeAny,
+    ConsumeEol,
+    ConsumeNewline,
     // Skipping
     Skip,
     SkipWhile,
     SkipStrWhile,
     SkipUntil,
     SkipUntilConsume,
+    SkipUntilAny,
     // Splitting
     SplitAt,
     SplitAtByte,
@@ -237,26 +350,76 @@ pub enum CoreOperation {
     TakeArray,
     TakeUntil,
     TakeUntilConsume,
+    TakeUntilLast,
+    TakeUntilInclusive,
+    TakeUntilAny,
+    TakeDigitsGrouped,
+    TakeBalanced,
+    ReadCStr,
     TakeWhile,
+    TakeWhile1,
+    TakeWhileInto,
     TakeConsumed,
     TakeStrWhile,
     TakeRemainingStr,
     // Peeking
     Peek,
+    Demand,
     PeekByte,
     PeekChar,
+    PeekCodeUnit,
     // Reading
     ReadByte,
     ReadChar,
+    ReadCodeUnit,
+    ReadAsciiFloat,
+    ReadAsciiInt,
+    ReadArray,
+    ReadVarint,
+    ReadHex,
+    ReadPercentDecoded,
+    ReadBits,
+    ReadLengthPrefixed,
+    Scoped,
+    FinishBits,
+    ParseRemaining,
+    ReadU8,
+    ReadI8,
+    ReadU16Le,
+    ReadU16Be,
+    ReadI16Le,
+    ReadI16Be,
+    ReadU32Le,
+    ReadU32Be,
+    ReadI32Le,
+    ReadI32Be,
+    ReadU64Le,
+    ReadU64Be,
+    ReadI64Le,
+    ReadI64Be,
+    ReadU128Le,
+    ReadU128Be,
+    ReadI128Le,
+    ReadI128Be,
+    ReadF32Le,
+    ReadF32Be,
+    ReadF64Le,
+    ReadF64Be,
     // Errors
     RecoverIf,
+    RecoverMap,
+    Alt,
+    Optional,
     Verify,
     Expect,
     ExpectExternal,
     // Converting
+    FromDetailless,
     IntoNonEmpty,
     IntoExternal,
     IntoString,
+    DecodeUtf16,
+    IntoUtf16,
 }
 
 impl Operation for CoreOperation {
@@ -274,6 +437,7 @@ impl CoreOperation {
         match T::TYPE {
             TokenType::Char => Self::ReadChar,
             TokenType::Byte => Self::ReadByte,
+            TokenType::Utf16 => Self::ReadCodeUnit,
         }
     }
 
@@ -281,6 +445,7 @@ impl CoreOperation {
         match T::TYPE {
             TokenType::Char => Self::PeekChar,
             TokenType::Byte => Self::PeekByte,
+            TokenType::Utf16 => Self::PeekCodeUnit,
         }
     }
 
@@ -290,33 +455,87 @@ impl CoreOperation {
             Self::ReadAll => "read all input",
             Self::ReadPartial => "read a partial length of input",
             Self::Consume => "consume input",
+            Self::ConsumeAny => "consume one of several values",
+            Self::ConsumeEol => "consume an end of line",
+            Self::ConsumeNewline => "consume a newline",
             Self::Skip => "skip a length of input",
             Self::SkipWhile => "skip input while a pattern matches",
             Self::SkipUntil => "skip input until a pattern matches",
             Self::SkipUntilConsume => "skip input until a pattern matches and consume it",
+            Self::SkipUntilAny => "skip input until any delimiter matches",
             Self::SkipStrWhile => "skip UTF-8 input while a condition remains true",
             Self::SplitAt => "split input at a token index",
             Self::SplitAtByte => "split input at a byte index",
             Self::Take => "take a length of input",
             Self::TakeArray => "take an array of bytes",
             Self::TakeWhile => "take input while a pattern matches",
+            Self::TakeWhile1 => "take at least one token of input while a pattern matches",
+            Self::TakeWhileInto => "take input while a pattern matches into a buffer",
             Self::TakeUntil => "take input until a pattern matches",
             Self::TakeUntilConsume => "take input until a pattern matches and consume it",
+            Self::TakeUntilLast => "take input until a pattern last matches",
+            Self::TakeUntilInclusive => "take input through a pattern match",
+            Self::TakeUntilAny => "take input until any delimiter matches",
+            Self::TakeDigitsGrouped => "take digits with grouping separators",
+            Self::TakeBalanced => "take input between balanced delimiters",
+            Self::ReadCStr => "read a NUL terminated string",
             Self::TakeConsumed => "take input that was consumed",
             Self::TakeStrWhile => "take UTF-8 input while a condition remains true",
             Self::TakeRemainingStr => "take remaining string within bytes",
             Self::Peek => "peek a length of input",
+            Self::Demand => "demand a length of input",
             Self::PeekByte => "peek a byte",
             Self::PeekChar => "peek a char",
+            Self::PeekCodeUnit => "peek a UTF-16 code unit",
             Self::ReadByte => "read a byte",
             Self::ReadChar => "read a char",
+            Self::ReadCodeUnit => "read a UTF-16 code unit",
+            Self::ReadAsciiFloat => "read an ASCII float",
+            Self::ReadAsciiInt => "read an ASCII integer",
+            Self::ReadArray => "read an array of bytes",
+            Self::ReadVarint => "read a LEB128 varint",
+            Self::ReadHex => "read hex encoded bytes",
+            Self::ReadPercentDecoded => "read percent encoded input",
+            Self::ReadBits => "read a bit field",
+            Self::ReadLengthPrefixed => "read a length prefixed value",
+            Self::Scoped => "read a scoped length of input",
+            Self::FinishBits => "finish reading bit fields",
+            Self::ReadU8 => "read u8",
+            Self::ReadI8 => "read i8",
+            Self::ReadU16Le => "read u16 (little endian)",
+            Self::ReadU16Be => "read u16 (big endian)",
+            Self::ReadI16Le => "read i16 (little endian)",
+            Self::ReadI16Be => "read i16 (big endian)",
+            Self::ReadU32Le => "read u32 (little endian)",
+            Self::ReadU32Be => "read u32 (big endian)",
+            Self::ReadI32Le => "read i32 (little endian)",
+            Self::ReadI32Be => "read i32 (big endian)",
+            Self::ReadU64Le => "read u64 (little endian)",
+            Self::ReadU64Be => "read u64 (big endian)",
+            Self::ReadI64Le => "read i64 (little endian)",
+            Self::ReadI64Be => "read i64 (big endian)",
+            Self::ReadU128Le => "read u128 (little endian)",
+            Self::ReadU128Be => "read u128 (big endian)",
+            Self::ReadI128Le => "read i128 (little endian)",
+            Self::ReadI128Be => "read i128 (big endian)",
+            Self::ReadF32Le => "read f32 (little endian)",
+            Self::ReadF32Be => "read f32 (big endian)",
+            Self::ReadF64Le => "read f64 (little endian)",
+            Self::ReadF64Be => "read f64 (big endian)",
+            Self::ParseRemaining => "parse the remaining input",
             Self::RecoverIf => "recover if a condition returns true",
+            Self::RecoverMap => "recover and map the error",
+            Self::Alt => "read one of several alternatives",
+            Self::Optional => "read an optional value",
             Self::Verify => "read and verify input",
             Self::Expect => "read and expect a value",
             Self::ExpectExternal => "read and expect an external value",
+            Self::FromDetailless => "convert from an error without details",
             Self::IntoNonEmpty => "convert input into non-empty input",
             Self::IntoExternal => "convert input into external type",
             Self::IntoString => "convert input into string",
+            Self::DecodeUtf16 => "decode UTF-16 input",
+            Self::IntoUtf16 => "convert input into UTF-16",
         }
     }
 }
@@ -398,6 +617,12 @@ where
     fn with_context(self, context: impl Context) -> Self {
         Self(self.0.with_context(ChildContext(context)))
     }
+
+    #[cfg(feature = "std")]
+    #[inline(always)]
+    fn with_source(self, source: alloc::boxed::Box<dyn std::error::Error + Send + Sync>) -> Self {
+        Self(self.0.with_source(source))
+    }
 }
 
 struct ChildContext<T>(T);
@@ -421,6 +646,11 @@ where
     fn is_child(&self) -> bool {
         true
     }
+
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.0.source()
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
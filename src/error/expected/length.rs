@@ -81,6 +81,6 @@ impl<'i> ToRetryRequirement for ExpectedLength<'i> {
     /// Returns `true` if `max()` has a value.
     #[inline]
     fn is_fatal(&self) -> bool {
-        self.input.is_bound() || self.len().max().is_some()
+        self.input.bound().is_end_bound() || self.len().max().is_some()
     }
 }
@@ -3,19 +3,21 @@ mod valid;
 mod value;
 
 pub use self::length::ExpectedLength;
-pub use self::valid::ExpectedValid;
+pub use self::valid::{ExpectedValid, InvalidReason};
 pub use self::value::ExpectedValue;
 
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
 use crate::display::ErrorDisplay;
+#[cfg(feature = "std")]
+use crate::error::context::SourceContext;
 use crate::error::{
-    Backtrace, BacktraceBuilder, Context, Details, RetryRequirement, ToRetryRequirement, Value,
-    WithContext,
+    Backtrace, BacktraceBuilder, Context, CoreContext, CoreExpected, CoreOperation, Details, Fatal,
+    Invalid, RetryRequirement, ToRetryRequirement, Value, WithContext,
 };
 use crate::fmt;
-use crate::input::{Input, MaybeString};
+use crate::input::{Bound, Bytes, Input, MaybeString};
 
 #[cfg(feature = "full-backtrace")]
 type ExpectedBacktrace = crate::error::FullBacktrace;
@@ -28,7 +30,7 @@ type ExpectedBacktrace = crate::error::RootBacktrace;
 ///   all contexts with [`Expected`].
 /// - It is generally recommended for better performance to box `Expected` if
 ///   the structures being returned from parsing are smaller than or equal to
-///   `~128 bytes`. This is because the `Expected` structure is `192 - 216
+///   `~128 bytes`. This is because the `Expected` structure is `184 - 208
 ///   bytes` large on 64 bit systems and successful parses may be hindered by
 ///   the time to move the `Result<T, Expected>` value. By boxing `Expected` the
 ///   size becomes only `8 bytes`. When in doubt, write a benchmark.
@@ -162,6 +164,12 @@ where
         self.add_context(context);
         self
     }
+
+    #[cfg(feature = "std")]
+    fn with_source(mut self, source: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        self.add_context(SourceContext(source));
+        self
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -180,6 +188,12 @@ where
         self.add_context(context);
         self
     }
+
+    #[cfg(feature = "std")]
+    fn with_source(mut self, source: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        self.add_context(SourceContext(source));
+        self
+    }
 }
 
 impl<'i, S> fmt::Debug for Expected<'i, S>
@@ -257,6 +271,51 @@ where
     }
 }
 
+/// Converts a [`Fatal`] error into an `Expected` error.
+///
+/// [`Fatal`] carries no details, so the resulting error has no span, input or
+/// backtrace beyond the conversion itself and simply reports invalid input.
+/// To keep the details, re-read the input with `Expected` instead, see
+/// [`Reader::error()`](crate::Reader::error()).
+impl<S> From<Fatal> for Expected<'_, S>
+where
+    S: BacktraceBuilder,
+{
+    fn from(_: Fatal) -> Self {
+        Self::from(detailless(None))
+    }
+}
+
+/// Converts an [`Invalid`] error into an `Expected` error.
+///
+/// As with the conversion from [`Fatal`], all detail but the
+/// [`RetryRequirement`] is lost.
+impl<S> From<Invalid> for Expected<'_, S>
+where
+    S: BacktraceBuilder,
+{
+    fn from(err: Invalid) -> Self {
+        Self::from(detailless(err.to_retry_requirement()))
+    }
+}
+
+fn detailless(retry_requirement: Option<RetryRequirement>) -> ExpectedValid<'static> {
+    let input = Bytes::new(&[], Bound::Start);
+    ExpectedValid {
+        reason: match retry_requirement {
+            Some(_) => InvalidReason::Truncated,
+            None => InvalidReason::Malformed,
+        },
+        retry_requirement,
+        context: CoreContext {
+            span: input.span(),
+            operation: CoreOperation::FromDetailless,
+            expected: CoreExpected::Valid("valid input"),
+        },
+        input: input.into_maybe_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -265,13 +324,13 @@ mod tests {
     #[cfg(all(target_pointer_width = "64", not(feature = "full-backtrace")))]
     fn test_expected_size() {
         // Update the docs if this value changes.
-        assert_eq!(core::mem::size_of::<Expected<'_>>(), 192);
+        assert_eq!(core::mem::size_of::<Expected<'_>>(), 184);
     }
 
     #[test]
     #[cfg(all(target_pointer_width = "64", feature = "full-backtrace"))]
     fn test_expected_size() {
         // Update the docs if this value changes.
-        assert_eq!(core::mem::size_of::<Expected<'_>>(), 216);
+        assert_eq!(core::mem::size_of::<Expected<'_>>(), 208);
     }
 }
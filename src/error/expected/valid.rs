@@ -6,12 +6,37 @@ use crate::input::MaybeString;
 /// [`Input`](crate::Input).
 #[must_use = "error must be handled"]
 pub struct ExpectedValid<'i> {
+    pub(crate) reason: InvalidReason,
     pub(crate) retry_requirement: Option<RetryRequirement>,
     pub(crate) context: CoreContext,
     pub(crate) input: MaybeString<'i>,
 }
 
+/// The reason [`Input`](crate::Input) was not valid.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InvalidReason {
+    /// The input is structurally wrong and more input won't make it valid.
+    Malformed,
+    /// The input was cut short of being valid.
+    ///
+    /// Whether more input can be provided depends on the input
+    /// [`Bound`](crate::Bound), see [`ToRetryRequirement::is_fatal()`].
+    Truncated,
+    /// The input is well formed but its value is out of range.
+    Overflow,
+}
+
 impl<'i> ExpectedValid<'i> {
+    /// The reason the input was not valid.
+    ///
+    /// Unlike [`ToRetryRequirement::is_fatal()`], this describes the failure
+    /// itself regardless of whether the input is bound.
+    #[must_use]
+    #[inline(always)]
+    pub fn reason(&self) -> InvalidReason {
+        self.reason
+    }
+
     /// The [`CoreContext`] around the error.
     #[inline(always)]
     #[must_use]
@@ -31,6 +56,7 @@ impl<'i> fmt::Debug for ExpectedValid<'i> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut debug = f.debug_struct("ExpectedValid");
 
+        debug.field("reason", &self.reason);
         debug.field("retry_requirement", &self.retry_requirement);
         debug.field("context", &self.context().debug_for(self.input()));
         debug.field("input", &self.input());
@@ -64,6 +90,6 @@ impl<'i> ToRetryRequirement for ExpectedValid<'i> {
 
     #[inline]
     fn is_fatal(&self) -> bool {
-        self.input.is_bound() || self.retry_requirement.is_none()
+        self.input.bound().is_end_bound() || self.retry_requirement.is_none()
     }
 }
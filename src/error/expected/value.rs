@@ -31,6 +31,24 @@ impl<'i> ExpectedValue<'i> {
     pub fn input(&self) -> MaybeString<'i> {
         self.input.clone()
     }
+
+    /// Returns the byte offset within the expected value where the input
+    /// found first differed from it.
+    ///
+    /// Returns `None` if the input found matched the expected value up until
+    /// it ended.
+    #[must_use]
+    pub fn divergence_offset(&self) -> Option<usize> {
+        let found = self
+            .context
+            .span
+            .of(self.input.as_dangerous_bytes())
+            .unwrap_or_default();
+        found
+            .iter()
+            .zip(self.expected.as_bytes())
+            .position(|(found, expected)| found != expected)
+    }
 }
 
 impl<'i> fmt::Debug for ExpectedValue<'i> {
@@ -45,7 +63,9 @@ impl<'i> fmt::Debug for ExpectedValue<'i> {
 
 impl<'i> fmt::DisplayBase for ExpectedValue<'i> {
     fn fmt(&self, w: &mut dyn fmt::Write) -> fmt::Result {
-        if self.is_fatal() {
+        if self.expected.set_kind().is_some() {
+            w.write_str("found no byte matching the expected set")
+        } else if self.is_fatal() {
             w.write_str("found a different value to the exact expected")
         } else {
             w.write_str("not enough input to match expected value")
@@ -75,7 +95,7 @@ impl<'i> ToRetryRequirement for ExpectedValue<'i> {
     /// was incomplete.
     #[inline]
     fn is_fatal(&self) -> bool {
-        if self.input.is_bound() {
+        if self.input.bound().is_end_bound() {
             return true;
         }
         match self.context.span.of(self.input.as_dangerous_bytes()) {
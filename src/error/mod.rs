@@ -29,18 +29,25 @@ mod retry;
 mod traits;
 mod value;
 
+pub use self::backtrace::{
+    Backtrace, BacktraceBuilder, BacktraceFrame, BacktraceFrames, BacktraceWalker, RootBacktrace,
+    StackBacktrace,
+};
+#[cfg(feature = "alloc")]
+pub use self::backtrace::{FullBacktrace, LimitedBacktrace};
 #[cfg(feature = "alloc")]
-pub use self::backtrace::FullBacktrace;
-pub use self::backtrace::{Backtrace, BacktraceBuilder, BacktraceWalker, RootBacktrace};
+pub use self::context::ValueContext;
 pub use self::context::{
     Context, CoreContext, CoreExpected, CoreOperation, ExternalContext, Operation, WithChildContext,
 };
-pub use self::expected::{Expected, ExpectedLength, ExpectedValid, ExpectedValue};
+pub use self::expected::{Expected, ExpectedLength, ExpectedValid, ExpectedValue, InvalidReason};
 pub use self::fatal::Fatal;
 pub use self::invalid::Invalid;
 pub use self::length::Length;
 pub use self::retry::{RetryRequirement, ToRetryRequirement};
 pub use self::traits::{Details, Error, External, WithContext};
 pub use self::value::Value;
+#[cfg(feature = "serde")]
+pub use crate::support::serde::SerializeDetails;
 
 pub(crate) use self::context::with_context;
@@ -1,3 +1,4 @@
+use core::mem;
 use core::num::NonZeroUsize;
 
 use crate::display::byte_count;
@@ -30,6 +31,41 @@ impl RetryRequirement {
         Self::new(needed.saturating_sub(had))
     }
 
+    /// Create a retry requirement for the byte size of `T`.
+    ///
+    /// Returns `None` if `T` is zero sized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::error::RetryRequirement;
+    ///
+    /// assert_eq!(RetryRequirement::for_struct::<u32>(), RetryRequirement::new(4));
+    /// assert_eq!(RetryRequirement::for_struct::<()>(), None);
+    /// ```
+    #[must_use]
+    pub fn for_struct<T>() -> Option<Self> {
+        Self::new(mem::size_of::<T>())
+    }
+
+    /// Create a retry requirement for the byte size of a slice of `len`
+    /// elements of `T`, saturating on overflow.
+    ///
+    /// Returns `None` if the slice is zero sized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::error::RetryRequirement;
+    ///
+    /// assert_eq!(RetryRequirement::for_slice::<u16>(3), RetryRequirement::new(6));
+    /// assert_eq!(RetryRequirement::for_slice::<u16>(0), None);
+    /// ```
+    #[must_use]
+    pub fn for_slice<T>(len: usize) -> Option<Self> {
+        Self::new(mem::size_of::<T>().saturating_mul(len))
+    }
+
     /// Create a retry requirement from a count of how many bytes are required
     /// to continue processing input.
     pub fn from_continue_after(continue_after: NonZeroUsize) -> Self {
@@ -52,6 +88,57 @@ impl RetryRequirement {
     pub fn continue_after_non_zero(self) -> NonZeroUsize {
         self.0
     }
+
+    /// Returns `true` if `available` additional bytes meet the requirement.
+    #[must_use]
+    pub fn met_by(self, available: usize) -> bool {
+        available >= self.continue_after()
+    }
+
+    /// Combines two requirements that both have to be met, such as for a
+    /// header and a body, saturating on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::error::RetryRequirement;
+    ///
+    /// let header = RetryRequirement::for_struct::<u32>().unwrap();
+    /// let body = RetryRequirement::for_slice::<u8>(12).unwrap();
+    /// let both = header.saturating_add(body);
+    ///
+    /// assert!(!both.met_by(12));
+    /// assert!(both.met_by(16));
+    /// ```
+    pub fn saturating_add(self, other: Self) -> Self {
+        // A saturated add of two non-zero values can never be zero.
+        Self::new(self.continue_after().saturating_add(other.continue_after())).unwrap_or(self)
+    }
+
+    /// Returns the larger of two optional requirements.
+    ///
+    /// `None` is only returned if both are `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::error::RetryRequirement;
+    ///
+    /// let a = RetryRequirement::new(1);
+    /// let b = RetryRequirement::new(4);
+    ///
+    /// assert_eq!(RetryRequirement::max(a, b), b);
+    /// assert_eq!(RetryRequirement::max(a, None), a);
+    /// assert_eq!(RetryRequirement::max(None, None), None);
+    /// ```
+    #[must_use]
+    pub fn max(a: Option<Self>, b: Option<Self>) -> Option<Self> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(Self(a.0.max(b.0))),
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
 }
 
 impl fmt::DisplayBase for RetryRequirement {
@@ -83,6 +170,35 @@ pub trait ToRetryRequirement {
     fn is_fatal(&self) -> bool {
         self.to_retry_requirement().is_none()
     }
+
+    /// Returns the largest requirement of `self` and `others`, ignoring any
+    /// that are fatal.
+    ///
+    /// `None` is only returned if all are fatal. This is useful when several
+    /// alternatives failed, to request enough input to retry any of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid, ToRetryRequirement};
+    /// use dangerous::error::RetryRequirement;
+    ///
+    /// let input = dangerous::input(b"ab");
+    /// let short: Invalid = input.clone().read_partial(|r| r.take(3)).unwrap_err();
+    /// let longer: Invalid = input.clone().read_partial(|r| r.take(6)).unwrap_err();
+    /// let fatal: Invalid = input.read_partial(|r| r.consume(b"x")).unwrap_err();
+    ///
+    /// assert_eq!(short.combine_max(&[&longer, &fatal]), RetryRequirement::new(4));
+    /// assert_eq!(fatal.combine_max(&[]), None);
+    /// ```
+    fn combine_max(&self, others: &[&dyn ToRetryRequirement]) -> Option<RetryRequirement> {
+        others
+            .iter()
+            .filter(|other| !other.is_fatal())
+            .fold(self.to_retry_requirement(), |max, other| {
+                RetryRequirement::max(max, other.to_retry_requirement())
+            })
+    }
 }
 
 impl ToRetryRequirement for RetryRequirement {
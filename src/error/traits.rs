@@ -46,6 +46,25 @@ pub trait WithContext<'i>: Sized {
     /// This method is used for adding contexts to errors bubbling up.
     #[must_use]
     fn with_context(self, context: impl Context) -> Self;
+
+    /// Return `Self` with the source of an [`External`] error.
+    ///
+    /// This is called by [`External::push_backtrace()`] implementations that
+    /// wish to preserve the original error. Defaults to discarding the source.
+    ///
+    /// [`Expected`] holds the source as a child context in its backtrace, so
+    /// it is only preserved by backtraces that keep the contexts pushed, such
+    /// as [`FullBacktrace`].
+    ///
+    /// [`Expected`]: crate::Expected
+    /// [`FullBacktrace`]: crate::error::FullBacktrace
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[must_use]
+    fn with_source(self, source: alloc::boxed::Box<dyn std::error::Error + Send + Sync>) -> Self {
+        let _ = source;
+        self
+    }
 }
 
 /// Required details around an error to produce a verbose report on what went
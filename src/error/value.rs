@@ -2,7 +2,9 @@ use core::slice;
 
 use crate::display::InputDisplay;
 use crate::fmt;
-use crate::input::{Bound, Bytes, Input};
+use crate::input::{AnyOf, Bound, Bytes, FnPattern, Input, NoneOf};
+#[cfg(feature = "serde")]
+use crate::util::utf8;
 use crate::util::utf8::CharBytes;
 
 /// Value that was expected in an operation.
@@ -16,25 +18,58 @@ enum ValueInner<'i> {
     Char(CharBytes),
     Bytes(&'i [u8]),
     String(&'i str),
+    AnyOf(&'i [u8]),
+    NoneOf(&'i [u8]),
 }
 
 impl<'i> Value<'i> {
     /// Returns the value as bytes.
+    ///
+    /// If a byte set was expected, such as with [`AnyOf`] or [`NoneOf`], these
+    /// are the bytes of the set.
     #[must_use]
     pub fn as_bytes(&self) -> &[u8] {
         match &self.0 {
             ValueInner::Byte(v) => slice::from_ref(v),
             ValueInner::Char(v) => v.as_bytes(),
-            ValueInner::Bytes(v) => v,
+            ValueInner::Bytes(v) | ValueInner::AnyOf(v) | ValueInner::NoneOf(v) => v,
             ValueInner::String(v) => v.as_bytes(),
         }
     }
 
+    /// Returns how the bytes of a byte set were expected, or `None` if the
+    /// value was expected exactly.
+    pub(crate) fn set_kind(&self) -> Option<&'static str> {
+        match self.0 {
+            ValueInner::AnyOf(_) => Some("any of"),
+            ValueInner::NoneOf(_) => Some("none of"),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a string slice if it was created from a `char` or
+    /// `&str`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match &self.0 {
+            // SAFETY: char bytes are always valid UTF-8.
+            ValueInner::Char(v) => Some(unsafe { utf8::from_unchecked(v.as_bytes()) }),
+            ValueInner::String(v) => Some(v),
+            ValueInner::Byte(_)
+            | ValueInner::Bytes(_)
+            | ValueInner::AnyOf(_)
+            | ValueInner::NoneOf(_) => None,
+        }
+    }
+
     /// Returns an [`InputDisplay`] for formatting.
     pub fn display(&self) -> InputDisplay<'_> {
         let display = Bytes::new(self.as_bytes(), Bound::StartEnd).display();
         match self.0 {
-            ValueInner::Byte(_) | ValueInner::Bytes(_) => display,
+            ValueInner::Byte(_)
+            | ValueInner::Bytes(_)
+            | ValueInner::AnyOf(_)
+            | ValueInner::NoneOf(_) => display,
             ValueInner::Char(_) | ValueInner::String(_) => display.str_hint(),
         }
     }
@@ -47,6 +82,8 @@ impl<'i> fmt::Debug for Value<'i> {
             ValueInner::Char(_) => "Char",
             ValueInner::Bytes(_) => "Bytes",
             ValueInner::String(_) => "String",
+            ValueInner::AnyOf(_) => "AnyOf",
+            ValueInner::NoneOf(_) => "NoneOf",
         };
         let display = self.display().with_formatter(f);
         f.debug_tuple(name).field(&display).finish()
@@ -79,6 +116,43 @@ impl<'i> From<&'i str> for Value<'i> {
     }
 }
 
+impl<'i> From<AnyOf<'i>> for Value<'i> {
+    #[inline(always)]
+    fn from(v: AnyOf<'i>) -> Self {
+        Self(ValueInner::AnyOf(v.0))
+    }
+}
+
+impl<'i> From<NoneOf<'i>> for Value<'i> {
+    #[inline(always)]
+    fn from(v: NoneOf<'i>) -> Self {
+        Self(ValueInner::NoneOf(v.0))
+    }
+}
+
+impl<F> From<FnPattern<F>> for Value<'_> {
+    #[inline(always)]
+    fn from(v: FnPattern<F>) -> Self {
+        Self(ValueInner::String(v.description))
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<'i> From<&'i regex::Regex> for Value<'i> {
+    #[inline(always)]
+    fn from(v: &'i regex::Regex) -> Self {
+        Self(ValueInner::String(v.as_str()))
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<'i> From<&'i regex::bytes::Regex> for Value<'i> {
+    #[inline(always)]
+    fn from(v: &'i regex::bytes::Regex) -> Self {
+        Self(ValueInner::String(v.as_str()))
+    }
+}
+
 impl<'i, const N: usize> From<&'i [u8; N]> for Value<'i> {
     #[inline(always)]
     fn from(v: &'i [u8; N]) -> Self {
@@ -10,11 +10,28 @@ pub enum Bound {
     ///
     /// The end of the [`Input`](crate::Input) may however change in further passes.
     Start,
+    /// The end of the [`Input`](crate::Input) in further passes will not change.
+    ///
+    /// The start of the [`Input`](crate::Input) may however change in further
+    /// passes.
+    End,
     /// Both sides of the [`Input`](crate::Input) in further passes will not change.
     StartEnd,
 }
 
 impl Bound {
+    /// Returns `true` if the start will not change in further passes.
+    #[inline(always)]
+    pub(crate) fn is_start_bound(self) -> bool {
+        matches!(self, Bound::Start | Bound::StartEnd)
+    }
+
+    /// Returns `true` if the end will not change in further passes.
+    #[inline(always)]
+    pub(crate) fn is_end_bound(self) -> bool {
+        matches!(self, Bound::End | Bound::StartEnd)
+    }
+
     #[inline(always)]
     pub(crate) fn force_close() -> Self {
         Bound::StartEnd
@@ -28,7 +45,16 @@ impl Bound {
             // If at least the start is bound make sure the end is unbound.
             Bound::StartEnd | Bound::Start => Bound::Start,
             // If the start is unbound both sides of the input are unbound.
-            Bound::None => Bound::None,
+            Bound::End | Bound::None => Bound::None,
+        }
+    }
+
+    /// An end is bound when it is known no more input will follow.
+    #[inline(always)]
+    pub(crate) fn bind_end(self) -> Self {
+        match self {
+            Bound::StartEnd | Bound::Start => Bound::StartEnd,
+            Bound::End | Bound::None => Bound::End,
         }
     }
 
@@ -45,11 +71,23 @@ impl Bound {
         Bound::force_close()
     }
 
+    /// Joins the bounds of two adjacent inputs, taking the start bound of
+    /// `self` and the end bound of `end`.
+    #[inline(always)]
+    pub(crate) fn join(self, end: Self) -> Self {
+        match (self.is_start_bound(), end.is_end_bound()) {
+            (true, true) => Bound::StartEnd,
+            (true, false) => Bound::Start,
+            (false, true) => Bound::End,
+            (false, false) => Bound::None,
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn for_end(self) -> Self {
         match self {
-            // If both sides are bounded nothing will change.
-            Bound::StartEnd => Bound::StartEnd,
+            // If the end is bounded nothing will change.
+            Bound::StartEnd | Bound::End => self,
             // As we have skipped to the end without checking, we don't know
             // where the start is, perhaps the true end is not known yet!
             Bound::Start | Bound::None => Bound::None,
@@ -28,6 +28,14 @@ unsafe impl ByteLength for u8 {
     }
 }
 
+unsafe impl ByteLength for u16 {
+    /// Always returns `2`.
+    #[inline(always)]
+    fn byte_len(&self) -> usize {
+        2
+    }
+}
+
 unsafe impl ByteLength for char {
     /// Returns the length of bytes for the UTF-8 character.
     #[inline(always)]
@@ -2,6 +2,30 @@ use crate::display::InputDisplay;
 use crate::{fmt, Bound, Bytes, Input, Span};
 
 /// Fixed size byte array taken from [`Input`].
+///
+/// A `ByteArray` borrows `N` bytes from the input rather than copying them,
+/// keeping zero-copy semantics while guaranteeing the length at compile time.
+/// It is returned by [`BytesReader::take_array()`] and
+/// [`BytesReader::read_byte_array()`], whereas [`BytesReader::read_array()`]
+/// copies the bytes out into an owned `[u8; N]`.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{ByteArray, Input, Invalid};
+///
+/// let result: Result<_, Invalid> = dangerous::input(b"\x7fELF").read_all(|r| {
+///     r.read_byte_array::<4>()
+/// });
+/// let magic: ByteArray<'_, 4> = result.unwrap();
+///
+/// assert_eq!(magic.as_dangerous(), b"\x7fELF");
+/// assert_eq!(magic.into_array(), *b"\x7fELF");
+/// ```
+///
+/// [`BytesReader::take_array()`]: crate::BytesReader::take_array()
+/// [`BytesReader::read_byte_array()`]: crate::BytesReader::read_byte_array()
+/// [`BytesReader::read_array()`]: crate::BytesReader::read_array()
 #[derive(Clone)]
 pub struct ByteArray<'i, const N: usize>(&'i [u8; N]);
 
@@ -30,6 +54,16 @@ impl<'i, const N: usize> ByteArray<'i, N> {
         *self.as_dangerous()
     }
 
+    /// Returns the underlying byte array.
+    ///
+    /// This will copy the bytes from the reference and is equivalent to
+    /// [`ByteArray::into_dangerous()`].
+    #[must_use]
+    #[inline(always)]
+    pub fn into_array(self) -> [u8; N] {
+        self.into_dangerous()
+    }
+
     /// Consumes `self` into [`Bytes`].
     #[inline(always)]
     pub fn into_bytes(self) -> Bytes<'i> {
@@ -0,0 +1,48 @@
+use core::iter::FusedIterator;
+
+use crate::input::{Bytes, Input, Pattern};
+
+/// Iterator over the non-overlapping matches of a [`Pattern`] within
+/// [`Bytes`].
+///
+/// Created from [`Bytes::match_indices()`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MatchIndices<'i, P> {
+    input: Bytes<'i>,
+    pattern: P,
+    offset: usize,
+}
+
+impl<'i, P> MatchIndices<'i, P> {
+    pub(super) fn new(input: Bytes<'i>, pattern: P) -> Self {
+        Self {
+            input,
+            pattern,
+            offset: 0,
+        }
+    }
+}
+
+impl<'i, P> Iterator for MatchIndices<'i, P>
+where
+    P: Pattern<Bytes<'i>> + Copy,
+{
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.input.as_dangerous();
+        let remaining = Bytes::new(bytes.get(self.offset..)?, self.input.bound());
+        if let Some((index, len)) = self.pattern.find_match(&remaining) {
+            let index = self.offset + index;
+            // An empty match still has to advance, or we would return the same
+            // match forever.
+            self.offset = index + len.max(1);
+            Some((index, len))
+        } else {
+            self.offset = bytes.len() + 1;
+            None
+        }
+    }
+}
+
+impl<'i, P> FusedIterator for MatchIndices<'i, P> where P: Pattern<Bytes<'i>> + Copy {}
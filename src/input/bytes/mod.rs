@@ -1,23 +1,31 @@
 mod array;
+mod match_indices;
 mod pattern;
 mod prefix;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::slice::Iter as SliceIter;
 use core::{iter, str};
 
 use crate::display::InputDisplay;
 use crate::error::{
-    with_context, CoreContext, CoreExpected, CoreOperation, ExpectedLength, ExpectedValid, Length,
-    WithContext,
+    with_context, CoreContext, CoreExpected, CoreOperation, ExpectedLength, ExpectedValid,
+    ExpectedValue, InvalidReason, Length, WithContext,
 };
 use crate::fmt;
+use crate::reader::Eol;
 use crate::util::{fast, slice, utf8};
 
-use super::{Bound, Input, MaybeString, Private, PrivateExt, String};
+use super::{AnyOf, Bound, Input, MaybeString, Pattern, Private, PrivateExt, String};
 
 pub use self::array::ByteArray;
+pub use self::match_indices::MatchIndices;
 
 /// Raw [`Input`].
+///
+/// Equality compares the content only, ignoring the [`Bound`]. See
+/// [`Input::value_eq()`].
 #[derive(Clone)]
 #[must_use = "input must be consumed"]
 pub struct Bytes<'i> {
@@ -53,6 +61,72 @@ impl<'i> Bytes<'i> {
         fast::count_u8(needle, self.as_dangerous())
     }
 
+    /// Returns the occurrences of `needle` within the underlying byte slice,
+    /// always using the portable implementation.
+    ///
+    /// Unlike [`Bytes::count()`] this never dispatches to `bytecount`, which is
+    /// useful for benchmarking and testing across feature configurations.
+    #[must_use]
+    pub fn count_scalar(&self, needle: u8) -> usize {
+        fast::count_u8_scalar(needle, self.as_dangerous())
+    }
+
+    /// Returns an iterator over the non-overlapping matches of a pattern.
+    ///
+    /// Each item is the `(index, len)` of a match, both in bytes and relative
+    /// to the start of the input. Searching for the next match continues from
+    /// the end of the previous one, so overlapping matches are never returned.
+    /// This is analogous to [`str::match_indices()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let input = dangerous::input(b"a,b,,c");
+    /// let indices: Vec<_> = input.match_indices(b',').collect();
+    ///
+    /// assert_eq!(indices, [(1, 1), (3, 1), (4, 1)]);
+    /// ```
+    pub fn match_indices<P>(&self, pattern: P) -> MatchIndices<'i, P>
+    where
+        P: Pattern<Bytes<'i>> + Copy,
+    {
+        MatchIndices::new(self.clone(), pattern)
+    }
+
+    /// Concatenates the underlying byte slices of inputs into an owned buffer.
+    ///
+    /// This is useful for reassembling a value that straddles several reads of
+    /// a stream, for example after a [`RetryRequirement`] was returned. The
+    /// result is owned and must be passed to [`dangerous::input()`] to be
+    /// parsed again, so any spans from the new input are relative to the
+    /// concatenated buffer, not the original inputs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Bytes, Input, Invalid};
+    ///
+    /// let buf = Bytes::concat(&[dangerous::input(b"hel"), dangerous::input(b"lo")]);
+    /// let result: Result<_, Invalid> = dangerous::input(&buf[..]).read_all(|r| r.consume(b"hello"));
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    ///
+    /// [`RetryRequirement`]: crate::error::RetryRequirement
+    /// [`dangerous::input()`]: crate::input()
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn concat(inputs: &[Bytes<'_>]) -> Vec<u8> {
+        let len = inputs.iter().map(Bytes::len).sum();
+        let mut buf = Vec::with_capacity(len);
+        for input in inputs {
+            buf.extend_from_slice(input.as_dangerous());
+        }
+        buf
+    }
+
     /// Returns the underlying byte slice.
     ///
     /// The naming of this function is to a degree hyperbole, and should not be
@@ -103,6 +177,12 @@ impl<'i> Input<'i> for Bytes<'i> {
         self
     }
 
+    #[inline(always)]
+    fn into_bound_end(mut self) -> Self {
+        self.bound = self.bound.bind_end();
+        self
+    }
+
     #[inline(always)]
     fn into_bytes(self) -> Bytes<'i> {
         self
@@ -138,6 +218,16 @@ impl<'i> Input<'i> for Bytes<'i> {
             (head, tail)
         })
     }
+
+    #[inline(always)]
+    fn join(self, other: Self, parent: &Self) -> Option<Self> {
+        slice::join_within(
+            parent.as_dangerous(),
+            self.as_dangerous(),
+            other.as_dangerous(),
+        )
+        .map(|bytes| Bytes::new(bytes, self.bound().join(other.bound())))
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -262,6 +352,299 @@ impl<'i> Bytes<'i> {
         })
     }
 
+    #[inline(always)]
+    pub(crate) fn split_while_into<P, E>(
+        self,
+        out: &mut [u8],
+        pattern: P,
+        operation: CoreOperation,
+    ) -> Result<(usize, Bytes<'i>), E>
+    where
+        E: From<ExpectedValid<'i>>,
+        P: Pattern<Bytes<'i>>,
+    {
+        let (head, tail) = match self.clone().split_while_opt(pattern) {
+            Some(split) => split,
+            None => (self.clone(), self.clone().end()),
+        };
+        let matched = head.as_dangerous();
+        if let Some(out) = out.get_mut(..matched.len()) {
+            out.copy_from_slice(matched);
+            Ok((matched.len(), tail))
+        } else {
+            Err(E::from(ExpectedValid {
+                reason: InvalidReason::Overflow,
+                retry_requirement: None,
+                context: CoreContext {
+                    span: matched.into(),
+                    operation,
+                    expected: CoreExpected::Valid("input within buffer capacity"),
+                },
+                input: self.into_maybe_string(),
+            }))
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn split_until_any_for<E>(
+        self,
+        delimiters: &'i [u8],
+        operation: CoreOperation,
+    ) -> Result<((Bytes<'i>, u8), Bytes<'i>), E>
+    where
+        E: From<ExpectedValue<'i>>,
+    {
+        let bytes = self.as_dangerous();
+        match bytes.iter().position(|b| delimiters.contains(b)) {
+            Some(index) => {
+                // SAFETY: the index was returned from the position of a byte
+                // within the input.
+                let (head, tail) = unsafe { self.split_at_byte_unchecked(index) };
+                Ok(((head, bytes[index]), tail))
+            }
+            None => Err(E::from(ExpectedValue {
+                expected: AnyOf(delimiters).into(),
+                context: CoreContext {
+                    span: self.span(),
+                    operation,
+                    expected: CoreExpected::PatternMatch,
+                },
+                input: self.into_maybe_string(),
+            })),
+        }
+    }
+
+    /// Splits a LEB128 encoded integer from the input of at most 64 bits.
+    ///
+    /// If `signed`, the value is sign extended to 64 bits.
+    pub(crate) fn split_leb128_for<E>(
+        self,
+        signed: bool,
+        operation: CoreOperation,
+    ) -> Result<(u64, Bytes<'i>), E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        let bytes = self.as_dangerous();
+        let mut value = 0;
+        for (i, &b) in bytes.iter().enumerate() {
+            let low = u64::from(b & 0x7F);
+            let shift = 7 * i;
+            let last = b & 0x80 == 0;
+            // The tenth byte holds the final bit of a 64 bit integer. For
+            // unsigned integers the other bits must be unset, for signed
+            // integers they must all match the final (sign) bit.
+            let valid_final = if signed {
+                low == 0 || low == 0x7F
+            } else {
+                low <= 1
+            };
+            if i == 9 && !(last && valid_final) {
+                return Err(E::from(ExpectedValid {
+                    reason: InvalidReason::Overflow,
+                    retry_requirement: None,
+                    context: CoreContext {
+                        span: bytes[..=i].into(),
+                        operation,
+                        expected: CoreExpected::Valid("varint within 64 bits"),
+                    },
+                    input: self.into_maybe_string(),
+                }));
+            }
+            value |= low << shift;
+            if last {
+                if signed && shift + 7 < 64 && b & 0x40 != 0 {
+                    value |= !0 << (shift + 7);
+                }
+                // SAFETY: `i` is the index of a byte within the input, so
+                // `i + 1` is at most the input length.
+                let (_, tail) = unsafe { self.split_at_byte_unchecked(i + 1) };
+                return Ok((value, tail));
+            }
+        }
+        Err(E::from(ExpectedLength {
+            len: Length::AtLeast(bytes.len() + 1),
+            context: CoreContext {
+                span: self.span(),
+                operation,
+                expected: CoreExpected::EnoughInputFor("varint"),
+            },
+            input: self.into_maybe_string(),
+        }))
+    }
+
+    /// Decodes `nibbles` ASCII hex digits into `out`, which must be half the
+    /// length of `nibbles`.
+    pub(crate) fn split_hex_into_for<E>(
+        self,
+        out: &mut [u8],
+        nibbles: usize,
+        operation: CoreOperation,
+    ) -> Result<((), Bytes<'i>), E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        let bytes = self.as_dangerous();
+        let invalid = |span: &[u8], expected| {
+            E::from(ExpectedValid {
+                reason: InvalidReason::Malformed,
+                retry_requirement: None,
+                context: CoreContext {
+                    span: span.into(),
+                    operation,
+                    expected: CoreExpected::Valid(expected),
+                },
+                input: self.clone().into_maybe_string(),
+            })
+        };
+        if nibbles % 2 != 0 {
+            return Err(invalid(
+                &bytes[..nibbles.min(bytes.len())],
+                "even number of hex digits",
+            ));
+        }
+        if bytes.len() < nibbles {
+            return Err(E::from(ExpectedLength {
+                len: Length::AtLeast(nibbles),
+                context: CoreContext {
+                    span: self.span(),
+                    operation,
+                    expected: CoreExpected::EnoughInputFor("hex digits"),
+                },
+                input: self.into_maybe_string(),
+            }));
+        }
+        let digits = &bytes[..nibbles];
+        for (i, pair) in digits.chunks_exact(2).enumerate() {
+            let mut byte = 0;
+            for (j, &digit) in pair.iter().enumerate() {
+                let index = i * 2 + j;
+                let value = (digit as char)
+                    .to_digit(16)
+                    .ok_or_else(|| invalid(&digits[index..=index], "hex digit"))?;
+                #[allow(clippy::cast_possible_truncation)]
+                let value = value as u8;
+                byte = (byte << 4) | value;
+            }
+            out[i] = byte;
+        }
+        // SAFETY: the input was checked to have at least `nibbles` bytes.
+        let (_, tail) = unsafe { self.split_at_byte_unchecked(nibbles) };
+        Ok(((), tail))
+    }
+
+    /// Decodes the input as UTF-16 code units, each read from two bytes with
+    /// `unit_from_bytes`.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn decode_utf16_for<E>(
+        self,
+        unit_from_bytes: fn([u8; 2]) -> u16,
+        operation: CoreOperation,
+    ) -> Result<alloc::string::String, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        let bytes = self.as_dangerous();
+        let unit_at = |i: usize| unit_from_bytes([bytes[i], bytes[i + 1]]);
+        let too_short = |i: usize, len| {
+            E::from(ExpectedLength {
+                len: Length::AtLeast(len),
+                context: CoreContext {
+                    span: bytes[i..].into(),
+                    operation,
+                    expected: CoreExpected::EnoughInputFor("utf-16 code point"),
+                },
+                input: self.clone().into_maybe_string(),
+            })
+        };
+        let unpaired = |i: usize| {
+            E::from(ExpectedValid {
+                reason: InvalidReason::Malformed,
+                retry_requirement: None,
+                context: CoreContext {
+                    span: bytes[i..i + 2].into(),
+                    operation,
+                    expected: CoreExpected::Valid("utf-16 code point"),
+                },
+                input: self.clone().into_maybe_string(),
+            })
+        };
+        let mut out = alloc::string::String::with_capacity(bytes.len() / 2);
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes.len() - i < 2 {
+                return Err(too_short(i, 2));
+            }
+            let unit = unit_at(i);
+            let (code_point, len) = match unit {
+                0xD800..=0xDBFF => {
+                    if bytes.len() - i < 4 {
+                        return Err(too_short(i, 4));
+                    }
+                    let low = unit_at(i + 2);
+                    if !(0xDC00..=0xDFFF).contains(&low) {
+                        return Err(unpaired(i));
+                    }
+                    let high = u32::from(unit - 0xD800) << 10;
+                    (0x10000 + (high | u32::from(low - 0xDC00)), 4)
+                }
+                0xDC00..=0xDFFF => return Err(unpaired(i)),
+                _ => (u32::from(unit), 2),
+            };
+            out.push(char::from_u32(code_point).ok_or_else(|| unpaired(i))?);
+            i += len;
+        }
+        Ok(out)
+    }
+
+    #[inline(always)]
+    pub(crate) fn split_eol_opt(self) -> Option<(Eol, Bytes<'i>)> {
+        let eol = match self.as_dangerous() {
+            [b'\n', ..] => Eol::Lf,
+            [b'\r', b'\n', ..] => Eol::CrLf,
+            _ => return None,
+        };
+        // SAFETY: the end of line was just matched at the start of the input
+        // so its length is a valid index.
+        let (_, tail) = unsafe { self.split_at_byte_unchecked(eol.as_bytes().len()) };
+        Some((eol, tail))
+    }
+
+    #[inline(always)]
+    pub(crate) fn split_eol_for<E>(
+        self,
+        operation: CoreOperation,
+        context_expected: CoreExpected,
+    ) -> Result<(Eol, Bytes<'i>), E>
+    where
+        E: From<ExpectedValue<'i>>,
+    {
+        if let Some(split) = self.clone().split_eol_opt() {
+            return Ok(split);
+        }
+        let bytes = self.as_dangerous();
+        let (expected, found) = match bytes {
+            // With no input, the shortest end of line could still follow.
+            [] => (Eol::Lf, 0),
+            // With only a `\r` we are waiting on the `\n` to follow, any
+            // other byte is invalid.
+            [_] => (Eol::CrLf, 1),
+            [_, _, ..] => (Eol::CrLf, 2),
+        };
+        Err(E::from(ExpectedValue {
+            expected: expected.as_bytes().into(),
+            context: CoreContext {
+                span: bytes[..found].into(),
+                operation,
+                expected: context_expected,
+            },
+            input: self.into_maybe_string(),
+        }))
+    }
+
     fn map_utf8_error<E>(
         self,
         error_len: Option<usize>,
@@ -293,6 +676,7 @@ impl<'i> Bytes<'i> {
             Some(error_len) => {
                 let error_end = valid_up_to + error_len;
                 E::from(ExpectedValid {
+                    reason: InvalidReason::Malformed,
                     retry_requirement: None,
                     context: CoreContext {
                         span: bytes[valid_up_to..error_end].into(),
@@ -331,6 +715,12 @@ impl<'i> Private<'i, u8> for Bytes<'i> {
         self
     }
 
+    #[inline(always)]
+    fn with_bound(mut self, bound: Bound) -> Self {
+        self.bound = bound;
+        self
+    }
+
     #[inline(always)]
     fn verify_token_boundary(&self, index: usize) -> Result<(), CoreExpected> {
         if index > self.len() {
@@ -388,6 +778,41 @@ impl<'i> PartialEq<Bytes<'i>> for [u8] {
     }
 }
 
+impl<'i> PartialEq<Bytes<'i>> for &[u8] {
+    #[inline(always)]
+    fn eq(&self, other: &Bytes<'i>) -> bool {
+        *self == other.as_dangerous()
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for Bytes<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.as_dangerous() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8; N]> for Bytes<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &&[u8; N]) -> bool {
+        self.as_dangerous() == *other
+    }
+}
+
+impl<'i, const N: usize> PartialEq<Bytes<'i>> for [u8; N] {
+    #[inline(always)]
+    fn eq(&self, other: &Bytes<'i>) -> bool {
+        self == other.as_dangerous()
+    }
+}
+
+impl<'i, const N: usize> PartialEq<Bytes<'i>> for &[u8; N] {
+    #[inline(always)]
+    fn eq(&self, other: &Bytes<'i>) -> bool {
+        *self == other.as_dangerous()
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Formatting
 
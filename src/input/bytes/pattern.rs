@@ -1,4 +1,4 @@
-use crate::input::{Bytes, Pattern};
+use crate::input::{AnyOf, Bytes, NoneOf, Pattern};
 use crate::util::fast;
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -74,6 +74,51 @@ unsafe impl<'i> Pattern<Bytes<'i>> for &str {
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Byte set patterns
+
+/// Bitmap of bytes for constant time membership checks.
+struct ByteSet([u64; 4]);
+
+impl ByteSet {
+    fn new(bytes: &[u8]) -> Self {
+        let mut set = [0; 4];
+        for &b in bytes {
+            set[usize::from(b >> 6)] |= 1 << (b & 0x3F);
+        }
+        Self(set)
+    }
+
+    #[inline(always)]
+    fn contains(&self, b: u8) -> bool {
+        self.0[usize::from(b >> 6)] & (1 << (b & 0x3F)) != 0
+    }
+}
+
+unsafe impl<'i> Pattern<Bytes<'i>> for AnyOf<'_> {
+    fn find_match(self, input: &Bytes<'i>) -> Option<(usize, usize)> {
+        let set = ByteSet::new(self.0);
+        (|b| set.contains(b)).find_match(input)
+    }
+
+    fn find_reject(self, input: &Bytes<'i>) -> Option<usize> {
+        let set = ByteSet::new(self.0);
+        (|b| set.contains(b)).find_reject(input)
+    }
+}
+
+unsafe impl<'i> Pattern<Bytes<'i>> for NoneOf<'_> {
+    fn find_match(self, input: &Bytes<'i>) -> Option<(usize, usize)> {
+        let set = ByteSet::new(self.0);
+        (|b| !set.contains(b)).find_match(input)
+    }
+
+    fn find_reject(self, input: &Bytes<'i>) -> Option<usize> {
+        let set = ByteSet::new(self.0);
+        (|b| !set.contains(b)).find_reject(input)
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Regex pattern
 
@@ -84,6 +129,17 @@ unsafe impl<'i> Pattern<Bytes<'i>> for &regex::bytes::Regex {
             .map(|m| (m.start(), m.end() - m.start()))
     }
 
+    fn find_match_last<'a>(self, input: &Bytes<'i>) -> Option<(usize, usize)>
+    where
+        Bytes<'i>: crate::Input<'a>,
+    {
+        // Searching from a later offset would change what anchors match, so
+        // the last of the non-overlapping matches is used instead.
+        regex::bytes::Regex::find_iter(self, input.as_dangerous())
+            .last()
+            .map(|m| (m.start(), m.end() - m.start()))
+    }
+
     fn find_reject(self, input: &Bytes<'i>) -> Option<usize> {
         let mut maybe_reject = 0;
         loop {
@@ -0,0 +1,175 @@
+//! [`io::Read`] support for streaming [`Input`].
+//!
+//! [`Input`]: crate::Input
+
+use std::io;
+use std::vec::Vec;
+
+use crate::error::{ToRetryRequirement, WithContext};
+use crate::reader::BytesReader;
+
+use super::{Bound, Bytes, Input};
+
+/// The minimum number of bytes to attempt to read from a stream at once.
+const MIN_READ_LEN: usize = 1024;
+
+/// A buffer that accumulates bytes read from an [`io::Read`].
+///
+/// The accumulated bytes are exposed as [`Bytes`] that are not bound at the
+/// end, so parsers return a [`RetryRequirement`] when more input is needed.
+///
+/// [`RetryRequirement`]: crate::error::RetryRequirement
+///
+/// # Example
+///
+/// ```
+/// use std::io::Cursor;
+/// use dangerous::Invalid;
+/// use dangerous::input::io::ReadBuffer;
+///
+/// let mut stream = Cursor::new(b"\x03abc\x02de");
+/// let mut buf = ReadBuffer::new();
+///
+/// let read_message = |r: &mut dangerous::BytesReader<'_, Invalid>| {
+///     let len = r.read()?;
+///     r.take(len as usize).map(|body| body.as_dangerous().to_vec())
+/// };
+///
+/// assert_eq!(buf.read_streaming(&mut stream, read_message).unwrap(), Ok(b"abc".to_vec()));
+/// assert_eq!(buf.read_streaming(&mut stream, read_message).unwrap(), Ok(b"de".to_vec()));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ReadBuffer {
+    buf: Vec<u8>,
+}
+
+impl ReadBuffer {
+    /// Create a new empty `ReadBuffer`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new empty `ReadBuffer` with the specified capacity.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of bytes accumulated.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns `true` if no bytes are accumulated.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Returns the accumulated bytes as [`Bytes`].
+    ///
+    /// The input is bound at the start, but not the end as more bytes may be
+    /// read.
+    pub fn input(&self) -> Bytes<'_> {
+        Bytes::new(&self.buf, Bound::Start)
+    }
+
+    /// Removes `len` bytes from the start of the accumulated bytes.
+    ///
+    /// If `len` is greater than the number of accumulated bytes, the buffer is
+    /// cleared.
+    pub fn consume(&mut self, len: usize) {
+        let len = len.min(self.buf.len());
+        self.buf.drain(..len);
+    }
+
+    /// Reads at least `len` more bytes from the reader into the buffer.
+    ///
+    /// The buffer is grown in bounded chunks as bytes are read, so a large
+    /// `len` does not allocate up front.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the reader other than
+    /// [`io::ErrorKind::Interrupted`], which is retried,
+    /// [`io::ErrorKind::UnexpectedEof`] if the reader ended before `len` bytes
+    /// were read and [`io::ErrorKind::InvalidInput`] if `len` more bytes could
+    /// never fit in the buffer.
+    pub fn read_more<R>(&mut self, reader: &mut R, len: usize) -> io::Result<()>
+    where
+        R: io::Read + ?Sized,
+    {
+        let start = self.buf.len();
+        let target = start.checked_add(len).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "read length overflows buffer")
+        })?;
+        let mut end = start;
+        let result = loop {
+            if end >= target {
+                break Ok(());
+            }
+            // Grow by a bounded chunk, truncating back to what was actually
+            // read once done.
+            if end == self.buf.len() {
+                self.buf.resize(end.saturating_add(MIN_READ_LEN), 0);
+            }
+            match reader.read(&mut self.buf[end..]) {
+                Ok(0) => break Err(io::Error::from(io::ErrorKind::UnexpectedEof)),
+                Ok(read) => end += read,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => break Err(err),
+            }
+        };
+        self.buf.truncate(end);
+        result
+    }
+
+    /// Reads from the reader until a parse of the accumulated bytes succeeds
+    /// or fails without a [`RetryRequirement`].
+    ///
+    /// The parse is run with [`Input::read_partial()`] and, when it returns a
+    /// `RetryRequirement`, at least the required number of bytes are read from
+    /// the reader before retrying. On success, the bytes consumed by the parse
+    /// are removed from the buffer, leaving any trailing bytes for the next
+    /// parse.
+    ///
+    /// As the buffer is mutated between attempts, neither the value nor the
+    /// error may borrow from the input.
+    ///
+    /// [`RetryRequirement`]: crate::error::RetryRequirement
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from [`ReadBuffer::read_more()`] in the outer result,
+    /// and any fatal parse error in the inner result.
+    pub fn read_streaming<R, F, T, E>(
+        &mut self,
+        reader: &mut R,
+        mut f: F,
+    ) -> io::Result<Result<T, E>>
+    where
+        R: io::Read + ?Sized,
+        F: for<'i> FnMut(&mut BytesReader<'i, E>) -> Result<T, E>,
+        E: for<'i> WithContext<'i>,
+        E: ToRetryRequirement,
+    {
+        loop {
+            let err = match self.input().read_partial(&mut f) {
+                Ok((value, remaining)) => {
+                    let consumed = self.buf.len() - remaining.byte_len();
+                    self.consume(consumed);
+                    return Ok(Ok(value));
+                }
+                Err(err) => err,
+            };
+            match err.to_retry_requirement() {
+                Some(requirement) => self.read_more(reader, requirement.continue_after())?,
+                None => return Ok(Err(err)),
+            }
+        }
+    }
+}
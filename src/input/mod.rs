@@ -4,22 +4,33 @@ mod bound;
 mod byte_len;
 mod bytes;
 mod entry;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod io;
 mod pattern;
 mod prefix;
 mod span;
+mod spanned_tokens;
+mod split_on;
 mod string;
 mod token;
 mod traits;
+mod utf16;
 
 pub use self::bound::Bound;
 pub use self::byte_len::ByteLength;
-pub use self::bytes::{ByteArray, Bytes};
-pub use self::pattern::Pattern;
+pub use self::bytes::{ByteArray, Bytes, MatchIndices};
+pub use self::pattern::{AnyOf, FnPattern, NoneOf, Pattern};
 pub use self::prefix::Prefix;
 pub use self::span::Span;
-pub use self::string::{MaybeString, String};
+pub use self::spanned_tokens::SpannedTokens;
+pub use self::split_on::SplitOn;
+pub use self::string::{Lines, MaybeString, String};
 pub use self::token::{Token, TokenType};
 pub use self::traits::Input;
+pub use self::utf16::Utf16;
+#[cfg(feature = "serde")]
+pub use crate::support::serde::SerializeSpan;
 
 pub(crate) use self::entry::IntoInput;
 pub(crate) use self::traits::{Private, PrivateExt};
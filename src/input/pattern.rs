@@ -1,3 +1,6 @@
+use crate::fmt;
+use crate::input::Input;
+
 /// Implemented for structures that can be found within an
 /// [`Input`](crate::Input).
 ///
@@ -12,7 +15,12 @@
 /// With the `simd` feature enabled pattern searches are SIMD optimised where
 /// possible.
 ///
-/// With the `regex` feature enabled, you can search for regex patterns.
+/// With the `regex` feature enabled, you can search for regex patterns with
+/// `&regex::Regex` within `String` and `&regex::bytes::Regex` within `Bytes`.
+/// Searches run directly on the underlying input without allocating, and
+/// whether a match is anchored to the start of the input follows the regex
+/// itself (for example `^\d+` versus `\d+`). When a regex pattern is expected,
+/// errors show the regex source as the expected value.
 ///
 /// # Safety
 ///
@@ -26,4 +34,164 @@ pub unsafe trait Pattern<I> {
     /// Returns the byte index of the first reject and `None` if there was no
     /// reject.
     fn find_reject(self, input: &I) -> Option<usize>;
+
+    /// Returns the byte index and byte length of the last match and `None` if
+    /// there was no match.
+    ///
+    /// The default implementation searches forward from each token after the
+    /// start of the previous match, keeping the last one found.
+    fn find_match_last<'i>(self, input: &I) -> Option<(usize, usize)>
+    where
+        Self: Copy,
+        I: Input<'i>,
+    {
+        let mut offset = 0;
+        let mut last = None;
+        loop {
+            // SAFETY: offset is always zero or a token index within the input.
+            let (_, tail) = unsafe { input.clone().split_at_byte_unchecked(offset) };
+            let (index, len) = match self.find_match(&tail) {
+                Some((index, len)) => (offset + index, len),
+                None => return last,
+            };
+            last = Some((index, len));
+            // SAFETY: Pattern guarantees it returns valid indexes.
+            let (_, matched) = unsafe { input.clone().split_at_byte_unchecked(index) };
+            match matched.tokens_indices().nth(1) {
+                Some((next, _)) => offset = index + next,
+                None => return last,
+            }
+        }
+    }
+}
+
+/// Pattern matching a byte within a set of bytes.
+///
+/// Membership is checked in constant time using a bitmap built once per
+/// search.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid};
+/// use dangerous::input::AnyOf;
+///
+/// let result: Result<_, Invalid> = dangerous::input(b",; a").read_partial(|r| {
+///     Ok(r.take_while(AnyOf(b",; ")))
+/// });
+///
+/// let (taken, remaining) = result.unwrap();
+/// assert_eq!(taken, b",; "[..]);
+/// assert_eq!(remaining, b"a"[..]);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct AnyOf<'a>(pub &'a [u8]);
+
+/// Pattern matching a byte not within a set of bytes.
+///
+/// This is the inverse of [`AnyOf`].
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid};
+/// use dangerous::input::NoneOf;
+///
+/// let result: Result<_, Invalid> = dangerous::input(b"value;rest").read_partial(|r| {
+///     Ok(r.take_while(NoneOf(b",;")))
+/// });
+///
+/// let (taken, remaining) = result.unwrap();
+/// assert_eq!(taken, b"value"[..]);
+/// assert_eq!(remaining, b";rest"[..]);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct NoneOf<'a>(pub &'a [u8]);
+
+/// Pattern matching with a function returning the byte index and byte length
+/// of the first match.
+///
+/// This is an escape hatch for matching variable length values without
+/// implementing [`Pattern`] by hand. A reject is found by repeatedly matching
+/// at the start of the remaining input, stopping at the first position that
+/// doesn't match with a non-zero length. When a `FnPattern` is expected,
+/// errors show its description as the expected value.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid};
+/// use dangerous::input::FnPattern;
+///
+/// // SAFETY: the returned index and length are within the bytes.
+/// let triple = unsafe {
+///     FnPattern::new("triple", |input: &dangerous::Bytes<'_>| {
+///         input
+///             .as_dangerous()
+///             .windows(3)
+///             .position(|w| w[0] == w[1] && w[1] == w[2])
+///             .map(|index| (index, 3))
+///     })
+/// };
+///
+/// let result: Result<_, Invalid> = dangerous::input(b"abcccd").read_partial(|r| {
+///     r.take_until(triple)
+/// });
+///
+/// let (taken, remaining) = result.unwrap();
+/// assert_eq!(taken, b"ab"[..]);
+/// assert_eq!(remaining, b"cccd"[..]);
+/// ```
+#[derive(Copy, Clone)]
+pub struct FnPattern<F> {
+    pub(crate) description: &'static str,
+    f: F,
+}
+
+impl<F> FnPattern<F> {
+    /// Create a new `FnPattern` given a description of what it matches and a
+    /// function returning the byte index and byte length of the first match
+    /// within the input.
+    ///
+    /// # Safety
+    ///
+    /// The function must return a byte index and byte length within the
+    /// provided input and both the start and end of the match must be on
+    /// token boundaries (for `String`, UTF-8 char boundaries), as these are
+    /// used for unchecked splits of the input.
+    pub unsafe fn new(description: &'static str, f: F) -> Self {
+        Self { description, f }
+    }
+}
+
+unsafe impl<'i, I, F> Pattern<I> for FnPattern<F>
+where
+    I: Input<'i>,
+    F: FnMut(&I) -> Option<(usize, usize)>,
+{
+    fn find_match(mut self, input: &I) -> Option<(usize, usize)> {
+        (self.f)(input)
+    }
+
+    fn find_reject(mut self, input: &I) -> Option<usize> {
+        let mut offset = 0;
+        loop {
+            // SAFETY: offset is zero or the end of a match at the start of the
+            // remaining input, which the function guarantees is valid.
+            let (_, tail) = unsafe { input.clone().split_at_byte_unchecked(offset) };
+            match (self.f)(&tail) {
+                Some((0, len)) if len > 0 => offset += len,
+                _ if offset == input.byte_len() => return None,
+                _ => return Some(offset),
+            }
+        }
+    }
+}
+
+impl<F> fmt::Debug for FnPattern<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnPattern")
+            .field("description", &self.description)
+            .finish_non_exhaustive()
+    }
 }
@@ -4,7 +4,10 @@ use core::slice;
 
 use crate::display::InputDisplay;
 use crate::fmt;
-use crate::input::{Input, MaybeString};
+#[cfg(feature = "serde")]
+use crate::input::SerializeSpan;
+use crate::input::{Input, MaybeString, PrivateExt, Token, TokenType};
+use crate::util::{fast, utf8};
 
 /// Range of [`Input`].
 ///
@@ -198,6 +201,37 @@ impl Span {
         parent.extract(self)
     }
 
+    /// Returns the sub-input of the provided parent [`Input`] `self` refers
+    /// to, or `None` if `self` is not within the parent or does not align with
+    /// start and end token boundaries.
+    ///
+    /// This is the inverse of [`Input::span()`] and is equivalent to
+    /// [`Span::of()`] without taking the parent by value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Expected, Input};
+    /// use dangerous::error::Details;
+    ///
+    /// let parent = dangerous::input("hello world");
+    /// let error: Expected<'_> = parent.clone().read_all(|r| {
+    ///     r.consume("hello ")?;
+    ///     r.consume("there")
+    /// }).unwrap_err();
+    /// let span = error.backtrace().root().span;
+    ///
+    /// assert_eq!(span.slice_of(&parent).unwrap(), "world");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn slice_of<'i, I>(self, parent: &I) -> Option<I>
+    where
+        I: Input<'i>,
+    {
+        self.of(parent.clone())
+    }
+
     /// Returns `Some(Range)` with the `start` and `end` offsets of `self`
     /// within the `parent`. `None` is returned if `self` is not within in the
     /// `parent`.
@@ -225,6 +259,158 @@ impl Span {
         }
     }
 
+    /// Returns `Some(offset)` of the start of `self` within the `parent` in
+    /// bytes. `None` is returned if `self` is not within the `parent`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Span};
+    ///
+    /// let parent = dangerous::input(b"hello world");
+    /// let span = Span::from(&parent.as_dangerous()[6..]);
+    ///
+    /// assert_eq!(span.offset_in(&parent), Some(6));
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub fn offset_in<'i, P>(self, parent: &P) -> Option<usize>
+    where
+        P: Input<'i>,
+    {
+        self.range_of(parent.span()).map(|range| range.start)
+    }
+
+    /// Returns `Some(Range)` of `self` within the `parent` input in bytes.
+    /// `None` is returned if `self` is not within the `parent`.
+    ///
+    /// This is [`Span::range_of()`] taking an [`Input`] as the parent. The
+    /// range formats compactly with `Debug` (for example `1..5`), which is
+    /// useful for logging error locations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Span};
+    ///
+    /// let parent = dangerous::input(b"hello world");
+    /// let span = Span::from(&parent.as_dangerous()[1..5]);
+    ///
+    /// assert_eq!(span.as_range_usize(&parent), Some(1..5));
+    /// assert_eq!(format!("{:?}", span.as_range_usize(&parent).unwrap()), "1..5");
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub fn as_range_usize<'i, P>(self, parent: &P) -> Option<Range<usize>>
+    where
+        P: Input<'i>,
+    {
+        self.range_of(parent.span())
+    }
+
+    /// Returns the smallest span covering both `self` and `other`.
+    ///
+    /// Any bytes between the two spans are covered too. As spans are only
+    /// pointers, both must be from the same input for the result to be
+    /// meaningful, which can be checked with [`Span::is_within()`] against
+    /// the input the spans were taken from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Span;
+    ///
+    /// let bytes = b"key=value";
+    /// let key = Span::from(&bytes[..3]);
+    /// let value = Span::from(&bytes[4..]);
+    ///
+    /// assert_eq!(key.union(value), Span::from(&bytes[..]));
+    /// ```
+    #[inline(always)]
+    pub fn union(self, other: Span) -> Span {
+        Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    /// Returns `Some(Span)` of the bytes covered by both `self` and `other`.
+    /// `None` is returned if the spans do not overlap or touch.
+    ///
+    /// Spans that touch without overlapping intersect at an empty span
+    /// between them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Span;
+    ///
+    /// let bytes = b"0123456789";
+    /// let a = Span::from(&bytes[2..6]);
+    /// let b = Span::from(&bytes[4..8]);
+    ///
+    /// assert_eq!(a.intersection(b), Some(Span::from(&bytes[4..6])));
+    /// assert_eq!(a.intersection(Span::from(&bytes[7..])), None);
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub fn intersection(self, other: Span) -> Option<Span> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        if start <= end {
+            Some(Self { start, end })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `Some((line, column))` of the start of `self` within the
+    /// `parent`. `None` is returned if `self` is not within the `parent` or
+    /// does not start on a token boundary.
+    ///
+    /// Both the line and column start from `1`. Lines are counted by `\n`
+    /// bytes before the span start, and the column is the number of tokens
+    /// since the last `\n` plus one. Columns are counted in chars for
+    /// [`String`] and in bytes for [`Bytes`]. `None` is always returned for
+    /// [`Utf16`] input as its lines are not delimited by `\n` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Span};
+    ///
+    /// let parent = dangerous::input("hello\nwörld");
+    /// let span = Span::from(&parent.as_dangerous()[10..]);
+    ///
+    /// assert_eq!(span.line_column_in(&parent), Some((2, 4)));
+    /// ```
+    ///
+    /// [`String`]: crate::String
+    /// [`Bytes`]: crate::Bytes
+    /// [`Utf16`]: crate::Utf16
+    #[must_use]
+    pub fn line_column_in<'i, P>(self, parent: &P) -> Option<(usize, usize)>
+    where
+        P: Input<'i>,
+    {
+        let start = self.range_of(parent.span())?.start;
+        parent.verify_token_boundary(start).ok()?;
+        let before = &parent.as_dangerous_bytes()[..start];
+        let line_start = before
+            .iter()
+            .rposition(|b| *b == b'\n')
+            .map_or(0, |i| i + 1);
+        let line_before = &before[line_start..];
+        let column = match P::Token::TYPE {
+            TokenType::Byte => line_before.len(),
+            // SAFETY: the line starts directly after a `\n` or at the start of
+            // the parent and ends on a verified char boundary.
+            TokenType::Char => fast::num_chars(unsafe { utf8::from_unchecked(line_before) }),
+            TokenType::Utf16 => return None,
+        };
+        Some((fast::count_u8(b'\n', before) + 1, column + 1))
+    }
+
     /// Returns `None` if the span is empty, `Some(Self)` if not.
     ///
     /// # Example
@@ -248,6 +434,28 @@ impl Span {
         }
     }
 
+    /// Wraps the span for serialization relative to the provided root.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Span;
+    ///
+    /// let root = &[1, 2, 3, 4][..];
+    /// let span = Span::from(&root[1..3]);
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_string(&span.serialize_in(root.into())).unwrap(),
+    ///     r#"{"start":1,"len":2}"#,
+    /// );
+    /// ```
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    #[inline(always)]
+    pub fn serialize_in(self, root: Span) -> SerializeSpan {
+        SerializeSpan::new(self, root)
+    }
+
     /// Wraps the span with improved debugging support given the containing
     /// input.
     #[inline(always)]
@@ -293,6 +501,16 @@ impl From<&str> for Span {
     }
 }
 
+/// Formats the span without its input as `(ptr: <address>, len: <length>)`.
+///
+/// A span only points into its input, so offsets can only be formatted against
+/// a parent with [`Span::as_range_usize()`].
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::DisplayBase::fmt(self, f)
+    }
+}
+
 impl fmt::Debug for Span {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Span")
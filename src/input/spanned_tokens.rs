@@ -0,0 +1,63 @@
+use core::iter::FusedIterator;
+
+use crate::input::{ByteLength, Input, PrivateExt, Span};
+
+/// Iterator over the tokens of an [`Input`] and their [`Span`]s.
+///
+/// Created from [`Input::spanned_tokens()`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SpannedTokens<'i, I>
+where
+    I: Input<'i>,
+{
+    bytes: &'i [u8],
+    iter: I::TokenIndicesIter,
+}
+
+impl<'i, I> SpannedTokens<'i, I>
+where
+    I: Input<'i>,
+{
+    pub(super) fn new(input: I) -> Self {
+        Self {
+            bytes: input.as_dangerous_bytes(),
+            iter: input.tokens_indices(),
+        }
+    }
+
+    fn spanned(&self, (index, token): (usize, I::Token)) -> (Span, I::Token) {
+        let span = Span::from(&self.bytes[index..index + token.byte_len()]);
+        (span, token)
+    }
+}
+
+impl<'i, I> Iterator for SpannedTokens<'i, I>
+where
+    I: Input<'i>,
+{
+    type Item = (Span, I::Token);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|item| self.spanned(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'i, I> DoubleEndedIterator for SpannedTokens<'i, I>
+where
+    I: Input<'i>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|item| self.spanned(item))
+    }
+}
+
+impl<'i, I> FusedIterator for SpannedTokens<'i, I>
+where
+    I: Input<'i>,
+    I::TokenIndicesIter: FusedIterator,
+{
+}
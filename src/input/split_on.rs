@@ -0,0 +1,59 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::input::{Input, Pattern, PrivateExt};
+
+/// Iterator over the pieces of an [`Input`] separated by a [`Pattern`].
+///
+/// Created from [`Input::split_on()`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitOn<'i, I, P>
+where
+    I: Input<'i>,
+{
+    remaining: Option<I>,
+    pattern: P,
+    marker: PhantomData<&'i ()>,
+}
+
+impl<'i, I, P> SplitOn<'i, I, P>
+where
+    I: Input<'i>,
+{
+    pub(super) fn new(input: I, pattern: P) -> Self {
+        Self {
+            remaining: Some(input),
+            pattern,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'i, I, P> Iterator for SplitOn<'i, I, P>
+where
+    I: Input<'i>,
+    P: Pattern<I> + Copy,
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.remaining.take()?;
+        let remaining_len = remaining.byte_len();
+        match remaining.clone().split_until_consume_opt(self.pattern) {
+            // An empty match at the start would never advance, so it is
+            // treated as no match.
+            Some((head, tail)) if tail.byte_len() < remaining_len => {
+                self.remaining = Some(tail);
+                Some(head)
+            }
+            _ => Some(remaining),
+        }
+    }
+}
+
+impl<'i, I, P> FusedIterator for SplitOn<'i, I, P>
+where
+    I: Input<'i>,
+    P: Pattern<I> + Copy,
+{
+}
@@ -0,0 +1,46 @@
+use core::iter::FusedIterator;
+
+use crate::input::{Input, Private, PrivateExt, String};
+
+/// Iterator over the lines of a [`String`].
+///
+/// Created from [`String::lines()`].
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Lines<'i> {
+    input: String<'i>,
+}
+
+impl<'i> Lines<'i> {
+    pub(super) fn new(input: String<'i>) -> Self {
+        Self { input }
+    }
+}
+
+impl<'i> Iterator for Lines<'i> {
+    type Item = String<'i>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+        let input = self.input.clone();
+        if let Some(index) = input.as_dangerous_bytes().iter().position(|b| *b == b'\n') {
+            // SAFETY: `\n` is ASCII, so both the index and the index after are
+            // always valid char boundaries.
+            let (line, tail) = unsafe { input.split_at_byte_unchecked(index) };
+            self.input = unsafe { tail.split_at_byte_unchecked(1).1 };
+            if line.as_dangerous_bytes().last() == Some(&b'\r') {
+                // SAFETY: `\r` is ASCII, see above.
+                Some(unsafe { line.clone().split_at_byte_unchecked(line.byte_len() - 1).0 })
+            } else {
+                Some(line)
+            }
+        } else {
+            self.input = input.clone().end();
+            Some(input)
+        }
+    }
+}
+
+impl FusedIterator for Lines<'_> {}
@@ -13,6 +13,43 @@ pub enum MaybeString<'i> {
 }
 
 impl<'i> MaybeString<'i> {
+    /// Create a `MaybeString` from bytes not known to be UTF-8.
+    ///
+    /// The input is bound at the start, as with [`dangerous::input()`].
+    ///
+    /// [`dangerous::input()`]: crate::input()
+    pub fn from_bytes(bytes: &'i [u8]) -> Self {
+        Self::Bytes(Bytes::new(bytes, Bound::Start))
+    }
+
+    /// Create a `MaybeString` from a string known to be UTF-8.
+    ///
+    /// The input is bound at the start, as with [`dangerous::input()`].
+    ///
+    /// [`dangerous::input()`]: crate::input()
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &'i str) -> Self {
+        Self::String(String::new(s, Bound::Start))
+    }
+
+    /// Returns the underlying byte slice.
+    #[must_use]
+    pub fn as_bytes(&self) -> &'i [u8] {
+        self.as_dangerous_bytes()
+    }
+
+    /// Returns the underlying string slice if the [`Input`] is known to be
+    /// UTF-8, `None` if not.
+    ///
+    /// Bytes are never decoded, even if they happen to be valid UTF-8.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&'i str> {
+        match self {
+            Self::Bytes(_) => None,
+            Self::String(v) => Some(v.as_dangerous()),
+        }
+    }
+
     /// Returns `true` if he [`Input`] is known to be UTF-8.
     #[must_use]
     pub fn is_string(&self) -> bool {
@@ -1,3 +1,4 @@
+mod lines;
 mod maybe;
 mod pattern;
 mod prefix;
@@ -6,16 +7,21 @@ use core::str;
 
 use crate::display::InputDisplay;
 use crate::error::{
-    CoreContext, CoreExpected, CoreOperation, ExpectedLength, ExpectedValid, Length,
+    CoreContext, CoreExpected, CoreOperation, ExpectedLength, ExpectedValid, InvalidReason, Length,
+    RetryRequirement,
 };
 use crate::fmt;
-use crate::util::{fast, slice, utf8};
+use crate::util::{ascii, fast, slice, utf8};
 
+pub use self::lines::Lines;
 pub use self::maybe::MaybeString;
 
-use super::{Bound, Bytes, Input, Private};
+use super::{Bound, Bytes, Input, Pattern, Private, PrivateExt};
 
 /// UTF-8 [`Input`].
+///
+/// Equality compares the content only, ignoring the [`Bound`]. See
+/// [`Input::value_eq()`].
 #[derive(Clone)]
 #[must_use = "input must be consumed"]
 pub struct String<'i> {
@@ -38,6 +44,17 @@ impl<'i> String<'i> {
         fast::num_chars(self.as_dangerous())
     }
 
+    /// Returns the number of UTF-8 characters in the string, always using the
+    /// portable implementation.
+    ///
+    /// Unlike [`String::num_chars()`] this never dispatches to `bytecount`,
+    /// which is useful for benchmarking and testing across feature
+    /// configurations.
+    #[must_use]
+    pub fn num_chars_scalar(&self) -> usize {
+        fast::num_chars_scalar(self.as_dangerous())
+    }
+
     /// Returns `true` if the underlying byte slice length is zero.
     #[must_use]
     #[inline(always)]
@@ -55,6 +72,83 @@ impl<'i> String<'i> {
         unsafe { utf8::from_unchecked(self.utf8.as_dangerous()) }
     }
 
+    /// Copies the underlying string slice into an owned string.
+    ///
+    /// This allocates and copies, detaching the string from the input so it
+    /// can outlive it. See [`Input::to_owned_bytes()`] for the byte
+    /// equivalent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let owned = dangerous::input("hello").to_owned_string();
+    ///
+    /// assert_eq!(owned, "hello");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn to_owned_string(&self) -> alloc::string::String {
+        self.as_dangerous().into()
+    }
+
+    /// Decodes UTF-16 little endian bytes into an owned string.
+    ///
+    /// UTF-16 can't borrow the original bytes, so the decoded string is
+    /// allocated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if a code unit or surrogate pair was cut
+    /// short and [`ExpectedValid`] on an unpaired surrogate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Invalid, String};
+    ///
+    /// let decoded = String::from_utf16_le::<Invalid>(dangerous::input(b"h\0i\0"));
+    ///
+    /// assert_eq!(decoded.unwrap(), "hi");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn from_utf16_le<E>(bytes: Bytes<'i>) -> Result<alloc::string::String, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        bytes.decode_utf16_for(u16::from_le_bytes, CoreOperation::DecodeUtf16)
+    }
+
+    /// Decodes UTF-16 big endian bytes into an owned string.
+    ///
+    /// See [`String::from_utf16_le()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if a code unit or surrogate pair was cut
+    /// short and [`ExpectedValid`] on an unpaired surrogate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Invalid, String};
+    ///
+    /// let decoded = String::from_utf16_be::<Invalid>(dangerous::input(b"\0h\0i"));
+    ///
+    /// assert_eq!(decoded.unwrap(), "hi");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn from_utf16_be<E>(bytes: Bytes<'i>) -> Result<alloc::string::String, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        bytes.decode_utf16_for(u16::from_be_bytes, CoreOperation::DecodeUtf16)
+    }
+
     /// Returns the underlying string slice if it is not empty.
     ///
     /// See [`Bytes::as_dangerous`] for naming.
@@ -106,6 +200,28 @@ impl<'i> String<'i> {
     pub unsafe fn from_utf8_unchecked(utf8: Bytes<'i>) -> Self {
         Self { utf8 }
     }
+
+    /// Returns an iterator over the lines of the string.
+    ///
+    /// Lines are split on `\n`, with a `\r` directly before it stripped. The
+    /// final line is returned even without a trailing newline, but a trailing
+    /// newline does not produce an empty final line. This is analogous to
+    /// [`str::lines()`].
+    ///
+    /// Each line is a sub-slice of the string, so errors raised while reading a
+    /// line still point into the original input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let input = dangerous::input("a\r\nb\n");
+    /// let lines: Vec<_> = input.lines().collect();
+    ///
+    /// assert_eq!(lines, ["a", "b"]);
+    /// ```
+    pub fn lines(self) -> Lines<'i> {
+        Lines::new(self)
+    }
 }
 
 impl<'i> Input<'i> for String<'i> {
@@ -136,6 +252,12 @@ impl<'i> Input<'i> for String<'i> {
         self
     }
 
+    #[inline(always)]
+    fn into_bound_end(mut self) -> Self {
+        self.utf8 = self.utf8.into_bound_end();
+        self
+    }
+
     #[inline(always)]
     fn into_maybe_string(self) -> MaybeString<'i> {
         MaybeString::String(self)
@@ -160,6 +282,279 @@ impl<'i> Input<'i> for String<'i> {
             None
         }
     }
+
+    #[inline(always)]
+    fn join(self, other: Self, parent: &Self) -> Option<Self> {
+        // Two adjacent strs are always valid UTF-8 when joined.
+        self.utf8
+            .join(other.utf8, &parent.utf8)
+            .map(|utf8| String { utf8 })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+impl<'i> String<'i> {
+    #[inline(always)]
+    pub(crate) fn split_ascii_f64_for<E>(
+        self,
+        expected: &'static str,
+        operation: CoreOperation,
+    ) -> Result<(f64, String<'i>), E>
+    where
+        E: From<ExpectedValid<'i>>,
+    {
+        let bytes = self.as_dangerous_bytes();
+        let (span, truncated) = match ascii::float_len(bytes) {
+            Ok(len) => {
+                // SAFETY: the float length only spans ASCII bytes, which is
+                // always a valid char boundary.
+                let (head, tail) = unsafe { self.clone().split_at_byte_unchecked(len) };
+                match head.as_dangerous().parse() {
+                    Ok(parsed) => return Ok((parsed, tail)),
+                    Err(_) => (head.as_dangerous_bytes(), false),
+                }
+            }
+            // If we ran out of input, more could still make the float valid.
+            Err(index) if index == bytes.len() => (&bytes[index..], true),
+            Err(index) => {
+                let c_len = utf8::char_len(bytes[index]).max(1);
+                (&bytes[index..index + c_len], false)
+            }
+        };
+        Err(E::from(ExpectedValid {
+            reason: if truncated {
+                InvalidReason::Truncated
+            } else {
+                InvalidReason::Malformed
+            },
+            retry_requirement: if truncated {
+                RetryRequirement::new(1)
+            } else {
+                None
+            },
+            context: CoreContext {
+                span: span.into(),
+                operation,
+                expected: CoreExpected::Valid(expected),
+            },
+            input: self.into_maybe_string(),
+        }))
+    }
+}
+
+impl<'i> String<'i> {
+    /// Splits an optionally signed ASCII integer in the given radix from the
+    /// start of the string, returning whether it was negative and its
+    /// magnitude.
+    ///
+    /// A sign is only accepted if `signed` is `true`, and the magnitude must
+    /// not exceed `max`, which may be one larger for negative values.
+    pub(crate) fn split_ascii_int_for<E>(
+        self,
+        radix: u32,
+        signed: bool,
+        max: u64,
+        expected: &'static str,
+        operation: CoreOperation,
+    ) -> Result<((bool, u64), String<'i>), E>
+    where
+        E: From<ExpectedValid<'i>>,
+    {
+        assert!((2..=36).contains(&radix), "radix must be between 2 and 36");
+        let bytes = self.as_dangerous_bytes();
+        let (negative, start) = match bytes.first() {
+            Some(b'-') if signed => (true, 1),
+            Some(b'+') if signed => (false, 1),
+            _ => (false, 0),
+        };
+        let max = if negative { max + 1 } else { max };
+        let mut value: u64 = 0;
+        let mut overflowed = false;
+        let mut end = start;
+        while let Some(digit) = bytes.get(end).and_then(|b| (*b as char).to_digit(radix)) {
+            match value
+                .checked_mul(u64::from(radix))
+                .and_then(|v| v.checked_add(u64::from(digit)))
+            {
+                Some(next) if next <= max => value = next,
+                _ => overflowed = true,
+            }
+            end += 1;
+        }
+        let (span, reason, retry_requirement) = if overflowed {
+            (&bytes[start..end], InvalidReason::Overflow, None)
+        } else if end > start {
+            // SAFETY: `end` is directly after an ASCII digit, which is always
+            // a valid char boundary.
+            let (_, tail) = unsafe { self.split_at_byte_unchecked(end) };
+            return Ok(((negative, value), tail));
+        } else if end == bytes.len() {
+            // If we ran out of input, a digit could still follow.
+            (
+                &bytes[end..],
+                InvalidReason::Truncated,
+                RetryRequirement::new(1),
+            )
+        } else {
+            let c_len = utf8::char_len(bytes[end]).max(1);
+            (&bytes[end..end + c_len], InvalidReason::Malformed, None)
+        };
+        Err(E::from(ExpectedValid {
+            reason,
+            retry_requirement,
+            context: CoreContext {
+                span: span.into(),
+                operation,
+                expected: CoreExpected::Valid(expected),
+            },
+            input: self.into_maybe_string(),
+        }))
+    }
+}
+
+impl<'i> String<'i> {
+    #[inline(always)]
+    pub(crate) fn split_digits_grouped_for<E>(
+        self,
+        separator: char,
+        operation: CoreOperation,
+    ) -> Result<(String<'i>, String<'i>), E>
+    where
+        E: From<ExpectedValid<'i>>,
+    {
+        let s = self.as_dangerous();
+        // A digit is expected at the start and after each separator.
+        let mut expect_digit = true;
+        let mut end = 0;
+        let mut chars = s.char_indices();
+        // The byte index and length of where a digit was expected, and whether
+        // the input ran out.
+        let invalid = loop {
+            match chars.next() {
+                Some((i, c)) if c.is_ascii_digit() => {
+                    expect_digit = false;
+                    end = i + 1;
+                }
+                Some((i, c)) if expect_digit => break Some((i, c.len_utf8(), false)),
+                Some((_, c)) if c == separator => expect_digit = true,
+                None if expect_digit => break Some((s.len(), 0, true)),
+                Some(_) | None => break None,
+            }
+        };
+        match invalid {
+            None => {
+                // SAFETY: `end` is directly after an ASCII digit, which is
+                // always a valid char boundary.
+                Ok(unsafe { self.split_at_byte_unchecked(end) })
+            }
+            Some((start, len, truncated)) => Err(E::from(ExpectedValid {
+                reason: if truncated {
+                    InvalidReason::Truncated
+                } else {
+                    InvalidReason::Malformed
+                },
+                retry_requirement: if truncated {
+                    RetryRequirement::new(1)
+                } else {
+                    None
+                },
+                context: CoreContext {
+                    span: s.as_bytes()[start..start + len].into(),
+                    operation,
+                    expected: CoreExpected::Valid("digit"),
+                },
+                input: self.into_maybe_string(),
+            })),
+        }
+    }
+}
+
+impl<'i> String<'i> {
+    /// Decodes `%XX` escapes, and `+` as a space if `plus_as_space`, up to the
+    /// first match of `until` or the end of the input.
+    ///
+    /// Each decoded byte is passed to `push`, which returns `false` if it
+    /// could not accept it. Returns the number of decoded bytes.
+    pub(crate) fn split_percent_decoded_for<P, F, E>(
+        self,
+        until: P,
+        plus_as_space: bool,
+        mut push: F,
+        operation: CoreOperation,
+    ) -> Result<(usize, String<'i>), E>
+    where
+        E: From<ExpectedValid<'i>>,
+        P: Pattern<String<'i>>,
+        F: FnMut(u8) -> bool,
+    {
+        let (head, tail) = match self.clone().split_until_opt(until) {
+            Some(split) => split,
+            None => (self.clone(), self.clone().end()),
+        };
+        let at_end = tail.is_empty();
+        let bytes = head.as_dangerous().as_bytes();
+        let invalid = |start: usize, len: usize, truncated: bool| {
+            E::from(ExpectedValid {
+                reason: if truncated {
+                    InvalidReason::Truncated
+                } else {
+                    InvalidReason::Malformed
+                },
+                retry_requirement: if truncated {
+                    RetryRequirement::new(3 - len)
+                } else {
+                    None
+                },
+                context: CoreContext {
+                    span: bytes[start..start + len].into(),
+                    operation,
+                    expected: CoreExpected::Valid("percent escape"),
+                },
+                input: self.clone().into_maybe_string(),
+            })
+        };
+        let mut written = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            let (byte, len) = match bytes[i] {
+                b'%' => {
+                    let digits = &bytes[i + 1..bytes.len().min(i + 3)];
+                    let mut byte = 0;
+                    for &digit in digits {
+                        match (digit as char).to_digit(16) {
+                            #[allow(clippy::cast_possible_truncation)]
+                            Some(value) => byte = (byte << 4) | value as u8,
+                            None => return Err(invalid(i, digits.len() + 1, false)),
+                        }
+                    }
+                    if digits.len() < 2 {
+                        // The escape may continue if it was cut short by the
+                        // end of the input rather than by `until`.
+                        return Err(invalid(i, digits.len() + 1, at_end));
+                    }
+                    (byte, 3)
+                }
+                b'+' if plus_as_space => (b' ', 1),
+                byte => (byte, 1),
+            };
+            if !push(byte) {
+                return Err(E::from(ExpectedValid {
+                    reason: InvalidReason::Overflow,
+                    retry_requirement: None,
+                    context: CoreContext {
+                        span: bytes[..i + len].into(),
+                        operation,
+                        expected: CoreExpected::Valid("input within buffer capacity"),
+                    },
+                    input: self.into_maybe_string(),
+                }));
+            }
+            written += 1;
+            i += len;
+        }
+        Ok((written, tail))
+    }
 }
 
 impl<'i> Private<'i, char> for String<'i> {
@@ -189,6 +584,12 @@ impl<'i> Private<'i, char> for String<'i> {
         self
     }
 
+    #[inline(always)]
+    fn with_bound(mut self, bound: Bound) -> Self {
+        self.utf8 = self.utf8.with_bound(bound);
+        self
+    }
+
     #[inline(always)]
     fn verify_token_boundary(&self, index: usize) -> Result<(), CoreExpected> {
         if index > self.byte_len() {
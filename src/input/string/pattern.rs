@@ -92,6 +92,17 @@ unsafe impl<'i> Pattern<String<'i>> for &regex::Regex {
         regex::Regex::find(self, input.as_dangerous()).map(|m| (m.start(), m.end() - m.start()))
     }
 
+    fn find_match_last<'a>(self, input: &String<'i>) -> Option<(usize, usize)>
+    where
+        String<'i>: crate::Input<'a>,
+    {
+        // Searching from a later offset would change what anchors match, so
+        // the last of the non-overlapping matches is used instead.
+        regex::Regex::find_iter(self, input.as_dangerous())
+            .last()
+            .map(|m| (m.start(), m.end() - m.start()))
+    }
+
     fn find_reject(self, input: &String<'i>) -> Option<usize> {
         let mut maybe_reject = 0;
         loop {
@@ -15,6 +15,17 @@ pub enum TokenType {
     Byte,
     /// A UTF-8 char.
     Char,
+    /// A UTF-16 code unit.
+    Utf16,
+}
+
+/// Returns the minimum byte length of `count` tokens.
+#[inline(always)]
+pub(crate) fn min_byte_len<T: Token>(count: usize) -> usize {
+    match T::TYPE {
+        TokenType::Byte | TokenType::Char => count,
+        TokenType::Utf16 => count.saturating_mul(2),
+    }
 }
 
 impl Token for u8 {
@@ -24,3 +35,7 @@ impl Token for u8 {
 impl Token for char {
     const TYPE: TokenType = TokenType::Char;
 }
+
+impl Token for u16 {
+    const TYPE: TokenType = TokenType::Utf16;
+}
@@ -3,15 +3,20 @@ use core::convert::Infallible;
 use crate::display::InputDisplay;
 use crate::error::{
     with_context, CoreContext, CoreExpected, CoreOperation, ExpectedLength, ExpectedValid,
-    ExpectedValue, External, Length, Value, WithChildContext, WithContext,
+    ExpectedValue, External, InvalidReason, Length, RetryRequirement, Value, WithChildContext,
+    WithContext,
 };
 use crate::fmt::{Debug, Display, DisplayBase};
 use crate::input::pattern::Pattern;
-use crate::reader::Reader;
+use crate::reader::{ProgressReader, Reader};
 
-use super::{Bound, ByteLength, Bytes, MaybeString, Prefix, Span, String, Token};
+use super::token;
+use super::{
+    Bound, ByteLength, Bytes, MaybeString, Prefix, Span, SpannedTokens, SplitOn, String, Token,
+};
 
-/// Implemented for immutable wrappers around bytes to be processed ([`Bytes`]/[`String`]).
+/// Implemented for immutable wrappers around bytes to be processed
+/// ([`Bytes`]/[`String`]/[`Utf16`]).
 ///
 /// It can only be created via [`dangerous::input()`] as so to clearly point out
 /// where untrusted / dangerous input is consumed and takes the form of either
@@ -25,12 +30,16 @@ use super::{Bound, ByteLength, Bytes, MaybeString, Prefix, Span, String, Token};
 /// formatting options.
 ///
 /// [`dangerous::input()`]: crate::input()
+/// [`Utf16`]: crate::Utf16
 #[must_use = "input must be consumed"]
 pub trait Input<'i>: Private<'i, Self::Token> {
     /// Smallest unit that can be consumed.
     type Token: Token;
 
     /// Returns the [`Input`] [`Bound`].
+    ///
+    /// Input taken from a known length is bound at both sides, while input
+    /// that may still be extended in further passes is not bound at its end.
     fn bound(&self) -> Bound;
 
     /// Returns `self` as a bound `Input`.
@@ -55,6 +64,27 @@ pub trait Input<'i>: Private<'i, Self::Token> {
     /// [`RetryRequirement`]: crate::error::RetryRequirement
     fn into_bound(self) -> Self;
 
+    /// Returns `self` with the end bound.
+    ///
+    /// Use this when it is known no more input will follow, such as after
+    /// receiving the final frame of a stream, but the start of the input is
+    /// not known to be final. Like [`Input::into_bound()`], errors from reading
+    /// past the end of the input will be fatal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid, ToRetryRequirement};
+    ///
+    /// let input = dangerous::input(b"1234").into_bound_end();
+    /// let error: Invalid = input.clone().read_partial(|r| r.take(5)).unwrap_err();
+    ///
+    /// assert!(input.bound_end());
+    /// assert!(error.is_fatal());
+    /// ```
+    #[must_use]
+    fn into_bound_end(self) -> Self;
+
     /// Consumes `self` into [`Bytes`].
     fn into_bytes(self) -> Bytes<'i>;
 
@@ -95,6 +125,30 @@ pub trait Input<'i>: Private<'i, Self::Token> {
     /// out of bounds.
     fn split_at_opt(self, mid: usize) -> Option<(Self, Self)>;
 
+    /// Joins the input with `other` if both are within `parent` and `other`
+    /// immediately follows the input.
+    ///
+    /// The joined input takes the start bound of `self` and the end bound of
+    /// `other`, so rejoining the two halves of a split returns the original
+    /// input. Returns `None` if either input is not within `parent` or they
+    /// are not adjacent.
+    ///
+    /// `parent` is required as inputs from separate allocations may sit next
+    /// to each other in memory, but can never be joined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let input = dangerous::input(b"hello");
+    /// let (head, tail) = input.clone().split_at_opt(2).unwrap();
+    ///
+    /// assert_eq!(head.clone().join(tail.clone(), &input), Some(input.clone()));
+    /// assert_eq!(tail.join(head, &input), None);
+    /// ```
+    fn join(self, other: Self, parent: &Self) -> Option<Self>;
+
     /// Splits the input into two at the byte index `mid`.
     ///
     /// # Errors
@@ -124,6 +178,48 @@ pub trait Input<'i>: Private<'i, Self::Token> {
         }
     }
 
+    /// Splits the input into two near `numerator / denominator` of its byte
+    /// length.
+    ///
+    /// The byte index is rounded down to the nearest token boundary, so a
+    /// [`String`] is always split on a char boundary. A ratio above one splits
+    /// at the end of the input and if `denominator` is zero, the whole input is
+    /// returned as the head.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let (head, tail) = dangerous::input("aé").split_at_ratio(1, 2);
+    ///
+    /// assert_eq!(head, "a");
+    /// assert_eq!(tail, "é");
+    /// ```
+    fn split_at_ratio(self, numerator: usize, denominator: usize) -> (Self, Self) {
+        let len = self.byte_len();
+        let mut mid = if denominator == 0 {
+            len
+        } else {
+            let mid = (len as u128 * numerator as u128 / denominator as u128).min(len as u128);
+            // Won't truncate as the index was capped at the length.
+            #[allow(clippy::cast_possible_truncation)]
+            let mid = mid as usize;
+            mid
+        };
+        loop {
+            if self.verify_token_boundary(mid).is_ok() {
+                // SAFETY: we verified that the index is a token boundary so
+                // this is safe.
+                return unsafe { self.split_at_byte_unchecked(mid) };
+            }
+            match mid.checked_sub(1) {
+                Some(prev) => mid = prev,
+                None => return (self.clone(), self.end()),
+            }
+        }
+    }
+
     ///////////////////////////////////////////////////////////////////////////
     // Provided methods
 
@@ -141,6 +237,22 @@ pub trait Input<'i>: Private<'i, Self::Token> {
         self.byte_len() == 0
     }
 
+    /// Returns `true` if the start of the input will not change in further
+    /// passes.
+    #[must_use]
+    #[inline(always)]
+    fn bound_start(&self) -> bool {
+        self.bound().is_start_bound()
+    }
+
+    /// Returns `true` if the end of the input will not change in further
+    /// passes.
+    #[must_use]
+    #[inline(always)]
+    fn bound_end(&self) -> bool {
+        self.bound().is_end_bound()
+    }
+
     /// Returns `true` if [`Self::bound()`] is [`Bound::StartEnd`].
     #[must_use]
     #[inline(always)]
@@ -148,6 +260,34 @@ pub trait Input<'i>: Private<'i, Self::Token> {
         self.bound() == Bound::StartEnd
     }
 
+    /// Returns `true` if the underlying bytes of `self` and `other` are equal,
+    /// ignoring their [`Bound`]s.
+    ///
+    /// `==` on [`Bytes`] and [`String`] also ignores the bound, this method
+    /// exists to make that explicit where it matters, such as comparing input
+    /// returned from different parsing paths. Note `==` on [`Utf16`] compares
+    /// code units, while this compares bytes so the byte order must match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Bound, Input};
+    ///
+    /// let unbound = dangerous::input(b"hello");
+    /// let bound = unbound.clone().into_bound();
+    ///
+    /// assert_eq!(unbound.bound(), Bound::Start);
+    /// assert_eq!(bound.bound(), Bound::StartEnd);
+    /// assert!(unbound.value_eq(&bound));
+    /// ```
+    ///
+    /// [`Utf16`]: crate::Utf16
+    #[must_use]
+    #[inline(always)]
+    fn value_eq(&self, other: &Self) -> bool {
+        self.as_dangerous_bytes() == other.as_dangerous_bytes()
+    }
+
     /// Returns a [`Span`] from the start of `self` to the end.
     #[inline(always)]
     fn span(&self) -> Span {
@@ -175,6 +315,243 @@ pub trait Input<'i>: Private<'i, Self::Token> {
         self.clone().tokens().next_back()
     }
 
+    /// Returns an iterator over the tokens of the input and their [`Span`]s.
+    ///
+    /// Each span covers exactly one token, so can be passed straight to
+    /// [`InputDisplay::span()`] or [`Span::range_of()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let input = dangerous::input("ab");
+    /// let tokens: Vec<_> = input
+    ///     .clone()
+    ///     .spanned_tokens()
+    ///     .map(|(span, c)| (span.range_of(input.span()).unwrap(), c))
+    ///     .collect();
+    ///
+    /// assert_eq!(tokens, [(0..1, 'a'), (1..2, 'b')]);
+    /// ```
+    ///
+    /// [`InputDisplay::span()`]: crate::display::InputDisplay::span()
+    #[inline]
+    fn spanned_tokens(self) -> SpannedTokens<'i, Self> {
+        SpannedTokens::new(self)
+    }
+
+    /// Returns an iterator over the pieces of the input separated by
+    /// `pattern`, consuming the matches between them.
+    ///
+    /// Like [`str::split()`], a trailing empty piece is yielded if the input
+    /// ends with a match. Each piece is a sub-input of `self` and retains its
+    /// [`Span`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let pieces: Vec<_> = dangerous::input(b"a,b,").split_on(b',').collect();
+    ///
+    /// assert_eq!(pieces, [&b"a"[..], &b"b"[..], &b""[..]]);
+    /// ```
+    #[inline]
+    fn split_on<P>(self, pattern: P) -> SplitOn<'i, Self, P>
+    where
+        P: Pattern<Self> + Copy,
+    {
+        SplitOn::new(self, pattern)
+    }
+
+    /// Returns `true` if every token in the input matches `pattern`.
+    ///
+    /// Returns `true` for empty input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::input::{AnyOf, Input};
+    ///
+    /// let base16 = AnyOf(b"0123456789abcdef");
+    ///
+    /// assert!(dangerous::input(b"c0ffee").all(base16));
+    /// assert!(!dangerous::input(b"coffee").all(base16));
+    /// ```
+    #[inline]
+    fn all<P>(&self, pattern: P) -> bool
+    where
+        P: Pattern<Self>,
+    {
+        self.byte_len() == 0 || pattern.find_reject(self).is_none()
+    }
+
+    /// Returns `true` if any part of the input matches `pattern`.
+    ///
+    /// Returns `false` for empty input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// assert!(dangerous::input("a b").any(' '));
+    /// assert!(!dangerous::input("ab").any(' '));
+    /// ```
+    #[inline]
+    fn any<P>(&self, pattern: P) -> bool
+    where
+        P: Pattern<Self>,
+    {
+        pattern.find_match(self).is_some()
+    }
+
+    /// Copies the underlying byte slice into an owned buffer.
+    ///
+    /// This allocates and copies, detaching the bytes from the input so they
+    /// can outlive it. Any spans later taken from the copy will not point into
+    /// the original input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let owned = dangerous::input(b"hello").to_owned_bytes();
+    ///
+    /// assert_eq!(owned, b"hello");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    fn to_owned_bytes(&self) -> alloc::vec::Vec<u8> {
+        self.clone().into_bytes().as_dangerous().to_vec()
+    }
+
+    /// Returns the input with the leading tokens matching the pattern removed.
+    ///
+    /// The bound of the input is kept and the returned input still points into
+    /// the original input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let input = dangerous::input("  hi  ");
+    ///
+    /// assert_eq!(input.trim_start(' '), "hi  ");
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    fn trim_start<P>(self, pattern: P) -> Self
+    where
+        P: Pattern<Self>,
+    {
+        let mid = pattern
+            .find_reject(&self)
+            .unwrap_or_else(|| self.byte_len());
+        // SAFETY: Pattern guarantees it returns valid indexes and the end of
+        // the input is always a valid index.
+        let (_, tail) = unsafe { self.split_at_byte_unchecked(mid) };
+        tail
+    }
+
+    /// Returns the input with the trailing tokens matching the pattern removed.
+    ///
+    /// The pattern is tested against each token individually, starting from
+    /// the last. The returned input keeps the bound of the original input, so
+    /// trimming an end that is not bound will not bind it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let input = dangerous::input("  hi  ");
+    ///
+    /// assert_eq!(input.trim_end(' '), "  hi");
+    /// ```
+    #[must_use]
+    #[inline]
+    fn trim_end<P>(self, pattern: P) -> Self
+    where
+        P: Pattern<Self> + Copy,
+    {
+        let mut end = self.byte_len();
+        for (i, _) in self.clone().tokens_indices().rev() {
+            // SAFETY: token indices and the end of the last token are always
+            // valid indexes to split at.
+            let token = unsafe {
+                let (head, _) = self.clone().split_at_byte_unchecked(end);
+                head.split_at_byte_unchecked(i).1
+            };
+            if pattern.find_reject(&token).is_some() {
+                break;
+            }
+            end = i;
+        }
+        let bound = self.bound();
+        // SAFETY: end is either the end of the input or a token index.
+        let (head, _) = unsafe { self.split_at_byte_unchecked(end) };
+        // Only the end moved within the input, so both bounds still hold.
+        head.with_bound(bound)
+    }
+
+    /// Returns the input with both the leading and trailing tokens matching
+    /// the pattern removed.
+    ///
+    /// See [`Input::trim_start()`] and [`Input::trim_end()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let input = dangerous::input(b"  hi  ");
+    ///
+    /// assert_eq!(input.trim(|b: u8| b.is_ascii_whitespace()), b"hi"[..]);
+    /// ```
+    #[must_use]
+    #[inline]
+    fn trim<P>(self, pattern: P) -> Self
+    where
+        P: Pattern<Self> + Copy,
+    {
+        self.trim_start(pattern).trim_end(pattern)
+    }
+
+    /// Splits the input at the last match of a pattern if it is present.
+    ///
+    /// The head is the input leading up to the match and the tail starts with
+    /// the match. The bound of each half follows a forward split at the same
+    /// index. See [`Pattern::find_match_last()`] for how the last match is
+    /// found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::Input;
+    ///
+    /// let (name, ext) = dangerous::input("archive.tar.gz")
+    ///     .split_until_last_opt('.')
+    ///     .unwrap();
+    ///
+    /// assert_eq!(name, "archive.tar");
+    /// assert_eq!(ext, ".gz");
+    /// ```
+    #[inline]
+    fn split_until_last_opt<P>(self, pattern: P) -> Option<(Self, Self)>
+    where
+        P: Pattern<Self> + Copy,
+    {
+        pattern.find_match_last(&self).map(|(index, _)| {
+            // SAFETY: Pattern guarantees it returns valid indexes.
+            unsafe { self.split_at_byte_unchecked(index) }
+        })
+    }
+
     /// Create a reader with the expectation all of the input is read.
     ///
     /// # Errors
@@ -207,6 +584,63 @@ pub trait Input<'i>: Private<'i, Self::Token> {
         }
     }
 
+    /// Create a reader with the expectation all of the input is read,
+    /// reporting progress as the reader advances.
+    ///
+    /// `on_progress` is called with the number of bytes consumed and the total
+    /// number of bytes. Calls are throttled to roughly every percent of the
+    /// input consumed, with a final call once all of the input was read. See
+    /// [`ProgressReader`] for when progress is reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let mut last = (0, 0);
+    /// let result: Result<_, Invalid> = dangerous::input(b"hello world")
+    ///     .read_all_with_progress(|r| r.consume(b"hello world"), |consumed, total| {
+    ///         last = (consumed, total);
+    ///     });
+    ///
+    /// assert!(result.is_ok());
+    /// assert_eq!(last, (11, 11));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either the provided function does, or there is
+    /// trailing input.
+    #[inline]
+    fn read_all_with_progress<F, T, E, P>(self, f: F, on_progress: P) -> Result<T, E>
+    where
+        F: FnOnce(&mut ProgressReader<'i, Self, E, P>) -> Result<T, E>,
+        E: WithContext<'i>,
+        E: From<ExpectedLength<'i>>,
+        P: FnMut(usize, usize),
+    {
+        let mut r = ProgressReader::new(self.clone(), on_progress);
+        let result = with_context(
+            CoreContext::from_operation(CoreOperation::ReadAll, self.span()),
+            self.clone(),
+            || f(&mut r),
+        );
+        let mut r = r.finish();
+        match result {
+            Ok(ok) if r.at_end() => Ok(ok),
+            Ok(_) => Err(E::from(ExpectedLength {
+                len: Length::Exactly(0),
+                context: CoreContext {
+                    span: r.take_remaining().span(),
+                    operation: CoreOperation::ReadAll,
+                    expected: CoreExpected::NoTrailingInput,
+                },
+                input: self.into_maybe_string(),
+            })),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Create a reader to read a part of the input and return the rest.
     ///
     /// # Errors
@@ -311,6 +745,9 @@ pub trait Private<'i, Token>: Sized + Clone + DisplayBase + Debug + Display {
     // Return self with its end bound removed.
     fn into_unbound_end(self) -> Self;
 
+    /// Returns `self` with the given bound.
+    fn with_bound(self, bound: Bound) -> Self;
+
     /// Verifies a token boundary.
     ///
     /// The start and end of the input (when `index == self.byte_len()`) are
@@ -337,6 +774,18 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
         self.clone().into_bytes().as_dangerous()
     }
 
+    /// Returns the byte length of the longest common prefix with `other`.
+    ///
+    /// The length is shortened to end on a token boundary.
+    fn common_prefix_byte_len(&self, other: &[u8]) -> usize {
+        let bytes = self.as_dangerous_bytes();
+        let mut len = bytes.iter().zip(other).take_while(|(a, b)| a == b).count();
+        while self.verify_token_boundary(len).is_err() {
+            len -= 1;
+        }
+        len
+    }
+
     /// Splits the input into two at the token index `mid`.
     ///
     /// # Errors
@@ -349,7 +798,7 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
     {
         self.clone().split_at_opt(mid).ok_or_else(|| {
             E::from(ExpectedLength {
-                len: Length::AtLeast(mid),
+                len: Length::AtLeast(token::min_byte_len::<Self::Token>(mid)),
 
                 context: CoreContext {
                     span: self.span(),
@@ -392,6 +841,7 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
                     Ok(unsafe { self.split_at_byte_unchecked(mid) })
                 }
                 Err(expected) => Err(E::from(ExpectedValid {
+                    reason: InvalidReason::Malformed,
                     retry_requirement: None,
                     context: CoreContext {
                         span: self.as_dangerous_bytes()[mid..mid].into(),
@@ -428,7 +878,7 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
     {
         self.clone().split_token_opt().ok_or_else(|| {
             E::from(ExpectedLength {
-                len: Length::AtLeast(1),
+                len: Length::AtLeast(token::min_byte_len::<Self::Token>(1)),
 
                 context: CoreContext {
                     span: self.span(),
@@ -440,6 +890,74 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
         })
     }
 
+    /// Splits the input into the first token if it is within a range and
+    /// whatever remains.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if the input is empty and [`ExpectedValid`]
+    /// if the token is not within the range.
+    #[inline(always)]
+    fn split_token_in_for<E>(
+        self,
+        range: core::ops::RangeInclusive<Self::Token>,
+        expected: &'static str,
+        operation: CoreOperation,
+    ) -> Result<(Self::Token, Self), E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+        Self::Token: PartialOrd,
+    {
+        self.split_token_map_for(
+            |token| {
+                if range.contains(&token) {
+                    Some(token)
+                } else {
+                    None
+                }
+            },
+            expected,
+            operation,
+        )
+    }
+
+    /// Splits the input into the first token mapped by a function and
+    /// whatever remains.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if the input is empty and [`ExpectedValid`]
+    /// if the function returns `None`.
+    #[inline(always)]
+    fn split_token_map_for<F, T, E>(
+        self,
+        f: F,
+        expected: &'static str,
+        operation: CoreOperation,
+    ) -> Result<(T, Self), E>
+    where
+        F: FnOnce(Self::Token) -> Option<T>,
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        let (token, tail) = self.clone().split_token_for::<E>(operation)?;
+        if let Some(value) = f(token) {
+            Ok((value, tail))
+        } else {
+            Err(E::from(ExpectedValid {
+                reason: InvalidReason::Malformed,
+                retry_requirement: None,
+                context: CoreContext {
+                    span: self.as_dangerous_bytes()[..token.byte_len()].into(),
+                    operation,
+                    expected: CoreExpected::Valid(expected),
+                },
+                input: self.into_maybe_string(),
+            }))
+        }
+    }
+
     /// Splits a prefix from the input if it is present.
     #[inline(always)]
     fn split_prefix_opt<P>(self, prefix: P) -> (Option<Self>, Self)
@@ -516,6 +1034,19 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
         })
     }
 
+    /// Splits at the end of a pattern in the input if it is present, keeping
+    /// the match in the head.
+    #[inline(always)]
+    fn split_until_inclusive_opt<P>(self, pattern: P) -> Option<(Self, Self)>
+    where
+        P: Pattern<Self>,
+    {
+        pattern.find_match(&self).map(|(index, len)| {
+            // SAFETY: Pattern guarantees it returns valid indexes.
+            unsafe { self.split_at_byte_unchecked(index + len) }
+        })
+    }
+
     /// Splits the input up to when the pattern matches.
     #[inline(always)]
     fn split_until_for<P, E>(self, pattern: P, operation: CoreOperation) -> Result<(Self, Self), E>
@@ -536,6 +1067,30 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
         })
     }
 
+    /// Splits the input up to when the pattern last matches.
+    #[inline(always)]
+    fn split_until_last_for<P, E>(
+        self,
+        pattern: P,
+        operation: CoreOperation,
+    ) -> Result<(Self, Self), E>
+    where
+        E: From<ExpectedValue<'i>>,
+        P: Pattern<Self> + Into<Value<'i>> + Copy,
+    {
+        self.clone().split_until_last_opt(pattern).ok_or_else(|| {
+            E::from(ExpectedValue {
+                expected: pattern.into(),
+                context: CoreContext {
+                    span: self.span(),
+                    operation,
+                    expected: CoreExpected::PatternMatch,
+                },
+                input: self.into_maybe_string(),
+            })
+        })
+    }
+
     /// Splits at a pattern in the input if it is present.
     ///
     /// # Errors
@@ -566,6 +1121,37 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
             })
     }
 
+    /// Splits at the end of a pattern in the input if it is present, keeping
+    /// the match in the head.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input does not have the pattern.
+    #[inline(always)]
+    fn split_until_inclusive_for<P, E>(
+        self,
+        pattern: P,
+        operation: CoreOperation,
+    ) -> Result<(Self, Self), E>
+    where
+        E: From<ExpectedValue<'i>>,
+        P: Pattern<Self> + Into<Value<'i>> + Copy,
+    {
+        self.clone()
+            .split_until_inclusive_opt(pattern)
+            .ok_or_else(|| {
+                E::from(ExpectedValue {
+                    expected: pattern.into(),
+                    context: CoreContext {
+                        span: self.span(),
+                        operation,
+                        expected: CoreExpected::PatternMatch,
+                    },
+                    input: self.into_maybe_string(),
+                })
+            })
+    }
+
     /// Splits the input up to when the provided function returns `false`.
     #[inline(always)]
     fn split_while_opt<P>(self, pattern: P) -> Option<(Self, Self)>
@@ -578,6 +1164,53 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
         })
     }
 
+    /// Splits the input up to when the pattern doesn't match, requiring at
+    /// least one token to match.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if no tokens matched.
+    #[inline(always)]
+    fn split_while1_for<P, E>(
+        self,
+        pattern: P,
+        expected: &'static str,
+        operation: CoreOperation,
+    ) -> Result<(Self, Self), E>
+    where
+        E: From<ExpectedValid<'i>>,
+        P: Pattern<Self>,
+    {
+        let (head, tail) = match self.clone().split_while_opt(pattern) {
+            Some(split) => split,
+            None => (self.clone(), self.clone().end()),
+        };
+        if head.is_empty() {
+            // If there was no input to match against, more may still match.
+            let truncated = self.is_empty();
+            Err(E::from(ExpectedValid {
+                reason: if truncated {
+                    InvalidReason::Truncated
+                } else {
+                    InvalidReason::Malformed
+                },
+                retry_requirement: if truncated {
+                    RetryRequirement::new(1)
+                } else {
+                    None
+                },
+                context: CoreContext {
+                    span: self.as_dangerous_bytes()[..0].into(),
+                    operation,
+                    expected: CoreExpected::Valid(expected),
+                },
+                input: self.into_maybe_string(),
+            }))
+        } else {
+            Ok((head, tail))
+        }
+    }
+
     /// Tries to split the input up to when the provided function returns
     /// `false`.
     ///
@@ -593,14 +1226,33 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
     where
         E: WithContext<'i>,
         F: FnMut(Self::Token) -> Result<bool, E>,
+    {
+        self.try_split_while_indexed_for(|_, token| f(token), operation)
+    }
+
+    /// Tries to split the input up to when the provided function returns
+    /// `false`, passing the index of each token (not the byte index).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error from the provided function if it fails.
+    #[inline(always)]
+    fn try_split_while_indexed_for<F, E>(
+        self,
+        mut f: F,
+        operation: CoreOperation,
+    ) -> Result<(Self, Self), E>
+    where
+        E: WithContext<'i>,
+        F: FnMut(usize, Self::Token) -> Result<bool, E>,
     {
         // For each token, lets make sure it matches the predicate.
-        for (i, token) in self.clone().tokens_indices() {
+        for (n, (i, token)) in self.clone().tokens_indices().enumerate() {
             // Check if the token doesn't match the predicate.
             let should_continue = with_context(
                 CoreContext::from_operation(operation, self.span()),
                 self.clone(),
-                || f(token),
+                || f(n, token),
             )?;
             if !should_continue {
                 // Split the input up to, but not including the token.
@@ -703,6 +1355,7 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
             let tail = reader.take_remaining();
             let span = self.as_dangerous_bytes()[..self.byte_len() - tail.byte_len()].into();
             Err(E::from(ExpectedValid {
+                reason: InvalidReason::Malformed,
                 retry_requirement: None,
                 context: CoreContext {
                     span,
@@ -749,6 +1402,7 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
                 context.span =
                     self.as_dangerous_bytes()[..self.byte_len() - tail.byte_len()].into();
                 Err(E::from(ExpectedValid {
+                    reason: InvalidReason::Malformed,
                     retry_requirement: None,
                     context,
                     input: self.into_maybe_string(),
@@ -806,8 +1460,14 @@ pub(crate) trait PrivateExt<'i>: Input<'i> {
         E: From<ExpectedValid<'i>>,
         Ex: External<'i>,
     {
+        let retry_requirement = external.retry_requirement();
         let error = E::from(ExpectedValid {
-            retry_requirement: external.retry_requirement(),
+            reason: if retry_requirement.is_some() {
+                InvalidReason::Truncated
+            } else {
+                InvalidReason::Malformed
+            },
+            retry_requirement,
             context: CoreContext {
                 span: external.span().unwrap_or_else(|| self.span()),
                 expected: CoreExpected::Valid(expected),
@@ -0,0 +1,334 @@
+mod pattern;
+
+use core::iter::{Enumerate, Map};
+use core::slice::ChunksExact;
+
+use crate::display::InputDisplay;
+use crate::error::{
+    CoreContext, CoreExpected, CoreOperation, ExpectedLength, ExpectedValid, InvalidReason, Length,
+};
+use crate::fmt;
+
+use super::{Bound, Bytes, Input, MaybeString, Private, String};
+
+/// UTF-16 code unit [`Input`].
+///
+/// Each token is a `u16` code unit read from two bytes in the byte order the
+/// input was created with. Token indices count code units while byte indices
+/// count the underlying bytes, so a byte index is only a token boundary if it
+/// is even.
+///
+/// Surrogate pairs are not decoded, so splitting between the two halves of a
+/// pair is valid. Use [`String::from_utf16_le()`] or
+/// [`String::from_utf16_be()`] to decode into an owned string.
+#[derive(Clone)]
+#[must_use = "input must be consumed"]
+pub struct Utf16<'i> {
+    bytes: Bytes<'i>,
+    big_endian: bool,
+}
+
+impl<'i> Utf16<'i> {
+    /// Creates UTF-16 input from little endian bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid, Utf16};
+    ///
+    /// let input = Utf16::from_le_bytes::<Invalid>(dangerous::input(b"h\0i\0")).unwrap();
+    ///
+    /// assert_eq!(input.first(), Some(0x68));
+    /// assert_eq!(input.num_units(), 2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if the input has an odd number of bytes.
+    pub fn from_le_bytes<E>(bytes: Bytes<'i>) -> Result<Self, E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        Self::from_bytes(bytes, false)
+    }
+
+    /// Creates UTF-16 input from big endian bytes.
+    ///
+    /// See [`Utf16::from_le_bytes()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if the input has an odd number of bytes.
+    pub fn from_be_bytes<E>(bytes: Bytes<'i>) -> Result<Self, E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        Self::from_bytes(bytes, true)
+    }
+
+    /// Returns the number of UTF-16 code units in the input.
+    #[must_use]
+    #[inline(always)]
+    pub fn num_units(&self) -> usize {
+        self.bytes.len() / 2
+    }
+
+    /// Returns `true` if the code units are read as big endian.
+    #[must_use]
+    #[inline(always)]
+    pub fn is_big_endian(&self) -> bool {
+        self.big_endian
+    }
+
+    /// Returns the underlying byte slice.
+    ///
+    /// See [`Bytes::as_dangerous`] for naming.
+    #[must_use]
+    #[inline(always)]
+    pub fn as_dangerous(&self) -> &'i [u8] {
+        self.bytes.as_dangerous()
+    }
+
+    fn from_bytes<E>(bytes: Bytes<'i>, big_endian: bool) -> Result<Self, E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        if bytes.len() % 2 == 0 {
+            Ok(Self { bytes, big_endian })
+        } else {
+            Err(E::from(ExpectedLength {
+                len: Length::AtLeast(bytes.len() + 1),
+                context: CoreContext {
+                    span: bytes.span(),
+                    operation: CoreOperation::IntoUtf16,
+                    expected: CoreExpected::EnoughInputFor("utf-16 code unit"),
+                },
+                input: bytes.into_maybe_string(),
+            }))
+        }
+    }
+
+    #[inline(always)]
+    fn with_bytes(&self, bytes: Bytes<'i>) -> Self {
+        Self {
+            bytes,
+            big_endian: self.big_endian,
+        }
+    }
+
+    #[inline(always)]
+    fn chunks(&self) -> ChunksExact<'i, u8> {
+        self.as_dangerous().chunks_exact(2)
+    }
+}
+
+impl<'i> Input<'i> for Utf16<'i> {
+    type Token = u16;
+
+    #[inline(always)]
+    fn bound(&self) -> Bound {
+        self.bytes.bound()
+    }
+
+    #[inline(always)]
+    fn into_bytes(self) -> Bytes<'i> {
+        self.bytes
+    }
+
+    /// UTF-16 can't be borrowed as UTF-8, so this fails unless the input is
+    /// empty. Use [`String::from_utf16_le()`] or [`String::from_utf16_be()`]
+    /// to decode into an owned string instead.
+    #[inline]
+    fn into_string<E>(self) -> Result<String<'i>, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        if self.is_empty() {
+            self.bytes.into_string()
+        } else {
+            Err(E::from(ExpectedValid {
+                reason: InvalidReason::Malformed,
+                retry_requirement: None,
+                context: CoreContext {
+                    span: self.span(),
+                    operation: CoreOperation::IntoString,
+                    expected: CoreExpected::Valid("utf-8 borrowed from utf-16 input"),
+                },
+                input: self.into_maybe_string(),
+            }))
+        }
+    }
+
+    #[inline(always)]
+    fn into_bound(mut self) -> Self {
+        self.bytes = self.bytes.into_bound();
+        self
+    }
+
+    #[inline(always)]
+    fn into_bound_end(mut self) -> Self {
+        self.bytes = self.bytes.into_bound_end();
+        self
+    }
+
+    #[inline(always)]
+    fn into_maybe_string(self) -> MaybeString<'i> {
+        MaybeString::Bytes(self.bytes)
+    }
+
+    fn display(&self) -> InputDisplay<'i> {
+        InputDisplay::new(self)
+    }
+
+    #[inline(always)]
+    fn split_at_opt(self, mid: usize) -> Option<(Self, Self)> {
+        match mid.checked_mul(2) {
+            // SAFETY: the byte index is even and within the input.
+            Some(byte_mid) if byte_mid <= self.byte_len() => {
+                Some(unsafe { self.split_at_byte_unchecked(byte_mid) })
+            }
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    fn join(self, other: Self, parent: &Self) -> Option<Self> {
+        if self.big_endian == other.big_endian {
+            // Both inputs have an even length, so the joined input does too.
+            let bytes = self.bytes.clone().join(other.bytes, &parent.bytes)?;
+            Some(self.with_bytes(bytes))
+        } else {
+            None
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Private
+
+type UnitFn<'i> = fn(&'i [u8]) -> u16;
+type UnitIndexFn<'i> = fn((usize, &'i [u8])) -> (usize, u16);
+type UnitIter<'i> = Map<ChunksExact<'i, u8>, UnitFn<'i>>;
+type UnitIndicesIter<'i> = Map<Enumerate<ChunksExact<'i, u8>>, UnitIndexFn<'i>>;
+
+fn unit_le(chunk: &[u8]) -> u16 {
+    u16::from_le_bytes([chunk[0], chunk[1]])
+}
+
+fn unit_be(chunk: &[u8]) -> u16 {
+    u16::from_be_bytes([chunk[0], chunk[1]])
+}
+
+fn unit_index_le((i, chunk): (usize, &[u8])) -> (usize, u16) {
+    (i * 2, unit_le(chunk))
+}
+
+fn unit_index_be((i, chunk): (usize, &[u8])) -> (usize, u16) {
+    (i * 2, unit_be(chunk))
+}
+
+impl<'i> Private<'i, u16> for Utf16<'i> {
+    type TokenIter = UnitIter<'i>;
+    type TokenIndicesIter = UnitIndicesIter<'i>;
+
+    #[inline(always)]
+    fn end(self) -> Self {
+        self.with_bytes(self.bytes.clone().end())
+    }
+
+    #[inline(always)]
+    fn tokens(self) -> Self::TokenIter {
+        let unit: UnitFn<'i> = if self.big_endian { unit_be } else { unit_le };
+        self.chunks().map(unit)
+    }
+
+    #[inline(always)]
+    fn tokens_indices(self) -> Self::TokenIndicesIter {
+        let unit_index: UnitIndexFn<'i> = if self.big_endian {
+            unit_index_be
+        } else {
+            unit_index_le
+        };
+        self.chunks().enumerate().map(unit_index)
+    }
+
+    #[inline(always)]
+    fn into_unbound_end(mut self) -> Self {
+        self.bytes = self.bytes.into_unbound_end();
+        self
+    }
+
+    #[inline(always)]
+    fn with_bound(mut self, bound: Bound) -> Self {
+        self.bytes = self.bytes.with_bound(bound);
+        self
+    }
+
+    #[inline(always)]
+    fn verify_token_boundary(&self, index: usize) -> Result<(), CoreExpected> {
+        if index > self.byte_len() {
+            Err(CoreExpected::EnoughInputFor("code unit index"))
+        } else if index % 2 == 0 {
+            Ok(())
+        } else {
+            Err(CoreExpected::Valid("code unit index"))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn split_at_byte_unchecked(self, mid: usize) -> (Self, Self) {
+        let (head, tail) = self.bytes.clone().split_at_byte_unchecked(mid);
+        (self.with_bytes(head), self.with_bytes(tail))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Equality
+
+impl PartialEq for Utf16<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.num_units() == other.num_units() && self.clone().tokens().eq(other.clone().tokens())
+    }
+}
+
+impl PartialEq<[u16]> for Utf16<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &[u16]) -> bool {
+        self.num_units() == other.len() && self.clone().tokens().eq(other.iter().copied())
+    }
+}
+
+impl<const N: usize> PartialEq<[u16; N]> for Utf16<'_> {
+    #[inline(always)]
+    fn eq(&self, other: &[u16; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Formatting
+
+impl fmt::Debug for Utf16<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let display = self.display().with_formatter(f);
+        f.debug_struct("Utf16")
+            .field("bound", &self.bound())
+            .field("big_endian", &self.big_endian)
+            .field("value", &display)
+            .finish()
+    }
+}
+
+impl fmt::DisplayBase for Utf16<'_> {
+    fn fmt(&self, w: &mut dyn fmt::Write) -> fmt::Result {
+        self.display().fmt(w)
+    }
+}
+
+impl fmt::Display for Utf16<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display().with_formatter(f).fmt(f)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::input::{Pattern, Private, Utf16};
+
+///////////////////////////////////////////////////////////////////////////////
+// Fn pattern
+
+unsafe impl<'i, F> Pattern<Utf16<'i>> for F
+where
+    F: FnMut(u16) -> bool,
+{
+    fn find_match(mut self, input: &Utf16<'i>) -> Option<(usize, usize)> {
+        input
+            .clone()
+            .tokens_indices()
+            .find_map(|(i, unit)| if (self)(unit) { Some((i, 2)) } else { None })
+    }
+
+    fn find_reject(mut self, input: &Utf16<'i>) -> Option<usize> {
+        input
+            .clone()
+            .tokens_indices()
+            .find_map(|(i, unit)| if (self)(unit) { None } else { Some(i) })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Token pattern
+
+unsafe impl<'i> Pattern<Utf16<'i>> for u16 {
+    fn find_match(self, input: &Utf16<'i>) -> Option<(usize, usize)> {
+        (|unit| unit == self).find_match(input)
+    }
+
+    fn find_reject(self, input: &Utf16<'i>) -> Option<usize> {
+        (|unit| unit == self).find_reject(input)
+    }
+}
@@ -23,8 +23,11 @@
 //! | `unicode`        | **Enabled** | Enables improved unicode printing support.         |
 //! | `full-backtrace` | **Enabled** | Enables collection of all contexts for `Expected`. |
 //! | `zc`             | _Disabled_  | Enables `zc` crate support.                        |
-//! | `nom`            | _Disabled_  | Enables `nom` crate error support.                 |
+//! | `nom`            | _Disabled_  | Enables `nom` crate error and parser support.      |
 //! | `regex`          | _Disabled_  | Enables `regex` pattern support.                   |
+//! | `bytes`          | _Disabled_  | Enables `bytes` crate input support.               |
+//! | `serde`          | _Disabled_  | Enables `serde` serialization of spans and errors. |
+//! | `tracing`        | _Disabled_  | Enables `tracing` spans for reader contexts.       |
 
 ///////////////////////////////////////////////////////////////////////////////
 // Library quirks & hacks
@@ -73,8 +76,11 @@ pub mod error;
 pub mod input;
 
 pub use self::error::{Error, Expected, Fatal, Invalid, ToRetryRequirement};
-pub use self::input::{Bound, ByteArray, Bytes, Input, MaybeString, Span, String};
-pub use self::reader::{BytesReader, Peek, Reader, StringReader};
+pub use self::input::{Bound, ByteArray, Bytes, Input, MaybeString, Span, String, Utf16};
+pub use self::reader::{
+    BitReader, BytesReader, Checkpoint, ChunkIter, Eol, Peek, ProgressReader, Reader, StringReader,
+    Utf16Reader,
+};
 
 // Re-exported types from core::fmt along with `DisplayBase` and `Write`.
 // This is used crate wide with the exception of crate::display.
@@ -102,3 +108,58 @@ where
 {
     input.into_input()
 }
+
+/// Concatenates byte slices into an owned buffer to create an `Input` from.
+///
+/// See [`Bytes::concat()`] for reassembling fragmented input. The result is
+/// owned, so any spans from the input created from it are relative to the
+/// concatenated buffer.
+///
+/// ```
+/// use dangerous::{Input, Invalid};
+///
+/// let buf = dangerous::input_concat(&[b"hel", b"lo"]);
+/// let result: Result<_, Invalid> = dangerous::input(&buf[..]).read_all(|r| r.consume(b"hello"));
+///
+/// assert!(result.is_ok());
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[must_use]
+pub fn input_concat(slices: &[&[u8]]) -> alloc::vec::Vec<u8> {
+    slices.concat()
+}
+
+/// Creates a new `Input` from the current contiguous chunk of a
+/// [`bytes::Buf`].
+///
+/// The input borrows the chunk without copying, so it can't outlive the
+/// borrow of the buffer. Note a `Buf` may hold more remaining bytes than its
+/// current chunk.
+///
+/// ```
+/// use dangerous::{Input, Invalid};
+///
+/// let buf = bytes::Bytes::from_static(b"hello");
+/// let result: Result<_, Invalid> = dangerous::input_from_buf(&buf).read_all(|r| r.consume(b"hello"));
+///
+/// assert!(result.is_ok());
+/// ```
+///
+/// The input can't outlive the buffer it was created from.
+///
+/// ```compile_fail
+/// let input = {
+///     let buf = bytes::Bytes::from(vec![1, 2, 3]);
+///     dangerous::input_from_buf(&buf)
+/// };
+/// ```
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+#[inline(always)]
+pub fn input_from_buf<B>(buf: &B) -> Bytes<'_>
+where
+    B: bytes::Buf + ?Sized,
+{
+    input(buf.chunk())
+}
@@ -0,0 +1,144 @@
+use crate::error::{
+    CoreContext, CoreExpected, CoreOperation, ExpectedLength, ExpectedValid, InvalidReason, Length,
+};
+use crate::fmt;
+use crate::input::{Bytes, Input, Private};
+
+use super::BytesReader;
+
+/// Reads bit fields, most significant bit first, from a [`BytesReader`].
+///
+/// Created with [`BytesReader::bits()`]. Whole bytes are consumed from the
+/// parent reader as they are read. A partially read byte is only consumed
+/// once it is completed, or skipped with [`BitReader::align()`]; if the
+/// `BitReader` is dropped mid-byte, the partial byte is left unconsumed.
+///
+/// [`BytesReader::bits()`]: crate::BytesReader::bits()
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Invalid};
+///
+/// let result: Result<_, Invalid> = dangerous::input(&[0b1010_1100]).read_all(|r| {
+///     let mut bits = r.bits();
+///     let flags = bits.read_bits(3)?;
+///     let value = bits.read_bits(5)?;
+///     bits.finish()?;
+///     Ok((flags, value))
+/// });
+///
+/// assert_eq!(result.unwrap(), (0b101, 0b01100));
+/// ```
+#[must_use = "bit readers must be read from"]
+pub struct BitReader<'r, 'i, E> {
+    reader: &'r mut BytesReader<'i, E>,
+    /// The number of bits read from the first byte of the reader's input.
+    offset: u8,
+}
+
+impl<'r, 'i, E> BitReader<'r, 'i, E> {
+    #[inline(always)]
+    pub(super) fn new(reader: &'r mut BytesReader<'i, E>) -> Self {
+        Self { reader, offset: 0 }
+    }
+
+    /// Returns `true` if the reader is at a byte boundary.
+    #[must_use]
+    #[inline(always)]
+    pub fn is_aligned(&self) -> bool {
+        self.offset == 0
+    }
+
+    /// Read an `n` bit unsigned field, most significant bit first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there are not enough bits left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than `64`.
+    pub fn read_bits(&mut self, n: u8) -> Result<u64, E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        assert!(n <= 64, "can't read more than 64 bits into a u64");
+        let start = usize::from(self.offset);
+        let end = start + usize::from(n);
+        let needed = (end + 7) / 8;
+        let input = self.reader.input.clone();
+        let bytes = input.as_dangerous();
+        if bytes.len() < needed {
+            return Err(E::from(ExpectedLength {
+                len: Length::AtLeast(needed),
+                context: CoreContext {
+                    span: input.span(),
+                    operation: CoreOperation::ReadBits,
+                    expected: CoreExpected::EnoughInputFor("bits"),
+                },
+                input: input.into_maybe_string(),
+            }));
+        }
+        let value = (start..end).fold(0, |value, i| {
+            let bit = (bytes[i / 8] >> (7 - i % 8)) & 1;
+            (value << 1) | u64::from(bit)
+        });
+        self.skip_bytes(end / 8);
+        // Won't truncate as the remainder is less than 8.
+        #[allow(clippy::cast_possible_truncation)]
+        let offset = (end % 8) as u8;
+        self.offset = offset;
+        Ok(value)
+    }
+
+    /// Skips the remaining bits of a partially read byte.
+    pub fn align(&mut self) {
+        if !self.is_aligned() {
+            self.skip_bytes(1);
+            self.offset = 0;
+        }
+    }
+
+    /// Finishes reading bits, returning the parent reader to reading bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if the reader is not at a byte boundary.
+    pub fn finish(self) -> Result<(), E>
+    where
+        E: From<ExpectedValid<'i>>,
+    {
+        if self.is_aligned() {
+            return Ok(());
+        }
+        let input = self.reader.input.clone();
+        Err(E::from(ExpectedValid {
+            reason: InvalidReason::Malformed,
+            retry_requirement: None,
+            context: CoreContext {
+                span: input.as_dangerous()[..1].into(),
+                operation: CoreOperation::FinishBits,
+                expected: CoreExpected::Valid("byte aligned bits"),
+            },
+            input: input.into_maybe_string(),
+        }))
+    }
+
+    fn skip_bytes(&mut self, len: usize) {
+        self.reader.advance(|input: Bytes<'i>| {
+            // SAFETY: callers only skip bytes that were checked to be present.
+            let (_, tail) = unsafe { input.split_at_byte_unchecked(len) };
+            ((), tail)
+        });
+    }
+}
+
+impl<E> fmt::Debug for BitReader<'_, '_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BitReader")
+            .field("reader", &self.reader)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
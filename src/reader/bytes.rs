@@ -1,9 +1,262 @@
-use crate::error::{CoreOperation, ExpectedLength, ExpectedValid, WithContext};
-use crate::input::{ByteArray, Bytes, String};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
 
-use super::BytesReader;
+use crate::error::{
+    CoreContext, CoreExpected, CoreOperation, ExpectedLength, ExpectedValid, ExpectedValue, Length,
+    WithContext,
+};
+use crate::input::{ByteArray, Bytes, Input, Pattern, Private, PrivateExt, String};
+
+use super::{BitReader, BytesReader, Eol};
 
 impl<'i, E> BytesReader<'i, E> {
+    /// Read a byte within an inclusive range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"5").read_all(|r| {
+    ///     r.read_u8_in("digit", b'0'..=b'9')
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), b'5');
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is no more input and
+    /// [`ExpectedValid`] with a span over the byte if it is not within the
+    /// range.
+    #[inline]
+    pub fn read_u8_in(&mut self, expected: &'static str, range: RangeInclusive<u8>) -> Result<u8, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(|input| input.split_token_in_for(range, expected, CoreOperation::ReadByte))
+    }
+
+    /// Read a byte and map it to a value, such as an enum variant from a tag.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Kind {
+    ///     Request,
+    ///     Response,
+    /// }
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(&[0x01]).read_all(|r| {
+    ///     r.read_u8_map("message kind", |b| match b {
+    ///         0x00 => Some(Kind::Request),
+    ///         0x01 => Some(Kind::Response),
+    ///         _ => None,
+    ///     })
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), Kind::Response);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is no more input and
+    /// [`ExpectedValid`] with a span over the byte if `f` returns `None`.
+    #[inline]
+    pub fn read_u8_map<F, T>(&mut self, expected: &'static str, f: F) -> Result<T, E>
+    where
+        F: FnOnce(u8) -> Option<T>,
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(|input| input.split_token_map_for(f, expected, CoreOperation::ReadByte))
+    }
+
+    /// Read a value from a frame of input with a length read first.
+    ///
+    /// The length is read with `read_len`, then `f` reads all of exactly that
+    /// many bytes. As the frame is bound, `f` can't read past it and any
+    /// length error within it is fatal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"\x05hello!").read_partial(|r| {
+    ///     r.read_length_prefixed(|r| r.read_u8(), |r| r.take_remaining_str())
+    /// });
+    ///
+    /// let (value, remaining) = result.unwrap();
+    /// assert_eq!(value, "hello");
+    /// assert_eq!(remaining, b"!"[..]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from reading the length, [`ExpectedLength`] if there
+    /// is less input than the length read or the length does not fit in a
+    /// `usize`, or any error from `f` including if it does not read all of the
+    /// frame.
+    pub fn read_length_prefixed<L, N, F, T>(&mut self, read_len: L, f: F) -> Result<T, E>
+    where
+        E: WithContext<'i>,
+        E: From<ExpectedLength<'i>>,
+        L: FnOnce(&mut Self) -> Result<N, E>,
+        N: TryInto<usize>,
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        // A length that does not fit in a `usize` can never be satisfied, so
+        // it saturates and fails splitting the frame below.
+        let len = read_len(self)?.try_into().unwrap_or(usize::MAX);
+        let frame =
+            self.try_advance(|input| input.split_at_for(len, CoreOperation::ReadLengthPrefixed))?;
+        frame.into_bound().read_all(f)
+    }
+
+    /// Read the input between a balanced pair of delimiters.
+    ///
+    /// The next byte must be `open`. Returns the input up to the matching
+    /// `close`, accounting for nested pairs, and consumes both delimiters.
+    /// Delimiters are matched as raw bytes, so nothing within the input is
+    /// treated as escaped or quoted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"(a(b)c)!").read_partial(|r| {
+    ///     r.take_balanced(b'(', b')')
+    /// });
+    ///
+    /// let (inner, remaining) = result.unwrap();
+    /// assert_eq!(inner, b"a(b)c"[..]);
+    /// assert_eq!(remaining, b"!"[..]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValue`] if the next byte is not `open` and
+    /// [`ExpectedLength`] if the input ends before the delimiters are balanced.
+    pub fn take_balanced(&mut self, open: u8, close: u8) -> Result<Bytes<'i>, E>
+    where
+        E: From<ExpectedValue<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(|input| {
+            let (_, rest) = input
+                .clone()
+                .split_prefix_for::<_, E>(open, CoreOperation::TakeBalanced)?;
+            let mut depth = 1_usize;
+            for (i, &b) in rest.as_dangerous().iter().enumerate() {
+                if b == close {
+                    depth -= 1;
+                    if depth == 0 {
+                        // SAFETY: `i` is an index of a byte within `rest` and
+                        // `i + 1` is at most its length.
+                        let (inner, tail) = unsafe { rest.split_at_byte_unchecked(i) };
+                        let (_, tail) = unsafe { tail.split_at_byte_unchecked(1) };
+                        return Ok((inner, tail));
+                    }
+                } else if b == open {
+                    depth += 1;
+                }
+            }
+            Err(E::from(ExpectedLength {
+                // At least one closing delimiter is required for each open.
+                len: Length::AtLeast(input.byte_len() + depth),
+                context: CoreContext {
+                    span: input.span(),
+                    operation: CoreOperation::TakeBalanced,
+                    expected: CoreExpected::EnoughInputFor("balanced delimiters"),
+                },
+                input: input.into_maybe_string(),
+            }))
+        })
+    }
+
+    /// Read a NUL terminated string.
+    ///
+    /// Returns the input leading up to the first `\0`, consuming the `\0`. The
+    /// returned input is bound at both sides as the terminator fixes its end.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"abc\0rest").read_partial(|r| {
+    ///     r.read_cstr()
+    /// });
+    ///
+    /// let (s, remaining) = result.unwrap();
+    /// assert_eq!(s, b"abc"[..]);
+    /// assert_eq!(remaining, b"rest"[..]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if no `\0` could be found.
+    pub fn read_cstr(&mut self) -> Result<Bytes<'i>, E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(Self::split_cstr)
+    }
+
+    /// Read a NUL terminated UTF-8 string.
+    ///
+    /// See [`BytesReader::read_cstr()`] for details. The `Reader` is only
+    /// advanced if the string is valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if no `\0` could be found and
+    /// [`ExpectedValid`] if the string is not valid UTF-8.
+    pub fn read_cstr_str(&mut self) -> Result<String<'i>, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(|input| {
+            let (head, tail) = Self::split_cstr(input)?;
+            Ok((head.into_string::<E>()?, tail))
+        })
+    }
+
+    fn split_cstr(input: Bytes<'i>) -> Result<(Bytes<'i>, Bytes<'i>), E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        match input.clone().split_until_consume_opt(0_u8) {
+            Some(split) => Ok(split),
+            None => Err(E::from(ExpectedLength {
+                // The terminator requires at least one more byte.
+                len: Length::AtLeast(input.byte_len() + 1),
+                context: CoreContext {
+                    span: input.span(),
+                    operation: CoreOperation::ReadCStr,
+                    expected: CoreExpected::EnoughInputFor("NUL terminator"),
+                },
+                input: input.into_maybe_string(),
+            })),
+        }
+    }
+
+    /// Read bit fields from the input.
+    ///
+    /// See [`BitReader`] for details.
+    #[inline(always)]
+    pub fn bits(&mut self) -> BitReader<'_, 'i, E> {
+        BitReader::new(self)
+    }
+
     /// Read an array from input.
     ///
     /// # Integers
@@ -31,6 +284,65 @@ impl<'i, E> BytesReader<'i, E> {
         self.try_advance(|input| input.split_array(CoreOperation::TakeArray))
     }
 
+    /// Read a [`ByteArray`] view of the next `N` bytes.
+    ///
+    /// The returned array borrows from the input rather than copying it. This
+    /// is equivalent to [`BytesReader::take_array()`]; use
+    /// [`BytesReader::read_array()`] for an owned copy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let input = dangerous::input(&[1, 2, 3, 4, 5]);
+    /// let result: Result<_, Invalid> = input.read_partial(|r| r.read_byte_array::<4>());
+    /// let (array, remaining) = result.unwrap();
+    ///
+    /// assert_eq!(array.into_array(), [1, 2, 3, 4]);
+    /// assert_eq!(remaining, [5][..]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is less than `N` bytes of input.
+    #[inline]
+    pub fn read_byte_array<const N: usize>(&mut self) -> Result<ByteArray<'i, N>, E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        self.take_array()
+    }
+
+    /// Read an array from input, copied out of the input.
+    ///
+    /// Unlike [`BytesReader::take_array()`], the returned array is owned and
+    /// not tied to the lifetime of the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(&[1, 2, 3, 4]).read_all(|r| {
+    ///     r.read_array::<4>()
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), [1, 2, 3, 4]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is less than `N` bytes of input.
+    #[inline]
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(|input| input.split_array(CoreOperation::ReadArray))
+            .map(ByteArray::into_dangerous)
+    }
+
     /// Read an optional array.
     ///
     /// Returns `Some(ByteArray)` if there was enough input, `None` if not.
@@ -39,8 +351,189 @@ impl<'i, E> BytesReader<'i, E> {
         self.advance_opt(Bytes::split_array_opt)
     }
 
+    /// Read a length of input while a pattern matches into a provided buffer.
+    ///
+    /// Returns the number of bytes written to `out`. This has the same
+    /// semantics as [`Reader::take_while()`] without requiring an allocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let mut buf = [0; 8];
+    /// let result: Result<_, Invalid> = dangerous::input(b"hello!").read_all(|r| {
+    ///     let len = r.take_while_into(&mut buf, |b: u8| b.is_ascii_alphabetic())?;
+    ///     r.consume(b'!')?;
+    ///     Ok(len)
+    /// });
+    ///
+    /// assert_eq!(&buf[..result.unwrap()], b"hello");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if the pattern matched more bytes than `out`
+    /// can hold. The reader is not advanced in this case.
+    ///
+    /// [`Reader::take_while()`]: crate::Reader::take_while()
+    pub fn take_while_into<P>(&mut self, out: &mut [u8], pattern: P) -> Result<usize, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        P: Pattern<Bytes<'i>>,
+    {
+        self.try_advance(|input| input.split_while_into(out, pattern, CoreOperation::TakeWhileInto))
+    }
+
+    /// Read a length of input until any of the provided delimiters matches.
+    ///
+    /// Returns the input leading up to the delimiter and the delimiter that
+    /// matched. The delimiter is not consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"key;value").read_all(|r| {
+    ///     let (key, delimiter) = r.take_until_any(b",;")?;
+    ///     r.skip(1)?;
+    ///     Ok((key, delimiter, r.take_remaining()))
+    /// });
+    ///
+    /// let (key, delimiter, value) = result.unwrap();
+    ///
+    /// assert_eq!(key, b"key"[..]);
+    /// assert_eq!(delimiter, b';');
+    /// assert_eq!(value, b"value"[..]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValue`] if none of the delimiters could be found.
+    pub fn take_until_any(&mut self, delimiters: &'i [u8]) -> Result<(Bytes<'i>, u8), E>
+    where
+        E: From<ExpectedValue<'i>>,
+    {
+        self.try_advance(|input| input.split_until_any_for(delimiters, CoreOperation::TakeUntilAny))
+    }
+
+    /// Skip a length of input until any of the provided delimiters matches.
+    ///
+    /// Returns the delimiter that matched. The delimiter is not consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValue`] if none of the delimiters could be found.
+    pub fn skip_until_any(&mut self, delimiters: &'i [u8]) -> Result<u8, E>
+    where
+        E: From<ExpectedValue<'i>>,
+    {
+        self.try_advance(|input| input.split_until_any_for(delimiters, CoreOperation::SkipUntilAny))
+            .map(|(_, delimiter)| delimiter)
+    }
+
+    /// Consume an end of line, either `\n` or `\r\n`.
+    ///
+    /// Returns which end of line was consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Eol, Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"a\r\nb\n").read_all(|r| {
+    ///     r.consume(b'a')?;
+    ///     let first = r.consume_eol()?;
+    ///     r.consume(b'b')?;
+    ///     let second = r.consume_eol()?;
+    ///     Ok((first, second))
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), (Eol::CrLf, Eol::Lf));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValue`] if neither end of line is present. The error
+    /// is only retryable if the input is unbound and either empty or a sole
+    /// `\r`, as the remainder of an end of line may still follow.
+    pub fn consume_eol(&mut self) -> Result<Eol, E>
+    where
+        E: From<ExpectedValue<'i>>,
+    {
+        self.try_advance(|input| {
+            input.split_eol_for(CoreOperation::ConsumeEol, CoreExpected::ExactValue)
+        })
+    }
+
+    /// Consume a newline, either `\r\n` or `\n`.
+    ///
+    /// Unlike [`BytesReader::consume_eol()`] this does not report which newline
+    /// was consumed, for tolerant parsing of protocols that specify `\r\n` but
+    /// are commonly sent with `\n`. A lone `\r` is not a newline.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"a\r\nb\n").read_all(|r| {
+    ///     r.consume(b'a')?;
+    ///     r.consume_newline()?;
+    ///     r.consume(b'b')?;
+    ///     r.consume_newline()
+    /// });
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValue`] if neither newline is present. The error is
+    /// only retryable if the input is unbound and either empty or a sole `\r`,
+    /// as the remainder of a newline may still follow.
+    pub fn consume_newline(&mut self) -> Result<(), E>
+    where
+        E: From<ExpectedValue<'i>>,
+    {
+        self.try_advance(|input| {
+            input.split_eol_for(
+                CoreOperation::ConsumeNewline,
+                CoreExpected::Valid("`\\r\\n` or `\\n`"),
+            )
+        })
+        .map(drop)
+    }
+
+    /// Consume an optional newline, either `\r\n` or `\n`.
+    ///
+    /// Returns `true` if a newline was consumed, `false` if not. A lone `\r`
+    /// is not consumed.
+    ///
+    /// Doesn't effect the internal state of the `Reader` if a newline couldn't
+    /// be consumed.
+    pub fn consume_newline_opt(&mut self) -> bool {
+        self.advance_opt(Bytes::split_eol_opt).is_some()
+    }
+
     /// Read the remaining string input.
     ///
+    /// The `Reader` is only advanced to the end if all of the remaining input
+    /// is valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"hello \xE2\x99\xA5").read_all(|r| {
+    ///     r.take_remaining_str()
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), "hello \u{2665}");
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns [`ExpectedValid`] if the input could never be valid UTF-8 and
@@ -120,3 +613,176 @@ impl<'i, E> BytesReader<'i, E> {
             .map(drop)
     }
 }
+
+impl<'i, E> BytesReader<'i, E>
+where
+    E: From<ExpectedValid<'i>>,
+    E: From<ExpectedLength<'i>>,
+{
+    /// Read an unsigned LEB128 encoded `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(&[0xE5, 0x8E, 0x26]).read_all(|r| {
+    ///     r.read_uleb128_u64()
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), 624_485);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if the input ends before the varint does and
+    /// [`ExpectedValid`] if the varint does not fit within 64 bits.
+    pub fn read_uleb128_u64(&mut self) -> Result<u64, E> {
+        self.try_advance(|input| input.split_leb128_for(false, CoreOperation::ReadVarint))
+    }
+
+    /// Read a signed LEB128 encoded `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(&[0xC0, 0xBB, 0x78]).read_all(|r| {
+    ///     r.read_sleb128_i64()
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), -123_456);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if the input ends before the varint does and
+    /// [`ExpectedValid`] if the varint does not fit within 64 bits.
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn read_sleb128_i64(&mut self) -> Result<i64, E> {
+        self.try_advance(|input| input.split_leb128_for(true, CoreOperation::ReadVarint))
+            // The value was sign extended, so this is a reinterpretation.
+            .map(|value| value as i64)
+    }
+
+    /// Read ASCII hex digits and decode them into a provided buffer.
+    ///
+    /// Two hex digits are read for each byte of `out`. Both upper and lower
+    /// case digits are accepted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let mut buf = [0; 4];
+    /// let result: Result<_, Invalid> = dangerous::input(b"deadBEEF").read_all(|r| {
+    ///     r.read_hex_into(&mut buf)
+    /// });
+    ///
+    /// assert!(result.is_ok());
+    /// assert_eq!(buf, [0xde, 0xad, 0xbe, 0xef]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there are not enough hex digits and
+    /// [`ExpectedValid`] if a byte is not a hex digit.
+    pub fn read_hex_into(&mut self, out: &mut [u8]) -> Result<(), E> {
+        let nibbles = out.len() * 2;
+        self.try_advance(|input| input.split_hex_into_for(out, nibbles, CoreOperation::ReadHex))
+    }
+
+    /// Read `len_nibbles` ASCII hex digits and decode them into bytes.
+    ///
+    /// See [`BytesReader::read_hex_into()`] for decoding without allocating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"ff00").read_all(|r| {
+    ///     r.read_hex_bytes(4)
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), [0xff, 0x00]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there are not enough hex digits and
+    /// [`ExpectedValid`] if a byte is not a hex digit or `len_nibbles` is odd.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn read_hex_bytes(&mut self, len_nibbles: usize) -> Result<Vec<u8>, E> {
+        let mut out = alloc::vec![0; len_nibbles / 2];
+        self.try_advance(|input| {
+            input.split_hex_into_for(&mut out, len_nibbles, CoreOperation::ReadHex)
+        })
+        .map(|()| out)
+    }
+}
+
+macro_rules! impl_read_num {
+    ($ty:ident, $read:ident => $op:ident) => {
+        #[doc = concat!("Read a `", stringify!($ty), "`.")]
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ExpectedLength`] if there is not enough input.
+        #[inline]
+        pub fn $read(&mut self) -> Result<$ty, E>
+        where
+            E: From<ExpectedLength<'i>>,
+        {
+            self.try_advance(|input| input.split_array(CoreOperation::$op))
+                .map(|arr| $ty::from_le_bytes(arr.into_dangerous()))
+        }
+    };
+    ($ty:ident, $read_le:ident => $op_le:ident, $read_be:ident => $op_be:ident) => {
+        #[doc = concat!("Read a little-endian encoded `", stringify!($ty), "`.")]
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ExpectedLength`] if there is not enough input.
+        #[inline]
+        pub fn $read_le(&mut self) -> Result<$ty, E>
+        where
+            E: From<ExpectedLength<'i>>,
+        {
+            self.try_advance(|input| input.split_array(CoreOperation::$op_le))
+                .map(|arr| $ty::from_le_bytes(arr.into_dangerous()))
+        }
+
+        #[doc = concat!("Read a big-endian encoded `", stringify!($ty), "`.")]
+        ///
+        /// # Errors
+        ///
+        /// Returns [`ExpectedLength`] if there is not enough input.
+        #[inline]
+        pub fn $read_be(&mut self) -> Result<$ty, E>
+        where
+            E: From<ExpectedLength<'i>>,
+        {
+            self.try_advance(|input| input.split_array(CoreOperation::$op_be))
+                .map(|arr| $ty::from_be_bytes(arr.into_dangerous()))
+        }
+    };
+}
+
+impl<'i, E> BytesReader<'i, E> {
+    impl_read_num!(u8, read_u8 => ReadU8);
+    impl_read_num!(i8, read_i8 => ReadI8);
+    impl_read_num!(u16, read_u16_le => ReadU16Le, read_u16_be => ReadU16Be);
+    impl_read_num!(i16, read_i16_le => ReadI16Le, read_i16_be => ReadI16Be);
+    impl_read_num!(u32, read_u32_le => ReadU32Le, read_u32_be => ReadU32Be);
+    impl_read_num!(i32, read_i32_le => ReadI32Le, read_i32_be => ReadI32Be);
+    impl_read_num!(u64, read_u64_le => ReadU64Le, read_u64_be => ReadU64Be);
+    impl_read_num!(i64, read_i64_le => ReadI64Le, read_i64_be => ReadI64Be);
+    impl_read_num!(u128, read_u128_le => ReadU128Le, read_u128_be => ReadU128Be);
+    impl_read_num!(i128, read_i128_le => ReadI128Le, read_i128_be => ReadI128Be);
+    impl_read_num!(f32, read_f32_le => ReadF32Le, read_f32_be => ReadF32Be);
+    impl_read_num!(f64, read_f64_le => ReadF64Le, read_f64_be => ReadF64Be);
+}
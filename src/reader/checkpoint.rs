@@ -0,0 +1,25 @@
+use core::marker::PhantomData;
+
+/// Position within a [`Reader`](crate::Reader) to restore to.
+///
+/// Created with [`Reader::checkpoint()`] and restored with
+/// [`Reader::restore()`].
+///
+/// [`Reader::checkpoint()`]: crate::Reader::checkpoint()
+/// [`Reader::restore()`]: crate::Reader::restore()
+#[must_use = "checkpoint must be restored to be of any use"]
+#[derive(Debug, Clone)]
+pub struct Checkpoint<'i, I> {
+    pub(super) input: I,
+    lifetime: PhantomData<&'i ()>,
+}
+
+impl<I> Checkpoint<'_, I> {
+    #[inline(always)]
+    pub(super) fn new(input: I) -> Self {
+        Self {
+            input,
+            lifetime: PhantomData,
+        }
+    }
+}
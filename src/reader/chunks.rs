@@ -0,0 +1,52 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::input::Input;
+
+/// Iterator of fixed length chunks of input.
+///
+/// Created with [`Reader::take_chunks()`]. Each chunk yielded is bound at
+/// both its start and end.
+///
+/// [`Reader::take_chunks()`]: crate::Reader::take_chunks()
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct ChunkIter<'i, I> {
+    input: I,
+    chunk_len: usize,
+    lifetime: PhantomData<&'i ()>,
+}
+
+impl<I> ChunkIter<'_, I> {
+    #[inline(always)]
+    pub(super) fn new(input: I, chunk_len: usize) -> Self {
+        Self {
+            input,
+            chunk_len,
+            lifetime: PhantomData,
+        }
+    }
+}
+
+impl<'i, I> Iterator for ChunkIter<'i, I>
+where
+    I: Input<'i>,
+{
+    type Item = I;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+        if let Some((chunk, tail)) = self.input.clone().split_at_opt(self.chunk_len) {
+            self.input = tail;
+            Some(chunk)
+        } else {
+            let last = self.input.clone().into_bound();
+            self.input = self.input.clone().end();
+            Some(last)
+        }
+    }
+}
+
+impl<'i, I> FusedIterator for ChunkIter<'i, I> where I: Input<'i> {}
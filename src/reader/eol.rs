@@ -0,0 +1,22 @@
+/// End of line read by [`BytesReader::consume_eol()`].
+///
+/// [`BytesReader::consume_eol()`]: crate::BytesReader::consume_eol()
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Eol {
+    /// A bare line feed (`\n`).
+    Lf,
+    /// A carriage return followed by a line feed (`\r\n`).
+    CrLf,
+}
+
+impl Eol {
+    /// Returns the end of line as bytes.
+    #[must_use]
+    #[inline(always)]
+    pub fn as_bytes(self) -> &'static [u8] {
+        match self {
+            Eol::Lf => b"\n",
+            Eol::CrLf => b"\r\n",
+        }
+    }
+}
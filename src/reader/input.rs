@@ -1,11 +1,15 @@
-use crate::input::{Input, Pattern, Prefix, PrivateExt};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::input::{Bound, Input, Pattern, Prefix, PrivateExt, Span};
+use crate::util;
 
 use crate::error::{
-    with_context, Context, CoreContext, CoreOperation, ExpectedLength, ExpectedValid,
-    ExpectedValue, External, Value, WithContext,
+    with_context, Context, CoreContext, CoreExpected, CoreOperation, ExpectedLength, ExpectedValid,
+    ExpectedValue, External, InvalidReason, Length, ToRetryRequirement, Value, WithContext,
 };
 
-use super::{Peek, Reader};
+use super::{Checkpoint, ChunkIter, Peek, Reader};
 
 impl<'i, I, E> Reader<'i, I, E>
 where
@@ -25,14 +29,139 @@ where
         self.input.byte_len()
     }
 
+    /// Returns the number of bytes consumed from the input the reader was
+    /// created with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"hello").read_partial(|r| {
+    ///     r.take(3)?;
+    ///     Ok(r.offset())
+    /// });
+    ///
+    /// assert_eq!(result.unwrap().0, 3);
+    /// ```
+    #[must_use]
+    #[inline(always)]
+    pub fn offset(&self) -> usize {
+        // The remaining input is always within the origin, unless restored
+        // from a checkpoint of an unrelated reader.
+        self.input
+            .span()
+            .range_of(Span::from(self.origin))
+            .map_or(0, |range| range.start)
+    }
+
+    /// Returns an empty [`Span`] at the current position of the reader.
+    ///
+    /// Pass it to [`Reader::context_region()`] once done reading to get the
+    /// [`Span`] of the input read in between.
+    #[inline(always)]
+    pub fn context_span(&self) -> Span {
+        self.input.span().start()
+    }
+
+    /// Returns the [`Span`] from a `start` taken with
+    /// [`Reader::context_span()`] to the current position of the reader.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let input = dangerous::input(b"key=value;");
+    /// let result: Result<_, Invalid> = input.clone().read_partial(|r| {
+    ///     let start = r.context_span();
+    ///     r.take_until(b'=')?;
+    ///     r.skip(1)?;
+    ///     r.take_until(b';')?;
+    ///     Ok(r.context_region(start))
+    /// });
+    /// let (region, _) = result.unwrap();
+    ///
+    /// assert_eq!(region.range_of(input.span()), Some(0..9));
+    /// ```
+    #[inline(always)]
+    pub fn context_region(&self, start: Span) -> Span {
+        start.union(self.context_span())
+    }
+
+    /// Returns the [`Bound`] of the remaining input within the reader.
+    #[inline(always)]
+    pub fn bound(&self) -> Bound {
+        self.input.bound()
+    }
+
+    /// Marks the remaining input within the reader as bound at its end.
+    ///
+    /// Use this when it is known no further input will follow what the reader
+    /// currently holds, for example when a complete frame has been received.
+    /// Any error from reading past the end of the remaining input will then be
+    /// fatal instead of producing a [`RetryRequirement`].
+    ///
+    /// Only call this when the end is truly known. Marking input bound that
+    /// could still be extended turns a recoverable incomplete read into a fatal
+    /// error, and a parse that would have succeeded with more input will not be
+    /// retried.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid, ToRetryRequirement};
+    ///
+    /// let error: Invalid = dangerous::input(b"1234")
+    ///     .read_partial(|r| {
+    ///         r.set_bound_end();
+    ///         r.take(5)
+    ///     })
+    ///     .unwrap_err();
+    ///
+    /// // If the end wasn't bound, this wouldn't be fatal.
+    /// assert!(error.is_fatal());
+    /// ```
+    ///
+    /// [`RetryRequirement`]: crate::error::RetryRequirement
+    #[inline(always)]
+    pub fn set_bound_end(&mut self) {
+        self.input = self.input.clone().into_bound_end();
+    }
+
     /// Read all of the remaining input.
     #[inline(always)]
     pub fn take_remaining(&mut self) -> I {
         self.advance(|input| (input.clone(), input.end()))
     }
 
+    /// Read all of the remaining input into an owned `Vec<u8>`.
+    ///
+    /// The `Reader` will be at the end of its input afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"hello world").read_all(|r| {
+    ///     r.consume(b"hello ")?;
+    ///     Ok(r.drain_to_vec())
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), b"world");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn drain_to_vec(&mut self) -> Vec<u8> {
+        self.take_remaining().as_dangerous_bytes().to_vec()
+    }
+
     /// Use the `Reader` in a mutable context.
     ///
+    /// With the `tracing` feature enabled, a `trace` level span named
+    /// `context` is entered while `f` runs.
+    ///
     /// # Errors
     ///
     /// Returns any error returned by the provided function with the specified
@@ -43,11 +172,16 @@ where
         E: WithContext<'i>,
         F: FnOnce(&mut Self) -> Result<T, E>,
     {
+        #[cfg(feature = "tracing")]
+        let _span = crate::support::tracing::enter_context(&context, self.offset());
         with_context(context, self.input.clone(), || f(self))
     }
 
     /// Use the `Reader` in an immutable context.
     ///
+    /// With the `tracing` feature enabled, a `trace` level span named
+    /// `context` is entered while `f` runs.
+    ///
     /// # Errors
     ///
     /// Returns any error returned by the provided function with the specified
@@ -58,9 +192,53 @@ where
         E: WithContext<'i>,
         F: FnOnce(&Self) -> Result<T, E>,
     {
+        #[cfg(feature = "tracing")]
+        let _span = crate::support::tracing::enter_context(&context, self.offset());
         with_context(context, self.input.clone(), || f(self))
     }
 
+    /// Use the `Reader` with a context that is only created if an error
+    /// occurs.
+    ///
+    /// Unlike [`Reader::context()`], `make_context` is not called on the
+    /// success path, so contexts computed from runtime state such as a
+    /// `ValueContext` are never built unless needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Expected};
+    /// use dangerous::error::ValueContext;
+    ///
+    /// let index = 3;
+    /// let error = dangerous::input(b"")
+    ///     .read_all::<_, _, Expected<'_>>(|r| {
+    ///         r.with_error_context(|| ValueContext::new(format!("item {}", index)), |r| r.read())
+    ///     })
+    ///     .unwrap_err();
+    ///
+    /// assert!(error.to_string().contains("(expected item 3)"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error returned by the provided function with the created
+    /// context attached.
+    #[inline(always)]
+    pub fn with_error_context<F, T, C>(
+        &mut self,
+        make_context: impl FnOnce() -> C,
+        f: F,
+    ) -> Result<T, E>
+    where
+        E: WithContext<'i>,
+        C: Context,
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        let input = self.input.clone();
+        f(self).map_err(|err| err.with_context(make_context()).with_input(input))
+    }
+
     /// Read a length of input that was successfully consumed from a sub-parse.
     pub fn take_consumed<F, T>(&mut self, consumer: F) -> (T, I)
     where
@@ -107,6 +285,43 @@ where
         })
     }
 
+    /// Try read a length of input that was successfully consumed from a
+    /// sub-parse, along with the [`Span`] of the consumed input.
+    ///
+    /// The span can be resolved against the input the `Reader` was created
+    /// from with [`Span::range_of()`], which is useful for attaching source
+    /// locations to parsed nodes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let input = dangerous::input(b"a=bc");
+    /// let result: Result<_, Invalid> = input.clone().read_all(|r| {
+    ///     r.consume(b"a=")?;
+    ///     r.try_take_consumed_spanned(|r| r.consume(b"bc"))
+    /// });
+    ///
+    /// let ((), consumed, span) = result.unwrap();
+    /// assert_eq!(consumed, b"bc"[..]);
+    /// assert_eq!(span.range_of(input.span()), Some(2..4));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error the provided function does.
+    pub fn try_take_consumed_spanned<F, T>(&mut self, consumer: F) -> Result<(T, I, Span), E>
+    where
+        E: WithContext<'i>,
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        self.try_take_consumed(consumer).map(|(value, consumed)| {
+            let span = consumed.span();
+            (value, consumed, span)
+        })
+    }
+
     /// Read and verify a value without returning it.
     ///
     /// # Errors
@@ -252,6 +467,46 @@ where
         })
     }
 
+    /// Captures the current position of the `Reader` to later restore to.
+    ///
+    /// Unlike [`Reader::recover()`], this allows reading ahead across several
+    /// reads before deciding whether to rewind.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"ab").read_all(|r| {
+    ///     let checkpoint = r.checkpoint();
+    ///     r.consume(b'a')?;
+    ///     if r.peek_eq(b'c') {
+    ///         r.skip(1)?;
+    ///         Ok(true)
+    ///     } else {
+    ///         r.restore(checkpoint);
+    ///         r.consume(b"ab")?;
+    ///         Ok(false)
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), false);
+    /// ```
+    #[inline(always)]
+    pub fn checkpoint(&self) -> Checkpoint<'i, I> {
+        Checkpoint::new(self.input.clone())
+    }
+
+    /// Restores the `Reader` to a position captured by
+    /// [`Reader::checkpoint()`].
+    ///
+    /// The checkpoint should come from the same `Reader`, otherwise the
+    /// `Reader` will continue reading the input of another.
+    #[inline(always)]
+    pub fn restore(&mut self, checkpoint: Checkpoint<'i, I>) {
+        self.input = checkpoint.input;
+    }
+
     /// Recovers from an error returning `Some(T)` if successful, or `None` if
     /// an error occurred.
     ///
@@ -307,6 +562,236 @@ where
         }
     }
 
+    /// Recovers from an error, mapping it into a value.
+    ///
+    /// If an error is recovered from the `Reader`'s internal state is reset
+    /// and `Ok(Err(R))` is returned with the mapped error. This allows
+    /// collecting errors while continuing to parse.
+    ///
+    /// # Errors
+    ///
+    /// Errors that can be retried with more input are not recovered, as more
+    /// input may have succeeded, and `Err(E)` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"a,b").read_all(|r| {
+    ///     r.set_bound_end();
+    ///     let first = r.recover_map(|r| r.consume(b"x"), |_| "not x")?;
+    ///     r.skip(3)?;
+    ///     Ok(first)
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), Err("not x"));
+    /// ```
+    #[inline]
+    pub fn recover_map<F, T, M, R>(&mut self, f: F, map: M) -> Result<Result<T, R>, E>
+    where
+        E: WithContext<'i> + ToRetryRequirement,
+        F: FnOnce(&mut Self) -> Result<T, E>,
+        M: FnOnce(E) -> R,
+    {
+        let checkpoint = self.input.clone();
+        match f(self) {
+            Ok(ok) => Ok(Ok(ok)),
+            Err(err) if err.is_fatal() => {
+                self.input = checkpoint;
+                Ok(Err(map(err)))
+            }
+            Err(err) => Err(err
+                .with_context(CoreContext::from_operation(
+                    CoreOperation::RecoverMap,
+                    checkpoint.span(),
+                ))
+                .with_input(checkpoint)),
+        }
+    }
+
+    /// Tries each branch in order, returning the value of the first to
+    /// succeed.
+    ///
+    /// The `Reader` is restored after each branch that fails. If all branches
+    /// fail, the error from the branch that read the furthest is returned,
+    /// preferring the earliest branch if several read as far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{BytesReader, Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"false!").read_partial(|r| {
+    ///     r.alt(&mut [
+    ///         &mut |r: &mut BytesReader<'_, _>| r.consume(b"true").map(|()| true),
+    ///         &mut |r: &mut BytesReader<'_, _>| r.consume(b"false").map(|()| false),
+    ///     ])
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), (false, dangerous::input(b"!")));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the branch that read the furthest if all branches
+    /// fail, or [`ExpectedValid`] if there are no branches.
+    #[allow(clippy::type_complexity)]
+    pub fn alt<T>(
+        &mut self,
+        branches: &mut [&mut dyn FnMut(&mut Self) -> Result<T, E>],
+    ) -> Result<T, E>
+    where
+        E: WithContext<'i>,
+        E: From<ExpectedValid<'i>>,
+    {
+        let checkpoint = self.input.clone();
+        let mut furthest: Option<(usize, E)> = None;
+        for branch in branches.iter_mut() {
+            match branch(self) {
+                Ok(ok) => return Ok(ok),
+                Err(err) => {
+                    let read = checkpoint.byte_len() - self.input.byte_len();
+                    self.input = checkpoint.clone();
+                    if furthest.as_ref().map_or(true, |(max, _)| read > *max) {
+                        furthest = Some((read, err));
+                    }
+                }
+            }
+        }
+        let context = CoreContext::from_operation(CoreOperation::Alt, checkpoint.span());
+        let err = match furthest {
+            Some((_, err)) => err,
+            None => E::from(ExpectedValid {
+                reason: InvalidReason::Malformed,
+                retry_requirement: None,
+                context: CoreContext {
+                    span: checkpoint.span().start(),
+                    operation: CoreOperation::Alt,
+                    expected: CoreExpected::Valid("at least one branch"),
+                },
+                input: checkpoint.clone().into_maybe_string(),
+            }),
+        };
+        Err(err.with_context(context).with_input(checkpoint))
+    }
+
+    /// Reads an optional value, returning `Ok(None)` only if it is absent.
+    ///
+    /// If the provided function fails with an error that is not fatal (more
+    /// input could still make it succeed), the value is considered absent, the
+    /// `Reader`'s internal state is reset and `Ok(None)` is returned. Unlike
+    /// [`Reader::recover()`], a value that is present but malformed is not
+    /// mistaken for an absent one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{BytesReader, Input, Invalid};
+    ///
+    /// fn read_suffix<'i>(r: &mut BytesReader<'i, Invalid>) -> Result<Option<u8>, Invalid> {
+    ///     r.optional(|r| {
+    ///         r.consume(b';')?;
+    ///         r.read()
+    ///     })
+    /// }
+    ///
+    /// // Absent
+    /// let result = dangerous::input(b"").read_all(read_suffix);
+    /// assert_eq!(result.unwrap(), None);
+    /// // Present
+    /// let result = dangerous::input(b";a").read_all(read_suffix);
+    /// assert_eq!(result.unwrap(), Some(b'a'));
+    /// // Present but malformed
+    /// let result = dangerous::input(b",a").read_all(read_suffix);
+    /// assert!(result.is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from the provided function if it is fatal.
+    #[inline]
+    pub fn optional<F, T>(&mut self, f: F) -> Result<Option<T>, E>
+    where
+        E: WithContext<'i> + ToRetryRequirement,
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        let checkpoint = self.input.clone();
+        match f(self) {
+            Ok(ok) => Ok(Some(ok)),
+            Err(err) if !err.is_fatal() => {
+                self.input = checkpoint;
+                Ok(None)
+            }
+            Err(err) => Err(err
+                .with_context(CoreContext::from_operation(
+                    CoreOperation::Optional,
+                    checkpoint.span(),
+                ))
+                .with_input(checkpoint)),
+        }
+    }
+
+    /// Read `n` values by calling the provided function `n` times.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let input = dangerous::input(&[0, 1, 0, 2, 0, 3]);
+    /// let result: Result<_, Invalid> = input.read_all(|r| r.read_n(3, |r| r.read_u16_be()));
+    ///
+    /// assert_eq!(result.unwrap(), [1, 2, 3]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from the provided function. The `Reader` is
+    /// not restored, so it is left where the failing read stopped.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn read_n<F, T>(&mut self, n: usize, mut f: F) -> Result<Vec<T>, E>
+    where
+        F: FnMut(&mut Self) -> Result<T, E>,
+    {
+        // `n` may come from untrusted input so the allocation is grown as
+        // values are read rather than up front.
+        let mut values = Vec::new();
+        for _ in 0..n {
+            values.push(f(self)?);
+        }
+        Ok(values)
+    }
+
+    /// Read `N` values into an array by calling the provided function `N`
+    /// times.
+    ///
+    /// Unlike [`Reader::read_n()`] this does not allocate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let input = dangerous::input(&[0, 1, 0, 2, 0, 3]);
+    /// let result: Result<[u16; 3], Invalid> =
+    ///     input.read_all(|r| r.read_array_of(|r| r.read_u16_be()));
+    ///
+    /// assert_eq!(result.unwrap(), [1, 2, 3]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from the provided function. The `Reader` is
+    /// not restored, so it is left where the failing read stopped.
+    pub fn read_array_of<T, F, const N: usize>(&mut self, mut f: F) -> Result<[T; N], E>
+    where
+        F: FnMut(&mut Self) -> Result<T, E>,
+    {
+        util::array::try_from_fn(|| f(self))
+    }
+
     /// Read with a different error type.
     ///
     /// Keep in mind using different errors types can increase your binary size,
@@ -342,13 +827,52 @@ where
     ///
     /// assert_eq!(result.unwrap(), 2);
     /// ```
+    ///
+    /// # Upgrading errors on failure
+    ///
+    /// A fast error such as [`Fatal`] can be used on the success path, only
+    /// re-reading the input with a detailed error if it fails. Converting the
+    /// [`Fatal`] error directly into [`Expected`] is also supported, but all
+    /// of the details of where and why the error occurred are lost.
+    ///
+    /// [`Fatal`]: crate::Fatal
+    /// [`Expected`]: crate::Expected
+    ///
+    /// ```
+    /// use dangerous::{BytesReader, Error, Expected, Fatal, Input};
+    ///
+    /// fn parse<'i, E>(r: &mut BytesReader<'i, E>) -> Result<u8, E>
+    /// where
+    ///     E: Error<'i>
+    /// {
+    ///     r.context("version", |r| {
+    ///         r.consume(b"v")?;
+    ///         r.read()
+    ///     })
+    /// }
+    ///
+    /// let input = dangerous::input(b"x1");
+    /// let error = input.read_all::<_, _, Expected<'_>>(|r| {
+    ///     let checkpoint = r.checkpoint();
+    ///     match r.error(|r: &mut BytesReader<'_, Fatal>| parse(r)) {
+    ///         Ok(version) => Ok(version),
+    ///         Err(Fatal) => {
+    ///             r.restore(checkpoint);
+    ///             parse(r)
+    ///         }
+    ///     }
+    /// }).unwrap_err();
+    ///
+    /// assert!(error.to_string().starts_with("failed to consume input"));
+    /// ```
     #[inline]
     pub fn error<F, T, S>(&mut self, f: F) -> T
     where
         F: FnOnce(&mut Reader<'i, I, S>) -> T,
     {
+        let origin = self.origin;
         self.advance(|input| {
-            let mut sub = Reader::new(input);
+            let mut sub = Reader::with_origin(input, origin);
             let ok = f(&mut sub);
             (ok, sub.input)
         })
@@ -394,6 +918,86 @@ where
         self.advance_opt(|input| input.split_at_opt(len))
     }
 
+    /// Read a value from a scoped length of input with a new `Reader`.
+    ///
+    /// `f` is given a `Reader` over exactly `len` tokens of input. As the
+    /// scoped input is bound, `f` can't read past it and any length error
+    /// within it is fatal. On success the `Reader` is advanced past the scoped
+    /// input, regardless of how much of it `f` consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"hello world").read_all(|r| {
+    ///     let first = r.scoped(6, |r| r.read())?;
+    ///     let rest = r.take(5)?;
+    ///     Ok((first, rest))
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), (b'h', dangerous::input(b"world")));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is less than `len` tokens of input
+    /// and any error `f` returns.
+    pub fn scoped<F, T>(&mut self, len: usize, f: F) -> Result<T, E>
+    where
+        E: WithContext<'i>,
+        E: From<ExpectedLength<'i>>,
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        let (scope, tail) = self
+            .input
+            .clone()
+            .split_at_for(len, CoreOperation::Scoped)?;
+        let (value, _) = scope.into_bound().read_partial(f)?;
+        self.advance(|_| ((), tail));
+        Ok(value)
+    }
+
+    /// Read the remaining input as chunks of `chunk_len` tokens.
+    ///
+    /// The reader is advanced past all of the chunks yielded by the returned
+    /// iterator. If `chunk_len` doesn't divide the remaining input, the final
+    /// shorter chunk is only yielded if the input is bound, otherwise it is
+    /// left within the reader for a future pass. If `chunk_len` is `0`,
+    /// nothing is read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"aabbc").read_partial(|r| {
+    ///     Ok(r.take_chunks(2).count())
+    /// });
+    ///
+    /// assert_eq!(result, Ok((2, dangerous::input(b"c"))));
+    /// ```
+    pub fn take_chunks(&mut self, chunk_len: usize) -> ChunkIter<'i, I> {
+        self.advance(|input| {
+            let mut end = 0;
+            if chunk_len > 0 {
+                if input.bound_end() {
+                    end = input.byte_len();
+                } else {
+                    let mut remaining = input.clone();
+                    while let Some((chunk, tail)) = remaining.split_at_opt(chunk_len) {
+                        end += chunk.byte_len();
+                        remaining = tail;
+                    }
+                }
+            }
+            // SAFETY: `end` is either the end of the last whole chunk or the
+            // end of the input.
+            let (taken, remaining) = unsafe { input.split_at_byte_unchecked(end) };
+            (ChunkIter::new(taken, chunk_len), remaining)
+        })
+    }
+
     /// Read a length of input while a pattern matches.
     ///
     /// Returns the input leading up to when the predicate returns `false`.
@@ -420,6 +1024,39 @@ where
         })
     }
 
+    /// Read a length of input while a pattern matches, requiring at least one
+    /// token to match.
+    ///
+    /// Otherwise behaves like [`Reader::take_while()`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"hello!").read_all(|r| {
+    ///     let ident = r.take_while1("identifier", |b: u8| b.is_ascii_alphabetic())?;
+    ///     r.consume(b'!')?;
+    ///     Ok(ident)
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), b"hello"[..]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] pointing at the current position if no tokens
+    /// matched.
+    pub fn take_while1<P>(&mut self, expected: &'static str, pattern: P) -> Result<I, E>
+    where
+        E: From<ExpectedValid<'i>>,
+        P: Pattern<I>,
+    {
+        self.try_advance(|input| {
+            input.split_while1_for(pattern, expected, CoreOperation::TakeWhile1)
+        })
+    }
+
     /// Try read a length of input while a predicate check remains successful
     /// and true.
     ///
@@ -434,6 +1071,39 @@ where
         self.try_advance(|input| input.try_split_while_for(pred, CoreOperation::TakeWhile))
     }
 
+    /// Try read a length of input while a predicate check remains successful
+    /// and true, passing the index of each token to the predicate.
+    ///
+    /// The index counts tokens, not bytes, so for [`String`] input it is the
+    /// index of the char.
+    ///
+    /// [`String`]: crate::input::String
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("a-b-c!").read_all(|r| {
+    ///     let ident = r.try_take_while_indexed(|i, c| Ok(c.is_ascii_alphabetic() || (i > 0 && c == '-')))?;
+    ///     r.consume('!')?;
+    ///     Ok(ident)
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), "a-b-c");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns any error the provided function does.
+    pub fn try_take_while_indexed<F>(&mut self, pred: F) -> Result<I, E>
+    where
+        E: WithContext<'i>,
+        F: FnMut(usize, I::Token) -> Result<bool, E>,
+    {
+        self.try_advance(|input| input.try_split_while_indexed_for(pred, CoreOperation::TakeWhile))
+    }
+
     /// Read a length of input until a expected pattern matches.
     ///
     /// Returns the input leading up to the pattern match.
@@ -465,6 +1135,38 @@ where
         self.try_advance(|input| input.split_until_for(pattern, CoreOperation::TakeUntil))
     }
 
+    /// Read a length of input until the last match of an expected pattern.
+    ///
+    /// Returns the input leading up to the last pattern match, leaving the
+    /// match and everything after it to be read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"a.b.c").read_all(|r| {
+    ///     let name = r.take_until_last(b'.')?;
+    ///     Ok((name, r.take_remaining()))
+    /// });
+    ///
+    /// let (name, ext) = result.unwrap();
+    ///
+    /// assert_eq!(name, b"a.b"[..]);
+    /// assert_eq!(ext, b".c"[..]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValue`] if the pattern could not be found.
+    pub fn take_until_last<P>(&mut self, pattern: P) -> Result<I, E>
+    where
+        E: From<ExpectedValue<'i>>,
+        P: Pattern<I> + Into<Value<'i>> + Copy,
+    {
+        self.try_advance(|input| input.split_until_last_for(pattern, CoreOperation::TakeUntilLast))
+    }
+
     /// Read a length of input until a pattern matches and consumes the matched
     /// input if found.
     ///
@@ -499,6 +1201,40 @@ where
         })
     }
 
+    /// Read a length of input until a pattern matches, including the matched
+    /// input.
+    ///
+    /// Returns the input leading up to and including the pattern match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"line1\nline2").read_all(|r| {
+    ///     let line = r.take_until_inclusive(b'\n')?;
+    ///     Ok((line, r.take_remaining()))
+    /// });
+    ///
+    /// let (line, remaining) = result.unwrap();
+    ///
+    /// assert_eq!(line, b"line1\n"[..]);
+    /// assert_eq!(remaining, b"line2"[..]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValue`] if the pattern could not be found.
+    pub fn take_until_inclusive<P>(&mut self, pattern: P) -> Result<I, E>
+    where
+        E: From<ExpectedValue<'i>>,
+        P: Pattern<I> + Into<Value<'i>> + Copy,
+    {
+        self.try_advance(|input| {
+            input.split_until_inclusive_for(pattern, CoreOperation::TakeUntilInclusive)
+        })
+    }
+
     /// Read a length of input until a pattern optionally matches.
     ///
     /// If you want to know whether the pattern was consumed or not, check
@@ -663,6 +1399,126 @@ where
         })
     }
 
+    /// Consume the first of several prefixes that matches.
+    ///
+    /// Returns the index of the matched prefix, or `None` if none matched.
+    ///
+    /// Doesn't effect the internal state of the `Reader` if the input couldn't
+    /// be consumed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"false").read_all(|r| {
+    ///     Ok(r.consume_any(&[&b"true"[..], &b"false"[..]]))
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), Some(1));
+    /// ```
+    pub fn consume_any<P>(&mut self, prefixes: &[P]) -> Option<usize>
+    where
+        P: Prefix<I> + Copy,
+    {
+        self.advance_opt(|input| {
+            prefixes.iter().enumerate().find_map(|(index, &prefix)| {
+                match input.clone().split_prefix_opt(prefix) {
+                    (Some(_), next) => Some((index, next)),
+                    (None, _) => None,
+                }
+            })
+        })
+    }
+
+    /// Consume the first of several prefixes that matches.
+    ///
+    /// Returns the index of the matched prefix.
+    ///
+    /// Doesn't effect the internal state of the `Reader` if the input couldn't
+    /// be consumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if no prefixes were provided, otherwise
+    /// [`ExpectedValue`] if none matched. As only one value can be expected,
+    /// the error is against the first prefix the remaining input could still
+    /// complete, so more input may be retried for, else the first prefix.
+    pub fn try_consume_any<P>(&mut self, prefixes: &[P]) -> Result<usize, E>
+    where
+        E: From<ExpectedValue<'i>>,
+        E: From<ExpectedValid<'i>>,
+        P: Prefix<I> + Into<Value<'i>> + Copy,
+    {
+        if let Some(index) = self.consume_any(prefixes) {
+            return Ok(index);
+        }
+        let remaining = self.input.as_dangerous_bytes();
+        let closest = prefixes
+            .iter()
+            .copied()
+            .enumerate()
+            .find(|&(_, prefix)| prefix.into().as_bytes().starts_with(remaining))
+            .or_else(|| prefixes.first().map(|&prefix| (0, prefix)));
+        match closest {
+            Some((index, prefix)) => self
+                .try_advance(|input| input.split_prefix_for(prefix, CoreOperation::ConsumeAny))
+                .map(|_| index),
+            None => Err(E::from(ExpectedValid {
+                reason: InvalidReason::Malformed,
+                retry_requirement: None,
+                context: CoreContext {
+                    span: self.input.span().start(),
+                    operation: CoreOperation::ConsumeAny,
+                    expected: CoreExpected::Valid("at least one prefix"),
+                },
+                input: self.input.clone().into_maybe_string(),
+            })),
+        }
+    }
+
+    /// Require at least `len` bytes of input to remain without consuming any.
+    ///
+    /// Useful for streaming parsers that know up front how much input they
+    /// need, as the error can be retried with a precise [`RetryRequirement`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::error::{RetryRequirement, ToRetryRequirement};
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"abc").read_partial(|r| {
+    ///     r.demand(5)
+    /// });
+    ///
+    /// assert_eq!(result.unwrap_err().to_retry_requirement(), RetryRequirement::new(2));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if fewer than `len` bytes remain.
+    ///
+    /// [`RetryRequirement`]: crate::error::RetryRequirement
+    pub fn demand(&self, len: usize) -> Result<(), E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        if self.input.byte_len() < len {
+            Err(E::from(ExpectedLength {
+                len: Length::AtLeast(len),
+                context: CoreContext {
+                    span: self.input.span(),
+                    operation: CoreOperation::Demand,
+                    expected: CoreExpected::EnoughInputFor("demand"),
+                },
+                input: self.input.clone().into_maybe_string(),
+            }))
+        } else {
+            Ok(())
+        }
+    }
+
     /// Peek a length of input.
     ///
     /// The function lifetime `'p` helps prevent the peeked [`Input`] being used
@@ -695,6 +1551,63 @@ where
             .map(|(head, _)| Peek::new(head))
     }
 
+    /// Peek all of the remaining input.
+    ///
+    /// This is equivalent to `peek` with the remaining length, without the
+    /// length needing to be computed first. The `Reader` is not advanced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"hello;").read_all(|r| {
+    ///     let terminated = r.peek_remaining().as_dangerous().ends_with(b";");
+    ///     let value = r.take_until(b';')?;
+    ///     r.skip(1)?;
+    ///     Ok((terminated, value))
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), (true, dangerous::input(b"hello")));
+    /// ```
+    #[must_use = "peek result must be used"]
+    pub fn peek_remaining(&self) -> Peek<'_, I> {
+        Peek::new(self.input.clone())
+    }
+
+    /// Returns the number of tokens at the front of the `Reader` that match
+    /// `pattern` without consuming them.
+    ///
+    /// Tokens are counted, so a [`StringReader`] counts chars rather than
+    /// bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("  ☃x").read_all(|r| {
+    ///     let indent = r.count_while(' ');
+    ///     let before_x = r.count_while(|c: char| c != 'x');
+    ///     r.skip(before_x + 1)?;
+    ///     Ok((indent, before_x))
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), (2, 3));
+    /// ```
+    ///
+    /// [`StringReader`]: crate::StringReader
+    #[must_use = "count result must be used"]
+    pub fn count_while<P>(&self, pattern: P) -> usize
+    where
+        P: Pattern<I>,
+    {
+        match self.input.clone().split_while_opt(pattern) {
+            Some((head, _)) => head.tokens().count(),
+            None => self.input.clone().tokens().count(),
+        }
+    }
+
     /// Returns `true` if `prefix` is next in the `Reader`.
     #[inline]
     #[must_use = "peek result must be used"]
@@ -705,11 +1618,93 @@ where
         prefix.is_prefix_of(&self.input)
     }
 
+    /// Returns the byte length of the longest common prefix of the remaining
+    /// input and `prefix`, without consuming it.
+    ///
+    /// Unlike [`Reader::peek_eq()`], this reports how far a partial match
+    /// got, which is useful for longest match dispatch and diagnostics. The
+    /// length always ends on a token boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"helicopter").read_all(|r| {
+    ///     let len = r.peek_common_prefix(b"hello");
+    ///     r.take_remaining();
+    ///     Ok(len)
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), 3);
+    /// ```
+    #[inline]
+    #[must_use = "peek result must be used"]
+    pub fn peek_common_prefix<P>(&self, prefix: P) -> usize
+    where
+        P: AsRef<[u8]>,
+    {
+        self.input.common_prefix_byte_len(prefix.as_ref())
+    }
+
+    /// Returns the byte length of a pattern match if it is next in the
+    /// `Reader`, without consuming it.
+    ///
+    /// Unlike [`Reader::peek_eq()`], this supports any [`Pattern`] and returns
+    /// how much input the match covers. Note the pattern is searched for in
+    /// all of the remaining input, but only a match starting at the current
+    /// position is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"abcdef").read_all(|r| {
+    ///     let len = r.peek_match_len(b"abc".as_ref());
+    ///     r.skip(6)?;
+    ///     Ok(len)
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), Some(3));
+    /// ```
+    #[inline]
+    #[must_use = "peek result must be used"]
+    pub fn peek_match_len<P>(&self, pattern: P) -> Option<usize>
+    where
+        P: Pattern<I>,
+    {
+        match pattern.find_match(&self.input) {
+            Some((0, len)) => Some(len),
+            _ => None,
+        }
+    }
+
     /// Peek the next token in the input without mutating the `Reader`.
     ///
+    /// The token is a `u8` for a [`BytesReader`] and a `char` for a
+    /// [`StringReader`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("♥ love").read_all(|r| {
+    ///     let love = r.peek_read()? == '♥';
+    ///     r.take_remaining();
+    ///     Ok(love)
+    /// });
+    ///
+    /// assert!(result.unwrap());
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns an error if the `Reader` has no more input.
+    ///
+    /// [`BytesReader`]: crate::BytesReader
+    /// [`StringReader`]: crate::StringReader
     #[inline]
     pub fn peek_read(&self) -> Result<I::Token, E>
     where
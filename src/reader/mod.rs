@@ -1,13 +1,24 @@
+mod bits;
 mod bytes;
+mod checkpoint;
+mod chunks;
+mod eol;
 mod input;
 mod peek;
+mod progress;
+mod string;
 
 use core::marker::PhantomData;
 
 use crate::fmt;
-use crate::input::{Bytes, Input, String};
+use crate::input::{Bytes, Input, PrivateExt, String, Utf16};
 
+pub use self::bits::BitReader;
+pub use self::checkpoint::Checkpoint;
+pub use self::chunks::ChunkIter;
+pub use self::eol::Eol;
 pub use self::peek::Peek;
+pub use self::progress::ProgressReader;
 
 /// [`Bytes`] specific [`Reader`].
 pub type BytesReader<'i, E> = Reader<'i, Bytes<'i>, E>;
@@ -15,6 +26,9 @@ pub type BytesReader<'i, E> = Reader<'i, Bytes<'i>, E>;
 /// [`String`] specific [`Reader`].
 pub type StringReader<'i, E> = Reader<'i, String<'i>, E>;
 
+/// [`Utf16`] specific [`Reader`].
+pub type Utf16Reader<'i, E> = Reader<'i, Utf16<'i>, E>;
+
 /// Created from and consumes an [`Input`].
 ///
 /// You can only create a [`Reader`] from [`Input`] via [`Input::read_all()`],
@@ -94,6 +108,7 @@ pub type StringReader<'i, E> = Reader<'i, String<'i>, E>;
 /// [`RetryRequirement`]: crate::error::RetryRequirement  
 pub struct Reader<'i, I, E> {
     input: I,
+    origin: &'i [u8],
     types: PhantomData<(&'i (), E)>,
 }
 
@@ -103,8 +118,19 @@ where
 {
     /// Create a `Reader` given `Input`.
     pub(crate) fn new(input: I) -> Self {
+        Self {
+            origin: input.as_dangerous_bytes(),
+            input,
+            types: PhantomData,
+        }
+    }
+
+    /// Create a `Reader` given `Input` within the bytes of another reader's
+    /// origin, so offsets stay relative to where that reader started.
+    fn with_origin(input: I, origin: &'i [u8]) -> Self {
         Self {
             input,
+            origin,
             types: PhantomData,
         }
     }
@@ -1,7 +1,7 @@
 use core::marker::PhantomData;
 use core::ops::Deref;
 
-use crate::input::Input;
+use crate::input::{Input, PrivateExt};
 
 /// Peek of [`Input`].
 ///
@@ -40,6 +40,38 @@ impl<'p, I> Peek<'p, I> {
     }
 }
 
+impl<'p, I> Peek<'p, I>
+where
+    I: Input<'p>,
+{
+    /// Returns the byte length of the longest common prefix of the peeked
+    /// input and `other`.
+    ///
+    /// The length always ends on a token boundary, so for a [`String`] a
+    /// partially matching `char` is not counted.
+    ///
+    /// [`String`]: crate::String
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"hello").read_all(|r| {
+    ///     let len = r.peek(5)?.common_prefix_len(b"help");
+    ///     r.skip(5)?;
+    ///     Ok(len)
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn common_prefix_len(&self, other: &[u8]) -> usize {
+        self.input.common_prefix_byte_len(other)
+    }
+}
+
 impl<'p, I> AsRef<I> for Peek<'p, I>
 where
     I: Input<'p>,
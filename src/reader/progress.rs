@@ -0,0 +1,110 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::fmt;
+use crate::input::Input;
+
+use super::Reader;
+
+/// Number of times progress is reported over the total input length.
+const STEPS: usize = 100;
+
+/// A [`Reader`] that reports how much of its input was consumed.
+///
+/// Created with [`Input::read_all_with_progress()`]. A `ProgressReader`
+/// dereferences to the [`Reader`] it wraps and reports its progress each time
+/// it is mutably borrowed, so a report reflects the reads made before it.
+/// Reports are throttled to roughly every percent of the input consumed.
+///
+/// As the progress is kept by the wrapper, a [`Reader`] read from directly
+/// has no progress reporting overhead.
+///
+/// [`Input::read_all_with_progress()`]: crate::Input::read_all_with_progress()
+pub struct ProgressReader<'i, I, E, P> {
+    reader: Reader<'i, I, E>,
+    total: usize,
+    step: usize,
+    next: usize,
+    reported: usize,
+    on_progress: P,
+}
+
+impl<'i, I, E, P> ProgressReader<'i, I, E, P>
+where
+    I: Input<'i>,
+    P: FnMut(usize, usize),
+{
+    pub(crate) fn new(input: I, on_progress: P) -> Self {
+        let total = input.byte_len();
+        let step = (total / STEPS).max(1);
+        Self {
+            reader: Reader::new(input),
+            total,
+            step,
+            next: step,
+            reported: 0,
+            on_progress,
+        }
+    }
+
+    /// Stops reporting progress, returning the wrapped reader.
+    ///
+    /// The current progress is reported if it crossed the next threshold, or
+    /// if all of the input was consumed and not yet reported.
+    pub(crate) fn finish(mut self) -> Reader<'i, I, E> {
+        self.update();
+        if self.reader.at_end() && self.reported != self.total {
+            self.report(self.total);
+        }
+        self.reader
+    }
+
+    /// Reports progress if the consumed length crossed the next threshold.
+    #[inline(always)]
+    fn update(&mut self) {
+        let consumed = self.total - self.reader.remaining_bytes();
+        if consumed >= self.next {
+            self.report(consumed);
+        }
+    }
+
+    #[cold]
+    fn report(&mut self, consumed: usize) {
+        self.next = consumed.saturating_add(self.step);
+        self.reported = consumed;
+        (self.on_progress)(consumed, self.total);
+    }
+}
+
+impl<'i, I, E, P> Deref for ProgressReader<'i, I, E, P> {
+    type Target = Reader<'i, I, E>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.reader
+    }
+}
+
+impl<'i, I, E, P> DerefMut for ProgressReader<'i, I, E, P>
+where
+    I: Input<'i>,
+    P: FnMut(usize, usize),
+{
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.update();
+        &mut self.reader
+    }
+}
+
+impl<'i, I, E, P> fmt::Debug for ProgressReader<'i, I, E, P>
+where
+    I: Input<'i>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressReader")
+            .field("reader", &self.reader)
+            .field("reported", &self.reported)
+            .field("total", &self.total)
+            .finish()
+    }
+}
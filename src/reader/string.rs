@@ -0,0 +1,463 @@
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+use core::str::FromStr;
+
+use crate::error::{
+    CoreContext, CoreExpected, CoreOperation, ExpectedLength, ExpectedValid, InvalidReason,
+};
+use crate::input::{Input, Pattern, PrivateExt, String};
+use crate::util::utf8;
+
+use super::StringReader;
+
+impl<E> StringReader<'_, E> {
+    /// Read all of the remaining input into an owned `String`.
+    ///
+    /// The `Reader` will be at the end of its input afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("hello world").read_all(|r| {
+    ///     r.consume("hello ")?;
+    ///     Ok(r.drain_to_string())
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), "world");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn drain_to_string(&mut self) -> alloc::string::String {
+        self.take_remaining().as_dangerous().to_owned()
+    }
+
+    /// Returns the number of chars consumed from the input the reader was
+    /// created with.
+    ///
+    /// See [`Reader::offset()`] for the number of bytes.
+    ///
+    /// [`Reader::offset()`]: crate::Reader::offset()
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("héllo").read_partial(|r| {
+    ///     r.take(2)?;
+    ///     Ok((r.offset(), r.char_offset()))
+    /// });
+    ///
+    /// assert_eq!(result.unwrap().0, (3, 2));
+    /// ```
+    #[must_use]
+    pub fn char_offset(&self) -> usize {
+        utf8::char_count(&self.origin[..self.offset()])
+    }
+
+    /// Skip the rest of the current line, including the `\n` that ends it.
+    ///
+    /// Returns `true` if a newline was found and consumed, `false` if the
+    /// reader skipped to the end of input instead. This is useful for
+    /// recovering after an error in line-based formats.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("# comment\nvalue").read_all(|r| {
+    ///     assert!(r.skip_line());
+    ///     r.consume("value")
+    /// });
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn skip_line(&mut self) -> bool {
+        self.skip_until_consume_opt('\n')
+    }
+
+    /// Skip any whitespace as defined by [`char::is_whitespace()`].
+    ///
+    /// This includes ASCII whitespace and newlines as well as Unicode
+    /// whitespace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(" \t\u{3000}hello").read_all(|r| {
+    ///     r.skip_whitespace();
+    ///     r.consume("hello")
+    /// });
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn skip_whitespace(&mut self) {
+        self.skip_while(char::is_whitespace);
+    }
+}
+
+impl<'i, E> StringReader<'i, E>
+where
+    E: From<ExpectedValid<'i>>,
+{
+    /// Read a char within an inclusive range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("é").read_all(|r| {
+    ///     r.read_char_in("latin-1 letter", 'À'..='ÿ')
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), 'é');
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is no more input and
+    /// [`ExpectedValid`] with a span over the char if it is not within the
+    /// range.
+    #[inline]
+    pub fn read_char_in(
+        &mut self,
+        expected: &'static str,
+        range: RangeInclusive<char>,
+    ) -> Result<char, E>
+    where
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(|input| input.split_token_in_for(range, expected, CoreOperation::ReadChar))
+    }
+
+    /// Read a char and map it to a value, such as an enum variant from a tag.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Sign {
+    ///     Plus,
+    ///     Minus,
+    /// }
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("-").read_all(|r| {
+    ///     r.read_char_map("sign", |c| match c {
+    ///         '+' => Some(Sign::Plus),
+    ///         '-' => Some(Sign::Minus),
+    ///         _ => None,
+    ///     })
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), Sign::Minus);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedLength`] if there is no more input and
+    /// [`ExpectedValid`] with a span over the char if `f` returns `None`.
+    #[inline]
+    pub fn read_char_map<F, T>(&mut self, expected: &'static str, f: F) -> Result<T, E>
+    where
+        F: FnOnce(char) -> Option<T>,
+        E: From<ExpectedLength<'i>>,
+    {
+        self.try_advance(|input| input.split_token_map_for(f, expected, CoreOperation::ReadChar))
+    }
+
+    /// Read the longest ASCII float at the start of the input.
+    ///
+    /// A float is an optional sign (`+` or `-`), digits with an optional
+    /// fractional part (`.`) and an optional exponent (`e` or `E` followed by
+    /// an optional sign and digits). At least one digit is required before or
+    /// after the `.`. An exponent without digits is left unread.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no valid float at the start of the input,
+    /// pointing at the first invalid char.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("-1.5e3,x").read_partial(|r| {
+    ///     r.read_ascii_f64("float")
+    /// });
+    ///
+    /// let (value, remaining) = result.unwrap();
+    /// assert_eq!(value, -1500.0);
+    /// assert_eq!(remaining, ",x");
+    /// ```
+    pub fn read_ascii_f64(&mut self, expected: &'static str) -> Result<f64, E> {
+        self.try_advance(|input| input.split_ascii_f64_for(expected, CoreOperation::ReadAsciiFloat))
+    }
+
+    /// Parse all of the remaining input with [`FromStr`].
+    ///
+    /// The `Reader` is only advanced to the end of its input if the parse
+    /// succeeds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<Ipv4Addr, Invalid> = dangerous::input("192.168.1.1").read_all(|r| {
+    ///     r.parse_remaining("ipv4 addr")
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), Ipv4Addr::new(192, 168, 1, 1));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] with a span over the remaining input if the
+    /// parse fails. As a parse error has no retry information, the error is
+    /// fatal.
+    pub fn parse_remaining<T>(&mut self, expected: &'static str) -> Result<T, E>
+    where
+        T: FromStr,
+    {
+        let remaining = self.input.clone();
+        match remaining.as_dangerous().parse() {
+            Ok(value) => {
+                let _ = self.take_remaining();
+                Ok(value)
+            }
+            Err(_) => Err(E::from(ExpectedValid {
+                reason: InvalidReason::Malformed,
+                retry_requirement: None,
+                context: CoreContext {
+                    span: remaining.span(),
+                    operation: CoreOperation::ParseRemaining,
+                    expected: CoreExpected::Valid(expected),
+                },
+                input: remaining.into_maybe_string(),
+            })),
+        }
+    }
+
+    /// Read an unsigned ASCII integer in the given radix.
+    ///
+    /// Reads digits until the first char that is not a digit in the radix,
+    /// which is left unread. Letters are accepted as digits for radixes above
+    /// `10`, in either case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("255x").read_partial(|r| {
+    ///     r.read_u64_radix(10)
+    /// });
+    ///
+    /// let (value, remaining) = result.unwrap();
+    /// assert_eq!(value, 255);
+    /// assert_eq!(remaining, "x");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if there is no digit at the start of the
+    /// input, or if the value does not fit within a `u64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between `2` and `36`.
+    pub fn read_u64_radix(&mut self, radix: u32) -> Result<u64, E> {
+        self.try_advance(|input| {
+            input.split_ascii_int_for(
+                radix,
+                false,
+                u64::MAX,
+                "integer within u64",
+                CoreOperation::ReadAsciiInt,
+            )
+        })
+        .map(|(_, value)| value)
+    }
+
+    /// Read a signed ASCII integer in the given radix.
+    ///
+    /// An optional sign (`+` or `-`) may come before the digits. See
+    /// [`StringReader::read_u64_radix()`] for how digits are read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("-ff").read_all(|r| {
+    ///     r.read_i64_radix(16)
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), -255);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if there is no digit after the optional sign,
+    /// or if the value does not fit within an `i64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between `2` and `36`.
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn read_i64_radix(&mut self, radix: u32) -> Result<i64, E> {
+        self.try_advance(|input| {
+            input.split_ascii_int_for(
+                radix,
+                true,
+                i64::MAX as u64,
+                "integer within i64",
+                CoreOperation::ReadAsciiInt,
+            )
+        })
+        // The magnitude is at most `i64::MAX + 1` when negative, which wraps
+        // to `i64::MIN` before being negated back to itself.
+        .map(|(negative, value)| {
+            if negative {
+                (value as i64).wrapping_neg()
+            } else {
+                value as i64
+            }
+        })
+    }
+
+    /// Take ASCII digits grouped by a separator, such as `1_000_000` or
+    /// `1,234`.
+    ///
+    /// The digits and separators are returned as they are in the input. Each
+    /// separator must be between two digits, so a leading, trailing or doubled
+    /// separator is an error. Reading stops at the first char that is neither
+    /// a digit nor a separator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("1,234 apples").read_partial(|r| {
+    ///     r.take_digits_grouped(',')
+    /// });
+    ///
+    /// let (digits, remaining) = result.unwrap();
+    /// assert_eq!(digits, "1,234");
+    /// assert_eq!(remaining, " apples");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] pointing at where a digit was expected if
+    /// there are no digits or a separator is misplaced.
+    pub fn take_digits_grouped(&mut self, separator: char) -> Result<String<'i>, E> {
+        self.try_advance(|input| {
+            input.split_digits_grouped_for(separator, CoreOperation::TakeDigitsGrouped)
+        })
+    }
+}
+
+impl<'i, E> StringReader<'i, E>
+where
+    E: From<ExpectedValid<'i>>,
+    E: From<ExpectedLength<'i>>,
+{
+    /// Read and decode percent encoded input up to a pattern match.
+    ///
+    /// Reads up to the first match of `until`, or to the end of the input if
+    /// there is none, leaving the match to be read. `%XX` escapes are decoded
+    /// into the byte they represent and, if `plus_as_space` is `true`, `+` is
+    /// decoded as a space as in `application/x-www-form-urlencoded` data.
+    ///
+    /// The decoded bytes are not required to be valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input("a%20b+c&d").read_partial(|r| {
+    ///     r.read_percent_decoded('&', true)
+    /// });
+    ///
+    /// let (decoded, remaining) = result.unwrap();
+    /// assert_eq!(decoded, b"a b c");
+    /// assert_eq!(remaining, "&d");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] over a malformed escape. The error is
+    /// retryable if the escape was cut short by the end of the input.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn read_percent_decoded<P>(&mut self, until: P, plus_as_space: bool) -> Result<Vec<u8>, E>
+    where
+        P: Pattern<String<'i>>,
+    {
+        let mut out = Vec::new();
+        self.try_advance(|input| {
+            input.split_percent_decoded_for::<_, _, E>(
+                until,
+                plus_as_space,
+                |byte| {
+                    out.push(byte);
+                    true
+                },
+                CoreOperation::ReadPercentDecoded,
+            )
+        })?;
+        Ok(out)
+    }
+
+    /// Read and decode percent encoded input up to a pattern match into a
+    /// provided buffer.
+    ///
+    /// Returns the number of bytes written to `out`. See
+    /// [`StringReader::read_percent_decoded()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] over a malformed escape or if the decoded
+    /// input is longer than `out`. The reader is not advanced on error.
+    pub fn read_percent_decoded_into<P>(
+        &mut self,
+        out: &mut [u8],
+        until: P,
+        plus_as_space: bool,
+    ) -> Result<usize, E>
+    where
+        P: Pattern<String<'i>>,
+    {
+        let mut slots = out.iter_mut();
+        self.try_advance(|input| {
+            input.split_percent_decoded_for::<_, _, E>(
+                until,
+                plus_as_space,
+                |byte| match slots.next() {
+                    Some(slot) => {
+                        *slot = byte;
+                        true
+                    }
+                    None => false,
+                },
+                CoreOperation::ReadPercentDecoded,
+            )
+        })
+    }
+}
@@ -0,0 +1,11 @@
+use crate::input::{Bound, Bytes, IntoInput};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+impl<'i> IntoInput<'i> for &'i ::bytes::Bytes {
+    type Input = Bytes<'i>;
+
+    #[inline(always)]
+    fn into_input(self) -> Self::Input {
+        Bytes::new(self, Bound::Start)
+    }
+}
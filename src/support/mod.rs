@@ -1,7 +1,13 @@
+#[cfg(feature = "bytes")]
+mod bytes;
 mod core;
 #[cfg(feature = "nom")]
 mod nom;
+#[cfg(feature = "serde")]
+pub(crate) mod serde;
 #[cfg(feature = "std")]
 mod std;
+#[cfg(feature = "tracing")]
+pub(crate) mod tracing;
 #[cfg(feature = "zc")]
 mod zc;
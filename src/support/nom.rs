@@ -3,11 +3,15 @@ use core::any::Any;
 use nom::error::{Error, ErrorKind};
 #[cfg(feature = "alloc")]
 use nom::error::{VerboseError, VerboseErrorKind};
-use nom::{Err, Needed};
+use nom::{Err, Needed, Parser};
 
-use crate::error::{Context, External, Operation, RetryRequirement, WithContext};
+use crate::error::{
+    Backtrace, Context, CoreOperation, Details, Expected, ExpectedLength, ExpectedValid, External,
+    Operation, RetryRequirement, WithContext,
+};
 use crate::fmt;
 use crate::input::Span;
+use crate::reader::BytesReader;
 
 pub trait AsBytes<'i> {
     fn as_bytes(&self) -> &'i [u8];
@@ -56,6 +60,56 @@ where
     }
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader
+
+impl<'i, E> BytesReader<'i, E> {
+    /// Read a value with a [`nom::Parser`] over the remaining bytes.
+    ///
+    /// The reader is advanced by the number of bytes the parser consumed. Any
+    /// [`nom::Err`] returned is translated with its span and backtrace.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dangerous::{Input, Invalid};
+    /// use nom::bytes::complete::tag;
+    ///
+    /// let result: Result<_, Invalid> = dangerous::input(b"hello world").read_all(|r| {
+    ///     let hello = r.nom(tag::<_, _, nom::error::Error<_>>("hello"))?;
+    ///     r.consume(b" world")?;
+    ///     Ok(hello)
+    /// });
+    ///
+    /// assert_eq!(result.unwrap(), b"hello");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpectedValid`] if the parser returned an error and
+    /// [`ExpectedLength`] if the parser returned remaining input that was not
+    /// derived from the input provided.
+    #[cfg_attr(docsrs, doc(cfg(feature = "nom")))]
+    pub fn nom<O, F, Ex>(&mut self, mut parser: F) -> Result<O, E>
+    where
+        E: WithContext<'i>,
+        E: From<ExpectedValid<'i>>,
+        E: From<ExpectedLength<'i>>,
+        F: Parser<&'i [u8], O, Ex>,
+        Ex: External<'i>,
+    {
+        self.try_external("nom parser", |i| {
+            let bytes = i.as_dangerous();
+            parser.parse(bytes).map(|(remaining, output)| {
+                // A well behaved parser returns a suffix of the input. If not,
+                // the read length will be out of bounds and an error returned.
+                let read = bytes.len().checked_sub(remaining.len());
+                (read.unwrap_or(usize::MAX), output)
+            })
+        })
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Basic
 
@@ -105,6 +159,108 @@ where
     }
 }
 
+/// Converts an [`Expected`] error into a nom [`Error`].
+///
+/// The nom error input is the remaining input from the start of the root
+/// context's span, and the [`ErrorKind`] is picked from the root operation.
+/// This allows dangerous parsers to be called from nom parsers while
+/// migrating between the two.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Expected, Input};
+/// use nom::error::{Error, ErrorKind};
+///
+/// let error: Expected<'_> = dangerous::input(b"ab!")
+///     .read_all(|r| {
+///         r.consume(b"ab")?;
+///         r.consume(b'?')
+///     })
+///     .unwrap_err();
+/// let error = Error::from(error);
+///
+/// assert_eq!(error.input, b"!");
+/// assert_eq!(error.code, ErrorKind::Tag);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "nom")))]
+impl<'i, S> From<Expected<'i, S>> for Error<&'i [u8]>
+where
+    S: Backtrace,
+{
+    fn from(err: Expected<'i, S>) -> Self {
+        let root = err.backtrace().root();
+        let input = err.input().as_bytes();
+        let start = root
+            .span
+            .range_of(input.into())
+            .map_or(0, |range| range.start);
+        Error::new(&input[start..], error_kind(root.operation))
+    }
+}
+
+fn error_kind(operation: CoreOperation) -> ErrorKind {
+    match operation {
+        CoreOperation::Consume
+        | CoreOperation::ConsumeAny
+        | CoreOperation::ConsumeEol
+        | CoreOperation::ConsumeNewline => ErrorKind::Tag,
+        CoreOperation::TakeWhile1 => ErrorKind::TakeWhile1,
+        CoreOperation::TakeUntil
+        | CoreOperation::TakeUntilConsume
+        | CoreOperation::TakeUntilLast
+        | CoreOperation::TakeUntilInclusive
+        | CoreOperation::TakeUntilAny
+        | CoreOperation::SkipUntil
+        | CoreOperation::SkipUntilConsume
+        | CoreOperation::SkipUntilAny => ErrorKind::TakeUntil,
+        CoreOperation::ReadAsciiInt => ErrorKind::Digit,
+        CoreOperation::ReadAsciiFloat => ErrorKind::Float,
+        CoreOperation::ReadHex => ErrorKind::HexDigit,
+        CoreOperation::ReadLengthPrefixed => ErrorKind::LengthValue,
+        CoreOperation::Alt => ErrorKind::Alt,
+        CoreOperation::IntoNonEmpty => ErrorKind::NonEmpty,
+        CoreOperation::Verify | CoreOperation::Expect | CoreOperation::ExpectExternal => {
+            ErrorKind::Verify
+        }
+        CoreOperation::Take
+        | CoreOperation::TakeArray
+        | CoreOperation::Skip
+        | CoreOperation::SplitAt
+        | CoreOperation::SplitAtByte
+        | CoreOperation::Peek
+        | CoreOperation::PeekByte
+        | CoreOperation::PeekChar
+        | CoreOperation::ReadByte
+        | CoreOperation::ReadChar
+        | CoreOperation::ReadArray
+        | CoreOperation::ReadBits
+        | CoreOperation::ReadU8
+        | CoreOperation::ReadI8
+        | CoreOperation::ReadU16Le
+        | CoreOperation::ReadU16Be
+        | CoreOperation::ReadI16Le
+        | CoreOperation::ReadI16Be
+        | CoreOperation::ReadU32Le
+        | CoreOperation::ReadU32Be
+        | CoreOperation::ReadI32Le
+        | CoreOperation::ReadI32Be
+        | CoreOperation::ReadU64Le
+        | CoreOperation::ReadU64Be
+        | CoreOperation::ReadI64Le
+        | CoreOperation::ReadI64Be
+        | CoreOperation::ReadU128Le
+        | CoreOperation::ReadU128Be
+        | CoreOperation::ReadI128Le
+        | CoreOperation::ReadI128Be
+        | CoreOperation::ReadF32Le
+        | CoreOperation::ReadF32Be
+        | CoreOperation::ReadF64Le
+        | CoreOperation::ReadF64Be => ErrorKind::Eof,
+        _ => ErrorKind::Fail,
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Verbose
 
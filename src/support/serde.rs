@@ -0,0 +1,217 @@
+use serde::ser::{Error as _, SerializeSeq, SerializeStruct};
+use serde::{Serialize, Serializer};
+
+use crate::error::{Backtrace, Context, Details, RetryRequirement, ToRetryRequirement, Value};
+use crate::fmt;
+use crate::input::Span;
+
+/// A [`Span`] that serializes relative to a root span.
+///
+/// Created with [`Span::serialize_in()`]. Serializes as `{ "start": usize,
+/// "len": usize }` where `start` is the byte offset from the start of the
+/// root. Serialization fails if the span is not within the root.
+///
+/// [`Span::serialize_in()`]: crate::Span::serialize_in()
+#[derive(Copy, Clone)]
+#[must_use = "span must be serialized"]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub struct SerializeSpan {
+    span: Span,
+    root: Span,
+}
+
+impl SerializeSpan {
+    pub(crate) fn new(span: Span, root: Span) -> Self {
+        Self { span, root }
+    }
+}
+
+impl Serialize for SerializeSpan {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let range = self
+            .span
+            .range_of(self.root)
+            .ok_or_else(|| S::Error::custom("span not within root"))?;
+        let mut state = serializer.serialize_struct("Span", 2)?;
+        state.serialize_field("start", &range.start)?;
+        state.serialize_field("len", &range.len())?;
+        state.end()
+    }
+}
+
+/// A serializable view of error [`Details`].
+///
+/// Serializes as:
+///
+/// ```json
+/// {
+///   "description": "found a different value to the exact expected",
+///   "expected": "hello",
+///   "retry_requirement": null,
+///   "span": { "start": 0, "len": 5 },
+///   "backtrace": [{ "operation": "consume input", "expected": "exact value" }]
+/// }
+/// ```
+///
+/// - `expected` is a string for `char` and `&str` values, bytes otherwise and
+///   `null` if there is no expected value.
+/// - `retry_requirement` is the number of additional bytes required to
+///   continue processing, or `null` if processing can't be retried.
+/// - `span` is the root context span relative to the error input.
+/// - `backtrace` is walked from the highest context to the root, `expected`
+///   within each entry is `null` if the context has none.
+///
+/// # Example
+///
+/// ```
+/// use dangerous::{Input, Expected};
+/// use dangerous::error::SerializeDetails;
+///
+/// let error = dangerous::input(b"hello")
+///     .read_all::<_, _, Expected<'_>>(|r| r.consume(b"world"))
+///     .unwrap_err();
+///
+/// let details = SerializeDetails::new(&error);
+/// ```
+#[must_use = "details must be serialized"]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub struct SerializeDetails<'a, T> {
+    error: &'a T,
+}
+
+impl<'a, 'i, T> SerializeDetails<'a, T>
+where
+    T: Details<'i> + ToRetryRequirement,
+{
+    /// Create a new `SerializeDetails` given error [`Details`].
+    pub fn new(error: &'a T) -> Self {
+        Self { error }
+    }
+}
+
+impl<'i, T> Serialize for SerializeDetails<'_, T>
+where
+    T: Details<'i> + ToRetryRequirement,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let root = self.error.input().span();
+        let backtrace = self.error.backtrace();
+        let mut state = serializer.serialize_struct("Details", 5)?;
+        state.serialize_field(
+            "description",
+            &DisplayWith::new(|w| self.error.description(w)),
+        )?;
+        state.serialize_field("expected", &self.error.expected().map(SerializeValue))?;
+        state.serialize_field(
+            "retry_requirement",
+            &self
+                .error
+                .to_retry_requirement()
+                .map(RetryRequirement::continue_after),
+        )?;
+        state.serialize_field("span", &backtrace.root().span.serialize_in(root))?;
+        state.serialize_field("backtrace", &SerializeBacktrace(backtrace))?;
+        state.end()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+struct SerializeValue<'a>(Value<'a>);
+
+impl Serialize for SerializeValue<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0.as_str() {
+            Some(s) => serializer.serialize_str(s),
+            None => serializer.serialize_bytes(self.0.as_bytes()),
+        }
+    }
+}
+
+struct SerializeBacktrace<'a>(&'a dyn Backtrace);
+
+impl Serialize for SerializeBacktrace<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.count()))?;
+        let mut error = None;
+        self.0.walk(
+            &mut |_, context| match seq.serialize_element(&SerializeContext(context)) {
+                Ok(()) => true,
+                Err(err) => {
+                    error = Some(err);
+                    false
+                }
+            },
+        );
+        match error {
+            Some(err) => Err(err),
+            None => seq.end(),
+        }
+    }
+}
+
+struct SerializeContext<'a>(&'a dyn Context);
+
+impl Serialize for SerializeContext<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Context", 2)?;
+        state.serialize_field(
+            "operation",
+            &DisplayWith::new(|w| self.0.operation().description(w)),
+        )?;
+        if self.0.has_expected() {
+            state.serialize_field("expected", &Some(DisplayWith::new(|w| self.0.expected(w))))?;
+        } else {
+            state.serialize_field("expected", &None::<()>)?;
+        }
+        state.end()
+    }
+}
+
+/// Serializes the output of a write function as a string.
+struct DisplayWith<F>(F);
+
+impl<F> DisplayWith<F>
+where
+    F: Fn(&mut dyn fmt::Write) -> fmt::Result,
+{
+    fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<F> Serialize for DisplayWith<F>
+where
+    F: Fn(&mut dyn fmt::Write) -> fmt::Result,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<F> fmt::Display for DisplayWith<F>
+where
+    F: Fn(&mut dyn fmt::Write) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
@@ -6,7 +6,9 @@ impl<'i> crate::error::External<'i> for std::net::AddrParseError {
     where
         E: WithContext<'i>,
     {
-        error.with_context("IP address")
+        error
+            .with_context("IP address")
+            .with_source(alloc::boxed::Box::new(self))
     }
 }
 
@@ -17,4 +19,11 @@ impl std::error::Error for crate::error::Invalid {}
 impl std::error::Error for crate::error::Fatal {}
 
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-impl<'i, S> std::error::Error for crate::error::Expected<'i, S> where S: crate::error::Backtrace {}
+impl<'i, S> std::error::Error for crate::error::Expected<'i, S>
+where
+    S: crate::error::Backtrace,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        crate::error::Details::backtrace(self).source()
+    }
+}
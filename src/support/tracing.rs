@@ -0,0 +1,44 @@
+use tracing::span::EnteredSpan;
+
+use crate::error::{Context, Operation};
+use crate::fmt;
+
+/// Enters a `tracing` span for a [`Context`] scope at `offset` bytes into the
+/// reader's input.
+///
+/// `tracing` span names must be static, so each span is named `context` with
+/// the operation description and expected value (if any) recorded as fields.
+pub(crate) fn enter_context(context: &impl Context, offset: usize) -> EnteredSpan {
+    let span = tracing::trace_span!(
+        "context",
+        operation = %OperationDisplay(context.operation()),
+        expected = tracing::field::Empty,
+        offset,
+    );
+    if context.has_expected() {
+        span.record(
+            "expected",
+            tracing::field::display(ExpectedDisplay(context)),
+        );
+    }
+    span.entered()
+}
+
+struct OperationDisplay<'a>(&'a dyn Operation);
+
+impl fmt::Display for OperationDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.description(f)
+    }
+}
+
+struct ExpectedDisplay<'a, C>(&'a C);
+
+impl<C> fmt::Display for ExpectedDisplay<'_, C>
+where
+    C: Context,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.expected(f)
+    }
+}
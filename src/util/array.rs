@@ -0,0 +1,47 @@
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+
+/// Creates an array by calling `f` for each element in order.
+///
+/// Stops at and returns the first error, dropping the elements created so
+/// far.
+pub(crate) fn try_from_fn<T, E, F, const N: usize>(mut f: F) -> Result<[T; N], E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    let mut guard = Guard::<T, N> {
+        // SAFETY: an array of `MaybeUninit` does not require initialisation.
+        array: unsafe { MaybeUninit::uninit().assume_init() },
+        len: 0,
+    };
+    while guard.len < N {
+        let value = f()?;
+        // SAFETY: `len < N` so the index is within the array.
+        unsafe { guard.array.get_unchecked_mut(guard.len).write(value) };
+        guard.len += 1;
+    }
+    // SAFETY: all `N` elements were initialised above and `MaybeUninit<T>`
+    // has the same layout as `T`. The guard is forgotten so the elements are
+    // not dropped twice.
+    let array = unsafe { ptr::read(guard.array.as_ptr().cast::<[T; N]>()) };
+    mem::forget(guard);
+    Ok(array)
+}
+
+/// Drops the initialised prefix of an array if creating it fails part way.
+struct Guard<T, const N: usize> {
+    array: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> Drop for Guard<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: the first `len` elements have been initialised.
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.array.as_mut_ptr().cast::<T>(),
+                self.len,
+            ));
+        }
+    }
+}
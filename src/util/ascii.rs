@@ -0,0 +1,59 @@
+/// Returns the byte length of the longest ASCII float at the start of `bytes`.
+///
+/// A float is an optional sign, digits with an optional fractional part and an
+/// optional exponent. At least one digit is required in the integer or
+/// fractional part. An exponent without digits is not part of the float.
+///
+/// Returns `Err(index)` with the byte index a digit was expected at if there
+/// was no float. The index is equal to `bytes.len()` if input ran out.
+pub(crate) fn float_len(bytes: &[u8]) -> Result<usize, usize> {
+    let digits = |i: usize| digit_len(bytes.get(i..).unwrap_or_default());
+    let mut i = 0;
+    if matches!(bytes.first(), Some(b'+' | b'-')) {
+        i += 1;
+    }
+    let int_len = digits(i);
+    i += int_len;
+    if bytes.get(i) == Some(&b'.') {
+        let frac_len = digits(i + 1);
+        if int_len == 0 && frac_len == 0 {
+            return Err(i + 1);
+        }
+        i += 1 + frac_len;
+    } else if int_len == 0 {
+        return Err(i);
+    }
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        let mut j = i + 1;
+        if matches!(bytes.get(j), Some(b'+' | b'-')) {
+            j += 1;
+        }
+        let exp_len = digits(j);
+        if exp_len > 0 {
+            i = j + exp_len;
+        }
+    }
+    Ok(i)
+}
+
+/// Returns the number of ASCII digits at the start of `bytes`.
+pub(crate) fn digit_len(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|b| b.is_ascii_digit()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_float_len() {
+        assert_eq!(float_len(b"1"), Ok(1));
+        assert_eq!(float_len(b"-1.5e+3x"), Ok(7));
+        assert_eq!(float_len(b".5"), Ok(2));
+        assert_eq!(float_len(b"1.e"), Ok(2));
+        assert_eq!(float_len(b"1e+"), Ok(1));
+        assert_eq!(float_len(b""), Err(0));
+        assert_eq!(float_len(b"-x"), Err(1));
+        assert_eq!(float_len(b"-."), Err(2));
+    }
+}
@@ -10,7 +10,12 @@ pub(crate) fn count_u8(needle: u8, haystack: &[u8]) -> usize {
 }
 
 #[cfg(not(feature = "bytecount"))]
+#[inline(always)]
 pub(crate) fn count_u8(needle: u8, haystack: &[u8]) -> usize {
+    count_u8_scalar(needle, haystack)
+}
+
+pub(crate) fn count_u8_scalar(needle: u8, haystack: &[u8]) -> usize {
     haystack.iter().copied().filter(|b| *b == needle).count()
 }
 
@@ -42,6 +47,10 @@ pub(crate) fn num_chars(s: &str) -> usize {
 #[cfg(not(feature = "bytecount"))]
 #[inline(always)]
 pub(crate) fn num_chars(s: &str) -> usize {
+    num_chars_scalar(s)
+}
+
+pub(crate) fn num_chars_scalar(s: &str) -> usize {
     s.chars().count()
 }
 
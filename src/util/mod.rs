@@ -1,3 +1,5 @@
+pub(crate) mod array;
+pub(crate) mod ascii;
 pub(crate) mod fast;
 pub(crate) mod slice;
 pub(crate) mod utf8;
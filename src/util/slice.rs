@@ -1,3 +1,5 @@
+use crate::input::Span;
+
 /// Returns an end slice of the slice provided (always empty).
 #[inline(always)]
 pub(crate) fn end<T>(slice: &[T]) -> &[T] {
@@ -19,6 +21,23 @@ pub(crate) fn split_at_opt<T>(slice: &[T], mid: usize) -> Option<(&[T], &[T])> {
     }
 }
 
+/// Joins two sub-slices of `parent` if `right` immediately follows `left`.
+///
+/// Returns `None` if either slice is not within `parent` or they are not
+/// adjacent. The joined slice is taken from `parent`, so slices from separate
+/// allocations that happen to be adjacent in memory are never joined.
+#[inline(always)]
+pub(crate) fn join_within<'a>(parent: &'a [u8], left: &[u8], right: &[u8]) -> Option<&'a [u8]> {
+    let parent_span = Span::from(parent);
+    let left = Span::from(left).range_of(parent_span)?;
+    let right = Span::from(right).range_of(parent_span)?;
+    if left.end == right.start {
+        parent.get(left.start..right.end)
+    } else {
+        None
+    }
+}
+
 /// Returns the first item in a slice without bounds checking.
 #[inline(always)]
 pub(crate) unsafe fn first_unchecked<T: Copy>(slice: &[T]) -> T {
@@ -34,6 +34,12 @@ fn is_cont_byte(byte: u8) -> bool {
     (byte & !CONT_MASK) == TAG_CONT_U8
 }
 
+/// Returns the number of chars in valid UTF-8 bytes.
+#[inline]
+pub(crate) fn char_count(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|&&byte| !is_cont_byte(byte)).count()
+}
+
 /// Returns a str slice from a byte slice without validation.
 #[inline]
 pub(crate) unsafe fn from_unchecked(bytes: &[u8]) -> &str {
@@ -52,6 +58,10 @@ pub(crate) fn char_len(b: u8) -> usize {
 fn first_codepoint(bytes: &[u8]) -> Result<char, InvalidChar> {
     if let Some(first_byte) = bytes.first() {
         let len = char_len(*first_byte);
+        // The byte can never start a UTF-8 codepoint.
+        if len == 0 {
+            return Err(InvalidChar { error_len: Some(1) });
+        }
         if bytes.len() >= len {
             return parse_char(&bytes[..len]);
         }
@@ -251,6 +261,8 @@ mod tests {
         assert!(first_codepoint(b"").is_err());
         assert!(first_codepoint(b"\xFF").is_err());
         assert!(first_codepoint(b"\xFFa").is_err());
+        assert_eq!(first_codepoint(b"\xFF").unwrap_err().error_len(), Some(1));
+        assert_eq!(first_codepoint(b"\xE2").unwrap_err().error_len(), None);
         assert_eq!(first_codepoint(b"a").unwrap(), 'a');
         assert_eq!(first_codepoint(b"ab").unwrap(), 'a');
         assert_eq!(
@@ -0,0 +1,51 @@
+#[macro_use]
+mod common;
+
+use common::*;
+
+///////////////////////////////////////////////////////////////////////////////
+// bytes::Bytes
+
+#[test]
+fn test_read_u32_be_header() {
+    let buf = bytes::Bytes::from(vec![0x00, 0x00, 0x01, 0x02, b'h', b'i']);
+    let (len, body) = dangerous::input(&buf)
+        .read_all::<_, _, Expected<'_>>(|r| Ok((r.read_u32_be()?, r.take_remaining())))
+        .unwrap();
+
+    assert_eq!(len, 0x0102);
+    assert_eq!(body, b"hi"[..]);
+    assert_eq!(body.span().range_of(buf[..].into()), Some(4..6));
+}
+
+#[test]
+fn test_input_from_bytes_is_zero_copy() {
+    let buf = bytes::Bytes::from_static(b"hello");
+
+    assert_eq!(dangerous::input(&buf).span(), Span::from(&buf[..]));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// bytes::Buf
+
+#[test]
+fn test_input_from_buf_chunk() {
+    use bytes::Buf;
+
+    let mut buf = bytes::Bytes::from_static(b"\x00\x00\x00\x05hello");
+    buf.advance(4);
+    let input = dangerous::input_from_buf(&buf);
+
+    assert_eq!(input, b"hello"[..]);
+    assert!(input.span().is_within(Span::from(&buf[..])));
+}
+
+#[test]
+fn test_input_from_buf_chain_first_chunk() {
+    use bytes::Buf;
+
+    let buf = (&b"hel"[..]).chain(&b"lo"[..]);
+
+    assert_eq!(buf.remaining(), 5);
+    assert_eq!(dangerous::input_from_buf(&buf), b"hel"[..]);
+}
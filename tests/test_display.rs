@@ -58,6 +58,59 @@ fn test_invalid_span_does_nothing() {
     assert_eq!(display.underline().to_string(), "                ");
 }
 
+#[test]
+fn test_span_stored_from_input() {
+    let full = b"hello world";
+    let span = read_all_ok!(full, |r| {
+        r.consume(b"hello ")?;
+        Ok(r.take_remaining().span())
+    });
+    let display = input!(full).display().span(span, 40);
+    assert_eq!(display.to_string(), "[68 65 6c 6c 6f 20 77 6f 72 6c 64]");
+    assert_eq!(
+        display.underline().to_string(),
+        "                   ^^ ^^ ^^ ^^ ^^ "
+    );
+}
+
+#[test]
+fn test_spans_disjoint() {
+    let full = b"hello world";
+    let spans = [full[..1].into(), full[6..8].into()];
+    let display = input!(full).display().spans(&spans, 40);
+    assert_eq!(display.to_string(), "[68 65 6c 6c 6f 20 77 6f 72 6c 64]");
+    assert_eq!(
+        display.underline().to_string(),
+        " ^^                ^^ ^^          "
+    );
+}
+
+#[test]
+fn test_spans_str_window_covers_spans() {
+    let full = "0123456789 (abc) 0123456789 [def] 0123456789";
+    let spans = [full[11..12].into(), full[32..33].into()];
+    let display = input!(full.as_bytes())
+        .display()
+        .str_hint()
+        .spans(&spans, 32);
+    assert_eq!(display.to_string(), r#".. " (abc) 0123456789 [def] " .."#);
+    assert_eq!(
+        display.underline().to_string(),
+        "     ^                    ^     "
+    );
+}
+
+#[test]
+fn test_spans_ignores_spans_not_within() {
+    let full = b"hello";
+    let spans = ["world".into(), full[4..].into()];
+    let display = input!(full).display().spans(&spans, 16);
+    assert_eq!(display.to_string(), "[68 65 6c 6c 6f]");
+    assert_eq!(display.underline().to_string(), "             ^^ ");
+    let display = input!(full).display().spans(&[], 16);
+    assert_eq!(display.underline().to_string(), "                ");
+}
+
 #[test]
 fn test_format_with_mut_ref_write() {
     use dangerous::display::{DisplayBase, Write};
@@ -87,6 +140,49 @@ fn test_format_with_mut_ref_write() {
     assert_eq!(Helper.to_string(), "a,1,01,80,81");
 }
 
+#[test]
+fn test_write_numbers() {
+    use dangerous::display::Write;
+
+    struct Buf(std::string::String);
+
+    impl Write for Buf {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0.push_str(s);
+            Ok(())
+        }
+
+        fn write_char(&mut self, c: char) -> fmt::Result {
+            self.0.push(c);
+            Ok(())
+        }
+
+        fn write_usize(&mut self, v: usize) -> fmt::Result {
+            self.0.push_str(&v.to_string());
+            Ok(())
+        }
+    }
+
+    let mut w = Buf(std::string::String::new());
+    let mut write_all = |w: &mut dyn Write| {
+        w.write_isize(-12)?;
+        w.write_char(',')?;
+        w.write_isize(isize::MIN)?;
+        w.write_char(',')?;
+        w.write_u64(u64::MAX)?;
+        w.write_char(',')?;
+        w.write_num(255, 16)?;
+        w.write_char(',')?;
+        w.write_num(5, 2)?;
+        w.write_char(',')?;
+        w.write_num(0, 36)
+    };
+    write_all(&mut w).unwrap();
+    assert_eq!(w.0, format!("-12,{},{},ff,101,0", isize::MIN, u64::MAX));
+    assert!(w.write_num(1, 37).is_err());
+    assert!(w.write_num(1, 1).is_err());
+}
+
 #[test]
 fn test_preferred_format_debug() {
     use dangerous::display::PreferredFormat;
@@ -95,3 +191,131 @@ fn test_preferred_format_debug() {
     assert_eq!(format!("{:?}", PreferredFormat::Bytes), "Bytes");
     assert_eq!(format!("{:?}", PreferredFormat::BytesAscii), "BytesAscii");
 }
+
+#[test]
+fn test_control_char_underline_aligns() {
+    let full = "a\u{1}b";
+    let display = input!(full.as_bytes())
+        .display()
+        .str_hint()
+        .span(full[2..].into(), 32);
+    assert_eq!(display.to_string(), r#""a\u{1}b""#);
+    assert_eq!(display.underline().to_string(), "       ^ ");
+}
+
+#[test]
+fn test_tab_underline_aligns() {
+    let full = "a\tb";
+    let display = input!(full.as_bytes())
+        .display()
+        .str_hint()
+        .span(full[2..].into(), 32);
+    assert_eq!(display.to_string(), r#""a\tb""#);
+    assert_eq!(display.clone().underline().to_string(), "    ^ ");
+    let display = display.expand_tabs(4);
+    assert_eq!(display.to_string(), r#""a    b""#);
+    assert_eq!(display.underline().to_string(), "      ^ ");
+}
+
+#[test]
+fn test_expanded_tab_underline() {
+    let full = "a\tb";
+    let display = input!(full.as_bytes())
+        .display()
+        .str_hint()
+        .expand_tabs(2)
+        .span(full[1..2].into(), 32);
+    assert_eq!(display.to_string(), r#""a  b""#);
+    assert_eq!(display.underline().to_string(), "  ^^  ");
+}
+
+#[test]
+fn test_hex_uppercase() {
+    let display = input!(&[0xAA, 0x0B, b'a']).display().hex_uppercase(true);
+    assert_eq!(display.to_string(), "[AA 0B 61]");
+    assert_eq!(display.clone().str_hint().to_string(), "[AA 0B 'a']");
+    assert_eq!(display.hex_uppercase(false).to_string(), "[aa 0b 61]");
+}
+
+#[test]
+fn test_colon_separator() {
+    let display = input!(&[0xAA, 0xBB, 0xCC]).display().separator(':');
+    assert_eq!(display.to_string(), "[aa:bb:cc]");
+    assert_eq!(display.hex_uppercase(true).to_string(), "[AA:BB:CC]");
+}
+
+#[test]
+fn test_colon_separator_head_tail() {
+    let display = input!(&[0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF])
+        .display()
+        .hex_uppercase(true)
+        .separator(':')
+        .head_tail(16);
+    assert_eq!(display.to_string(), "[AA:BB .. EE:FF]");
+}
+
+#[test]
+fn test_wide_separator_fits_width() {
+    let display = input!(&[0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF])
+        .display()
+        .separator('—')
+        .head(16);
+    assert_eq!(display.to_string(), "[aa—bb—cc—dd ..]");
+
+    let display = input!(&[0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF])
+        .display()
+        .separator('\u{3000}')
+        .head(16);
+    assert_eq!(display.to_string(), "[aa\u{3000}bb\u{3000}cc ..]");
+}
+
+#[test]
+fn test_colon_separator_underline() {
+    let full = &[0xAA, 0xBB, 0xCC];
+    let display = input!(full)
+        .display()
+        .separator(':')
+        .span(full[1..].into(), 32);
+    assert_eq!(display.to_string(), "[aa:bb:cc]");
+    assert_eq!(display.underline().to_string(), "    ^^ ^^ ");
+}
+
+#[test]
+fn test_write_to_matches_display() {
+    let display = input!(b"hello\xff").display().str_hint();
+    let mut formatted = std::string::String::new();
+    display.write_to(&mut formatted).unwrap();
+    assert_eq!(formatted, display.to_string());
+}
+
+#[test]
+fn test_write_io_matches_display() {
+    let display = input!(b"hello\xff").display().str_hint();
+    let mut formatted = Vec::new();
+    display.write_io(&mut formatted).unwrap();
+    assert_eq!(formatted, display.to_string().into_bytes());
+}
+
+#[test]
+fn test_hexdump_rows() {
+    let input = input!(b"hello world\x00\x01\x02\x7fabcde");
+    assert_eq!(input.byte_len(), 20);
+    let formatted = input.display().hexdump().to_string();
+    let rows: Vec<_> = formatted.lines().collect();
+    assert_eq!(
+        rows,
+        [
+            "00000000  68 65 6c 6c 6f 20 77 6f  72 6c 64 00 01 02 7f 61  |hello world....a|",
+            "00000010  62 63 64 65                                       |bcde|",
+        ]
+    );
+}
+
+#[test]
+fn test_hexdump_reset_by_full() {
+    let input = input!(b"hello");
+    assert_eq!(
+        input.display().hexdump().full().to_string(),
+        "[68 65 6c 6c 6f]"
+    );
+}
@@ -72,6 +72,43 @@ fn test_invalid_fatal() {
     );
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Fatal / Invalid into Expected
+
+#[test]
+#[cfg(feature = "full-backtrace")]
+fn test_fatal_into_expected() {
+    let error: Expected<'static> = Expected::from(Fatal);
+
+    assert!(error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), None);
+    assert_str_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            failed to convert from an error without details: expected valid input
+            > []
+              ^^
+            additional:
+              error offset: 0, input length: 0
+            backtrace:
+              1. `convert from an error without details` (expected valid input)
+        "#}
+    );
+}
+
+#[test]
+fn test_invalid_into_expected() {
+    let error = input!(b"")
+        .read_all::<_, _, Invalid>(|r| r.consume(b"12"))
+        .unwrap_err();
+    let error: Expected<'_> = error.into();
+
+    assert!(!error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(2));
+    let error: Expected<'_> = Invalid::fatal().into();
+    assert!(error.is_fatal());
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Expected support
 
@@ -119,6 +156,60 @@ impl<'i> ToRetryRequirement for ExpectedKind<'i> {
     }
 }
 
+#[test]
+fn test_retry_requirement_max() {
+    let one = RetryRequirement::new(1);
+    let two = RetryRequirement::new(2);
+    assert_eq!(RetryRequirement::max(one, two), two);
+    assert_eq!(RetryRequirement::max(two, one), two);
+    assert_eq!(RetryRequirement::max(None, one), one);
+    assert_eq!(RetryRequirement::max(None, None), None);
+}
+
+#[test]
+fn test_retry_requirement_for_struct_and_slice() {
+    assert_eq!(
+        RetryRequirement::for_struct::<u64>(),
+        RetryRequirement::new(8)
+    );
+    assert_eq!(RetryRequirement::for_struct::<[u8; 0]>(), None);
+    assert_eq!(
+        RetryRequirement::for_slice::<u32>(3),
+        RetryRequirement::new(12)
+    );
+    assert_eq!(
+        RetryRequirement::for_slice::<u32>(usize::MAX),
+        RetryRequirement::new(usize::MAX)
+    );
+}
+
+#[test]
+fn test_retry_requirement_saturating_add_met_by() {
+    let header = RetryRequirement::for_struct::<u16>().unwrap();
+    let body = RetryRequirement::new(3).unwrap();
+    let both = header.saturating_add(body);
+    assert_eq!(both.continue_after(), 5);
+    assert!(!both.met_by(4));
+    assert!(both.met_by(5));
+    assert!(both.met_by(6));
+    let max = RetryRequirement::new(usize::MAX).unwrap();
+    assert_eq!(max.saturating_add(body), max);
+}
+
+#[test]
+fn test_combine_max() {
+    let retry_1: Invalid = input!(b"a").read_all(|r| r.take(2)).unwrap_err();
+    let retry_3: Invalid = input!(b"a").read_all(|r| r.take(4)).unwrap_err();
+    let fatal: Invalid = input!(b"a").read_all(|r| r.consume(b"b")).unwrap_err();
+    assert_eq!(retry_1.combine_max(&[&retry_3]), RetryRequirement::new(3));
+    assert_eq!(fatal.combine_max(&[&retry_1]), RetryRequirement::new(1));
+    assert_eq!(
+        retry_3.combine_max(&[&fatal, &retry_1]),
+        RetryRequirement::new(3)
+    );
+    assert_eq!(fatal.combine_max(&[&fatal]), None);
+}
+
 fn trigger_expected_valid<E: Error<'static>>() -> E {
     input!(b"hello world\xC2 ")
         .read_all(|r| r.context("hi", |r| r.take_str_while(|_| true)))
@@ -168,10 +259,12 @@ fn test_expected_valid_variant() {
     assert!(!error.input().is_string());
     assert_eq!(error.input().into_bytes(), b"hello world\xC2 "[..]);
     assert_eq!(error.to_retry_requirement(), None);
+    assert_eq!(error.reason(), InvalidReason::Malformed);
     assert_str_eq!(
         format!("{:#?}\n", error),
         indoc! {r#"
             ExpectedValid {
+                reason: Malformed,
                 retry_requirement: None,
                 context: CoreContext {
                     span: Span(
@@ -191,6 +284,19 @@ fn test_expected_valid_variant() {
     );
 }
 
+#[test]
+fn test_expected_valid_reason() {
+    let reason = |input: &'static str| match dangerous::input(input)
+        .into_bound()
+        .read_all::<_, _, ExpectedKind<'_>>(|r| r.read_ascii_f64("float"))
+    {
+        Err(ExpectedKind::Valid(error)) => error.reason(),
+        _ => unreachable!(),
+    };
+    assert_eq!(reason("-x"), InvalidReason::Malformed);
+    assert_eq!(reason("-"), InvalidReason::Truncated);
+}
+
 #[test]
 fn test_expected_valid_root() {
     let error: Expected<RootBacktrace> = trigger_expected_valid();
@@ -235,6 +341,33 @@ fn test_expected_valid_full() {
     );
 }
 
+#[test]
+#[cfg(feature = "full-backtrace")]
+fn test_value_context_full() {
+    let field = 2;
+    let error: Expected = input!(b"hello world\xC2 ")
+        .read_all(|r| {
+            r.context(error::ValueContext::new(format!("field {}", field)), |r| {
+                r.take_str_while(|_| true)
+            })
+        })
+        .unwrap_err();
+    assert_str_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            failed to take UTF-8 input while a condition remains true: expected utf-8 code point
+            > [68 65 6c 6c 6f 20 77 6f 72 6c 64 c2 20]
+                                                ^^    
+            additional:
+              error offset: 11, input length: 13
+            backtrace:
+              1. `read all input`
+              2. `<context>` (expected field 2)
+              3. `take UTF-8 input while a condition remains true` (expected utf-8 code point)
+        "#}
+    );
+}
+
 #[test]
 #[cfg(feature = "full-backtrace")]
 fn test_expected_valid_with_empty_span_full() {
@@ -347,6 +480,265 @@ fn test_external_error_deep_child() {
     );
 }
 
+#[test]
+#[cfg(all(feature = "std", feature = "full-backtrace"))]
+fn test_external_error_source() {
+    use std::error::Error as _;
+    use std::net::{AddrParseError, Ipv4Addr};
+
+    let error = read_all_err!("not an ip", |r| {
+        r.take_remaining()
+            .into_external("ipv4 addr", |i| i.as_dangerous().parse::<Ipv4Addr>())
+    });
+
+    let source = error.source().expect("source preserved");
+    assert!(source.downcast_ref::<AddrParseError>().is_some());
+}
+
+#[test]
+#[cfg(feature = "full-backtrace")]
+fn test_backtrace_to_json() {
+    let error = read_all_err!(b"hello", |r| {
+        r.context("outer", |r| {
+            r.context("\"inner\"\\\n", |r| r.consume(b"world"))
+        })
+    });
+    assert_eq!(
+        error.backtrace().to_json().unwrap(),
+        concat!(
+            r#"[{"operation":"read all input","expected":null},"#,
+            r#"{"operation":"<context>","expected":"outer"},"#,
+            r#"{"operation":"<context>","expected":"\"inner\"\\\n"},"#,
+            r#"{"operation":"consume input","expected":"exact value"}]"#,
+        )
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_limited_backtrace_count_matches_walk() {
+    let error = input!(b"hello")
+        .read_all::<_, _, Expected<'_, LimitedBacktrace<1>>>(|r| {
+            r.context("outer", |r| r.consume(b"world"))
+        })
+        .unwrap_err();
+    let backtrace = error.backtrace();
+    assert_eq!(backtrace.count(), 3);
+    assert_eq!(walk_operations(backtrace).len(), backtrace.count());
+    let error = input!(b"hello")
+        .read_all::<_, _, Expected<'_, LimitedBacktrace<2>>>(|r| {
+            r.context("outer", |r| r.consume(b"world"))
+        })
+        .unwrap_err();
+    let backtrace = error.backtrace();
+    assert_eq!(backtrace.count(), 3);
+    assert_eq!(walk_operations(backtrace).len(), backtrace.count());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_limited_backtrace() {
+    fn nested<'i>(
+        r: &mut BytesReader<'i, Expected<'i, LimitedBacktrace<5>>>,
+        depth: usize,
+    ) -> Result<(), Expected<'i, LimitedBacktrace<5>>> {
+        if depth == 0 {
+            r.consume(b"world")
+        } else {
+            r.context("nested", |r| nested(r, depth - 1))
+        }
+    }
+    let error = input!(b"hello").read_all(|r| nested(r, 50)).unwrap_err();
+    let backtrace = error.backtrace();
+    // 50 nested contexts and the read all context, five of which are kept.
+    assert_eq!(backtrace.count(), 7);
+    let walked = walk_operations(backtrace);
+    assert_eq!(walked.len(), backtrace.count());
+    assert_eq!(walked[0], (1, "... 46 more contexts".into()));
+    assert_eq!(walked[1], (2, "<context>".into()));
+    assert_eq!(walked[6], (7, "consume input".into()));
+    assert_eq!(frame_operations(backtrace), walked);
+}
+
+fn walk_operations(backtrace: &dyn Backtrace) -> Vec<(usize, std::string::String)> {
+    let mut walked = Vec::new();
+    backtrace.walk(&mut |depth, context| {
+        let mut operation = std::string::String::new();
+        context.operation().description(&mut operation).unwrap();
+        walked.push((depth, operation));
+        true
+    });
+    walked
+}
+
+#[test]
+#[cfg(feature = "full-backtrace")]
+fn test_backtrace_frames() {
+    let error: Expected = input!(b"hello")
+        .read_all(|r| r.context("outer", |r| r.context("inner", |r| r.consume(b"world"))))
+        .unwrap_err();
+    let backtrace = error.backtrace();
+    let operations: Vec<_> = frame_operations(backtrace)
+        .into_iter()
+        .map(|(_, operation)| operation)
+        .collect();
+    assert_eq!(
+        operations,
+        ["read all input", "<context>", "<context>", "consume input"]
+    );
+    let expected: Vec<_> = backtrace
+        .frames()
+        .filter(|frame| frame.has_expected())
+        .map(|frame| {
+            let mut expected = std::string::String::new();
+            frame.expected(&mut expected).unwrap();
+            expected
+        })
+        .collect();
+    assert_eq!(expected, ["outer", "inner", "exact value"]);
+    assert_eq!(backtrace.frames().len(), backtrace.count());
+    assert_eq!(frame_operations(backtrace), walk_operations(backtrace));
+    assert_eq!(
+        format!("{:?}", backtrace.frames().nth(1).unwrap()),
+        r#"BacktraceFrame { depth: 2, operation: "<context>" }"#
+    );
+}
+
+#[test]
+#[cfg(feature = "full-backtrace")]
+fn test_backtrace_frames_children() {
+    struct DeepExternalError;
+
+    impl<'i> External<'i> for DeepExternalError {
+        fn push_backtrace<E>(self, error: E) -> E
+        where
+            E: WithContext<'i>,
+        {
+            error.with_context("a").with_context("b")
+        }
+    }
+
+    let error = read_all_err!("hello world", |r| {
+        r.context("outer", |r| {
+            r.try_external("value", |_| {
+                Result::<(usize, ()), DeepExternalError>::Err(DeepExternalError)
+            })
+        })
+    });
+    let backtrace = error.backtrace();
+    assert_eq!(backtrace.frames().len(), backtrace.count());
+    assert_eq!(frame_operations(backtrace), walk_operations(backtrace));
+}
+
+#[test]
+fn test_stack_backtrace_frames() {
+    let error = input!(b"hello")
+        .read_all::<_, _, Expected<'_, StackBacktrace<1>>>(|r| {
+            r.context("outer", |r| r.consume(b"world"))
+        })
+        .unwrap_err();
+    let backtrace = error.backtrace();
+    assert_eq!(backtrace.frames().len(), backtrace.count());
+    assert_eq!(frame_operations(backtrace), walk_operations(backtrace));
+}
+
+fn frame_operations(backtrace: &dyn Backtrace) -> Vec<(usize, std::string::String)> {
+    backtrace
+        .frames()
+        .map(|frame| {
+            let mut operation = std::string::String::new();
+            frame.operation().description(&mut operation).unwrap();
+            (frame.depth(), operation)
+        })
+        .collect()
+}
+
+#[test]
+fn test_stack_backtrace_fill() {
+    let error: Expected<StackBacktrace<2>> = input!(b"hello")
+        .read_all(|r| r.context("outer", |r| r.consume(b"world")))
+        .unwrap_err();
+    let backtrace = error.backtrace();
+    assert_eq!(backtrace.count(), 3);
+    assert_eq!(
+        walk_operations(backtrace),
+        [
+            (1, "read all input".into()),
+            (2, "<context>".into()),
+            (3, "consume input".into()),
+        ]
+    );
+    let mut expected = std::string::String::new();
+    backtrace.walk(&mut |depth, context| {
+        if depth == 2 {
+            context.expected(&mut expected).unwrap();
+        }
+        true
+    });
+    assert_eq!(expected, "outer");
+}
+
+#[test]
+fn test_stack_backtrace_overflow() {
+    let error: Expected<StackBacktrace<2>> = input!(b"hello")
+        .read_all(|r| {
+            r.context("outer", |r| {
+                r.context("middle", |r| r.context("inner", |r| r.consume(b"world")))
+            })
+        })
+        .unwrap_err();
+    let backtrace = error.backtrace();
+    assert_eq!(backtrace.count(), 4);
+    assert_eq!(
+        walk_operations(backtrace),
+        [
+            (1, "... 2 more contexts".into()),
+            (2, "<context>".into()),
+            (3, "<context>".into()),
+            (4, "consume input".into()),
+        ]
+    );
+}
+
+#[test]
+#[cfg(feature = "full-backtrace")]
+fn test_backtrace_to_json_write_error() {
+    struct Unwritable;
+
+    impl Operation for Unwritable {
+        fn description(&self, _w: &mut dyn display::Write) -> std::fmt::Result {
+            Err(std::fmt::Error)
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+    }
+
+    impl Context for Unwritable {
+        fn operation(&self) -> &dyn Operation {
+            self
+        }
+    }
+
+    let error = input!(b"hello")
+        .read_all::<_, _, Expected<'_>>(|r| {
+            r.consume(b"world").map_err(|e| e.with_context(Unwritable))
+        })
+        .unwrap_err();
+    assert_eq!(error.backtrace().to_json(), Err(std::fmt::Error));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_backtrace_to_json_root() {
+    let error: Expected<RootBacktrace> = trigger_expected_length();
+    assert_eq!(
+        error.backtrace().to_json().unwrap(),
+        r#"[{"operation":"take a length of input","expected":"enough input for split"}]"#
+    );
+}
+
 #[test]
 fn test_expected_length_root() {
     let error: Expected<RootBacktrace> = trigger_expected_length();
@@ -426,6 +818,24 @@ fn test_expected_value_variant() {
     );
 }
 
+#[test]
+fn test_expected_value_divergence_offset() {
+    let error = match input!(b"help").read_all(|r| r.consume(b"hello")) {
+        Err(ExpectedKind::Value(error)) => error,
+        _ => unreachable!(),
+    };
+    assert_eq!(error.divergence_offset(), Some(3));
+}
+
+#[test]
+fn test_expected_value_divergence_offset_too_short() {
+    let error = match input!(b"hel").read_all(|r| r.consume(b"hello")) {
+        Err(ExpectedKind::Value(error)) => error,
+        _ => unreachable!(),
+    };
+    assert_eq!(error.divergence_offset(), None);
+}
+
 #[test]
 fn test_expected_value_root() {
     let error: Expected<RootBacktrace> = trigger_expected_value();
@@ -476,6 +886,52 @@ fn test_expected_value_full() {
     );
 }
 
+#[test]
+fn test_expected_value_any_of() {
+    let error: Expected<RootBacktrace> = input!(b"abc")
+        .read_all(|r| r.take_until(input::AnyOf(b",;")))
+        .unwrap_err();
+
+    assert_str_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            failed to take input until a pattern matches: found no byte matching the expected set
+            expected any of:
+            > [2c 3b]
+            in:
+            > [61 62 63]
+               ^^ ^^ ^^ 
+            additional:
+              error offset: 0, input length: 3
+            backtrace:
+              1. `take input until a pattern matches` (expected pattern match)
+        "#}
+    );
+}
+
+#[test]
+fn test_expected_value_none_of() {
+    let error: Expected<RootBacktrace> = input!(b",;,")
+        .read_all(|r| r.take_until(input::NoneOf(b",;")))
+        .unwrap_err();
+
+    assert_str_eq!(
+        format!("{}\n", error),
+        indoc! {r#"
+            failed to take input until a pattern matches: found no byte matching the expected set
+            expected none of:
+            > [2c 3b]
+            in:
+            > [2c 3b 2c]
+               ^^ ^^ ^^ 
+            additional:
+              error offset: 0, input length: 3
+            backtrace:
+              1. `take input until a pattern matches` (expected pattern match)
+        "#}
+    );
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Other
 
@@ -557,6 +1013,50 @@ fn test_error_display_str_hint() {
     );
 }
 
+#[test]
+fn test_error_display_input_only() {
+    let error: Expected<RootBacktrace> = trigger_expected_valid();
+
+    assert_str_eq!(
+        format!(
+            "{}",
+            error
+                .display()
+                .description(false)
+                .additional(false)
+                .backtrace(false)
+        ),
+        indoc! {r#"
+            > [68 65 6c 6c 6f 20 77 6f 72 6c 64 c2 20]
+                                                ^^    
+        "#}
+    );
+}
+
+#[test]
+fn test_error_display_section_toggles() {
+    let error: Expected<RootBacktrace> = trigger_expected_valid();
+
+    for flags in 0..16 {
+        let [banner, description, additional, backtrace] =
+            [0, 1, 2, 3].map(|bit| flags & (1 << bit) != 0);
+        let output = format!(
+            "{}",
+            error
+                .display()
+                .banner(banner)
+                .description(description)
+                .additional(additional)
+                .backtrace(backtrace)
+        );
+        assert_eq!(output.contains("-- INPUT ERROR --"), banner);
+        assert_eq!(output.contains("failed to "), description);
+        assert_eq!(output.contains("additional:"), additional);
+        assert_eq!(output.contains("backtrace:"), backtrace);
+        assert!(output.contains("> [68 65 6c 6c 6f"));
+    }
+}
+
 #[test]
 fn test_invalid_error_details_span() {
     use dangerous::Input;
@@ -77,6 +77,38 @@ fn test_read_all() {
     );
 }
 
+#[test]
+fn test_read_all_with_progress() {
+    let input = [0_u8; 1000];
+    let mut calls = Vec::new();
+    let mut on_progress = |consumed, total| calls.push((consumed, total));
+    let result: Result<_, Invalid> = dangerous::input(&input[..]).read_all_with_progress(
+        |r| {
+            while !r.at_end() {
+                let len = r.remaining_bytes().min(7);
+                r.skip(len)?;
+            }
+            Ok(())
+        },
+        &mut on_progress,
+    );
+    assert!(result.is_ok());
+    assert_eq!(calls.first(), Some(&(14, 1000)));
+    assert_eq!(calls.last(), Some(&(1000, 1000)));
+    assert!(calls.len() <= 100);
+    assert!(calls.windows(2).all(|w| w[0].0 < w[1].0));
+}
+
+#[test]
+fn test_read_all_with_progress_trailing() {
+    let mut calls = Vec::new();
+    let mut on_progress = |consumed, total| calls.push((consumed, total));
+    let result: Result<_, Invalid> =
+        dangerous::input(b"hello").read_all_with_progress(|r| r.skip(4), &mut on_progress);
+    assert!(result.is_err());
+    assert_eq!(calls, [(4, 5)]);
+}
+
 #[test]
 fn test_read_partial() {
     // Valid
@@ -114,3 +146,439 @@ fn test_read_infallible() {
         (input!(b"hello"), input!(b"1"))
     );
 }
+
+#[test]
+fn test_concat() {
+    let buf = Bytes::concat(&[input!(b"hel"), input!(b""), input!(b"lo")]);
+    assert_eq!(buf, b"hello");
+    assert_eq!(dangerous::input_concat(&[b"hel", b"lo"]), b"hello");
+    assert_eq!(read_all_ok!(&buf[..], |r| r.take(5)), input!(b"hello"));
+}
+
+#[test]
+fn test_match_indices() {
+    let indices: Vec<_> = input!(b"aaaa").match_indices(&b"aa"[..]).collect();
+    assert_eq!(indices, [(0, 2), (2, 2)]);
+    let indices: Vec<_> = input!(b"aaa").match_indices(&b"aa"[..]).collect();
+    assert_eq!(indices, [(0, 2)]);
+    let indices: Vec<_> = input!(b"abc").match_indices(b'x').collect();
+    assert!(indices.is_empty());
+}
+
+#[test]
+fn test_num_chars_scalar_agrees() {
+    let input = dangerous::input("a\u{e9}b\u{2603}c\u{1f600}d");
+    assert_eq!(input.num_chars(), 7);
+    assert_eq!(input.num_chars_scalar(), input.num_chars());
+}
+
+#[test]
+fn test_count_scalar_agrees() {
+    let input = dangerous::input("a\u{e9}a\u{2603}a".as_bytes());
+    assert_eq!(input.count(b'a'), 3);
+    assert_eq!(input.count_scalar(b'a'), input.count(b'a'));
+    assert_eq!(input.count_scalar(0xe2), input.count(0xe2));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_to_owned() {
+    let owned = {
+        let full = std::string::String::from("héllo");
+        let input = dangerous::input(full.as_str());
+        (input.to_owned_bytes(), input.to_owned_string())
+    };
+    assert_eq!(owned.0, "héllo".as_bytes());
+    assert_eq!(owned.1, "héllo");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_from_utf16() {
+    let le = String::from_utf16_le::<Expected<'_>>(input!(b"A\0\x00\xfb"));
+    assert_eq!(le.unwrap(), "A\u{fb00}");
+    let be = String::from_utf16_be::<Expected<'_>>(input!(b"\0A\xfb\x00"));
+    assert_eq!(be.unwrap(), "A\u{fb00}");
+    let pair = String::from_utf16_le::<Expected<'_>>(input!(b"\x3d\xd8\x00\xde"));
+    assert_eq!(pair.unwrap(), "\u{1f600}");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_from_utf16_unpaired_high_surrogate() {
+    let input = input!(b"\x3d\xd8A\0");
+    let err = String::from_utf16_le::<Expected<'_>>(input.clone()).unwrap_err();
+    assert!(err.is_fatal());
+    let span = err.backtrace().root().span;
+    assert_eq!(span.range_of(input.span()), Some(0..2));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_from_utf16_short() {
+    // A dangling high surrogate and an odd byte count may be completed.
+    let err = String::from_utf16_le::<Expected<'_>>(input!(b"A\0\x3d\xd8")).unwrap_err();
+    assert_eq!(err.to_retry_requirement(), RetryRequirement::new(2));
+    let err = String::from_utf16_le::<Expected<'_>>(input!(b"A\0B")).unwrap_err();
+    assert_eq!(err.to_retry_requirement(), RetryRequirement::new(1));
+    // Unless the input is bound at the end.
+    let err = String::from_utf16_le::<Expected<'_>>(input!(b"A\0B").into_bound_end()).unwrap_err();
+    assert!(err.is_fatal());
+}
+
+#[test]
+fn test_maybe_string_bytes() {
+    let maybe = MaybeString::from_bytes(b"hello");
+    assert!(!maybe.is_string());
+    assert_eq!(maybe.as_bytes(), b"hello");
+    assert_eq!(maybe.as_str(), None);
+    assert_eq!(maybe.bound(), Bound::Start);
+}
+
+#[test]
+fn test_maybe_string_string() {
+    let maybe = MaybeString::from_str("héllo");
+    assert!(maybe.is_string());
+    assert_eq!(maybe.as_bytes(), "héllo".as_bytes());
+    assert_eq!(maybe.as_str(), Some("héllo"));
+    assert_eq!(maybe.bound(), Bound::Start);
+}
+
+#[test]
+fn test_maybe_string_from_error() {
+    let error = read_all_err!("hello", |r| r.consume("world"));
+    assert_eq!(error.input().as_str(), Some("hello"));
+    let error = read_all_err!(b"hello", |r| r.consume(b"world"));
+    assert_eq!(error.input().as_str(), None);
+    assert_eq!(error.input().as_bytes(), b"hello");
+}
+
+#[test]
+fn test_split_at_ratio() {
+    let (head, tail) = input!(b"abcd").split_at_ratio(1, 2);
+    assert_eq!(head, b"ab"[..]);
+    assert_eq!(tail, b"cd"[..]);
+}
+
+#[test]
+fn test_split_at_ratio_char_boundary() {
+    // The midpoint of 5 bytes lands within `é` and is backed off before it.
+    let input = input!("aébc");
+    let (head, tail) = input.clone().split_at_ratio(1, 2);
+    assert_eq!(head, "a");
+    assert_eq!(tail, "ébc");
+    let (head, tail) = input.split_at_ratio(3, 5);
+    assert_eq!(head, "aé");
+    assert_eq!(tail, "bc");
+}
+
+#[test]
+fn test_split_at_ratio_out_of_range() {
+    let (head, tail) = input!("abc").split_at_ratio(3, 2);
+    assert_eq!(head, "abc");
+    assert_eq!(tail, "");
+    let (head, tail) = input!("abc").split_at_ratio(1, 0);
+    assert_eq!(head, "abc");
+    assert_eq!(tail, "");
+    let (head, tail) = input!("abc").split_at_ratio(usize::MAX, usize::MAX);
+    assert_eq!(head, "abc");
+    assert_eq!(tail, "");
+}
+
+#[test]
+fn test_join_adjacent() {
+    let input = dangerous::input(b"abcd");
+    let (head, tail) = input.clone().split_at_opt(1).unwrap();
+    let joined = head.join(tail, &input).unwrap();
+    assert_eq!(joined, b"abcd"[..]);
+    assert_eq!(joined.bound(), Bound::Start);
+}
+
+#[test]
+fn test_join_adjacent_str() {
+    let input = dangerous::input("aébc").into_bound();
+    let (head, tail) = input.clone().split_at_opt(2).unwrap();
+    let joined = head.join(tail, &input).unwrap();
+    assert_eq!(joined, "aébc");
+    assert_eq!(joined.bound(), Bound::StartEnd);
+}
+
+#[test]
+fn test_join_non_adjacent() {
+    let input = dangerous::input(b"abcd");
+    let (head, tail) = input.clone().split_at_opt(1).unwrap();
+    let (_, tail) = tail.split_at_opt(1).unwrap();
+    assert_eq!(head.clone().join(tail.clone(), &input), None);
+    assert_eq!(tail.join(head, &input), None);
+}
+
+#[test]
+fn test_join_outside_parent() {
+    let bytes = b"abcd";
+    let (head, tail) = dangerous::input(bytes).split_at_opt(2).unwrap();
+    // Adjacent in memory, but not within the parent provided.
+    let (parent, _) = dangerous::input(bytes).split_at_opt(3).unwrap();
+    assert_eq!(head.clone().join(tail.clone(), &parent), None);
+    let other = dangerous::input(b"ab");
+    assert_eq!(head.join(tail, &other), None);
+}
+
+#[test]
+fn test_bytes_eq_array() {
+    let input = input!(b"abc");
+    assert_eq!(input, *b"abc");
+    assert_eq!(input, b"abc");
+    assert_eq!(*b"abc", input);
+    assert_eq!(b"abc", input);
+    assert_ne!(input, b"abd");
+    assert_ne!(input, b"ab");
+}
+
+#[test]
+fn test_bytes_eq_slice() {
+    let input = input!(b"abc");
+    assert_eq!(input, &b"abc"[..]);
+    assert_eq!(&b"abc"[..], input);
+    assert_ne!(input, &b"ab"[..]);
+    assert_ne!(&b"ab"[..], input);
+}
+
+#[test]
+fn test_value_eq_ignores_bound() {
+    let unbound = input!(b"abc");
+    let bound = unbound.clone().into_bound();
+    assert_eq!(unbound.bound(), Bound::Start);
+    assert_eq!(bound.bound(), Bound::StartEnd);
+    assert!(unbound.value_eq(&bound));
+    assert_eq!(unbound, bound);
+    assert!(!unbound.value_eq(&input!(b"abd")));
+}
+
+#[test]
+fn test_value_eq_str_ignores_bound() {
+    let (head, tail) = dangerous::input("abab").split_at::<Invalid>(2).unwrap();
+    assert_ne!(head.bound(), tail.bound());
+    assert!(head.value_eq(&tail));
+    assert_eq!(head, tail);
+}
+
+#[test]
+fn test_bytes_eq_generic() {
+    fn is_abc<T>(value: &T) -> bool
+    where
+        T: PartialEq<[u8; 3]> + for<'a> PartialEq<&'a [u8]>,
+    {
+        *value == *b"abc" && *value == &b"abc"[..]
+    }
+    assert!(is_abc(&input!(b"abc")));
+    assert!(!is_abc(&input!(b"abd")));
+}
+
+#[test]
+fn test_spanned_tokens() {
+    let input = dangerous::input("ab");
+    let tokens: Vec<_> = input
+        .clone()
+        .spanned_tokens()
+        .map(|(span, c)| (span.range_of(input.span()).unwrap(), c))
+        .collect();
+    assert_eq!(tokens, [(0..1, 'a'), (1..2, 'b')]);
+}
+
+#[test]
+fn test_spanned_tokens_rev() {
+    let input = dangerous::input("aé");
+    let tokens: Vec<_> = input
+        .clone()
+        .spanned_tokens()
+        .rev()
+        .map(|(span, c)| (span.range_of(input.span()).unwrap(), c))
+        .collect();
+    assert_eq!(tokens, [(1..3, 'é'), (0..1, 'a')]);
+}
+
+#[test]
+fn test_spanned_tokens_bytes() {
+    let input = dangerous::input(b"ab");
+    let spans: Vec<_> = input
+        .clone()
+        .spanned_tokens()
+        .map(|(span, _)| span)
+        .collect();
+    assert_eq!(spans[1].of(input), Some(dangerous::input(b"b")));
+}
+
+#[test]
+fn test_split_on() {
+    let pieces: Vec<_> = input!(b"a,b,c").split_on(b',').collect();
+    assert_eq!(pieces, [input!(b"a"), input!(b"b"), input!(b"c")]);
+}
+
+#[test]
+fn test_split_on_trailing_empty() {
+    let pieces: Vec<_> = input!(b"a,").split_on(b',').collect();
+    assert_eq!(pieces, [input!(b"a"), input!(b"")]);
+}
+
+#[test]
+fn test_split_on_spans() {
+    let input = dangerous::input(b"ab,,c");
+    let ranges: Vec<_> = input
+        .clone()
+        .split_on(b',')
+        .map(|piece| piece.span().range_of(input.span()).unwrap())
+        .collect();
+    assert_eq!(ranges, [0..2, 3..3, 4..5]);
+}
+
+#[test]
+fn test_split_on_str() {
+    let pieces: Vec<_> = input!("é::a").split_on("::").collect();
+    assert_eq!(pieces, [input!("é"), input!("a")]);
+}
+
+#[test]
+fn test_all() {
+    let alphabet = input::AnyOf(b"abcdef");
+    assert!(input!(b"abc").all(alphabet));
+    assert!(!input!(b"abz").all(alphabet));
+    assert!(input!(b"").all(alphabet));
+    assert!(input!(b"aaa").all(b'a'));
+    assert!(!input!("a\u{e9}").all(|c: char| c.is_ascii()));
+}
+
+#[test]
+fn test_any() {
+    let alphabet = input::AnyOf(b"xyz");
+    assert!(input!(b"abz").any(alphabet));
+    assert!(!input!(b"abc").any(alphabet));
+    assert!(!input!(b"").any(alphabet));
+    assert!(input!("a\u{e9}").any('\u{e9}'));
+}
+
+#[test]
+fn test_lines() {
+    let lines: Vec<_> = dangerous::input("a\nb\n").lines().collect();
+    assert_eq!(lines, ["a", "b"]);
+}
+
+#[test]
+fn test_lines_crlf() {
+    let full = "a\r\nb";
+    let lines: Vec<_> = dangerous::input(full).lines().collect();
+    assert_eq!(lines, ["a", "b"]);
+    assert_eq!(lines[0].span(), Span::from(&full[0..1]));
+    assert_eq!(lines[1].span(), Span::from(&full[3..4]));
+}
+
+#[test]
+fn test_lines_empty() {
+    assert_eq!(dangerous::input("").lines().count(), 0);
+    let lines: Vec<_> = dangerous::input("\n\na").lines().collect();
+    assert_eq!(lines, ["", "", "a"]);
+}
+
+#[test]
+fn test_trim_str() {
+    let full = "  hi  ";
+    let input = dangerous::input(full);
+    let ws = |c: char| c.is_ascii_whitespace();
+    assert_eq!(input.clone().trim_start(ws), "hi  ");
+    assert_eq!(input.clone().trim_end(ws), "  hi");
+    let trimmed = input.trim(ws);
+    assert_eq!(trimmed, "hi");
+    assert_eq!(trimmed.span(), Span::from(&full[2..4]));
+}
+
+#[test]
+fn test_trim_bytes() {
+    let full = b"  hi  ";
+    let input = dangerous::input(full);
+    let ws = |b: u8| b.is_ascii_whitespace();
+    assert_eq!(input.clone().trim_start(ws), b"hi  "[..]);
+    assert_eq!(input.clone().trim_end(ws), b"  hi"[..]);
+    let trimmed = input.trim(ws);
+    assert_eq!(trimmed, b"hi"[..]);
+    assert_eq!(trimmed.span(), Span::from(&full[2..4]));
+}
+
+#[test]
+fn test_trim_multi_byte_chars() {
+    let input = dangerous::input("♥♥a♥");
+    assert_eq!(input.clone().trim('♥'), "a");
+    assert_eq!(input.trim('b'), "♥♥a♥");
+}
+
+#[test]
+fn test_trim_all_and_empty() {
+    assert_eq!(dangerous::input("   ").trim(' '), "");
+    assert_eq!(dangerous::input("").trim(' '), "");
+}
+
+#[test]
+fn test_trim_keeps_bound() {
+    let input = dangerous::input(b" a ");
+    assert_eq!(input.bound(), Bound::Start);
+    assert_eq!(input.clone().trim_start(b' ').bound(), Bound::Start);
+    assert_eq!(input.clone().trim_end(b' ').bound(), Bound::Start);
+    assert_eq!(input.clone().trim(b' ').bound(), Bound::Start);
+    let bound = input.into_bound();
+    assert_eq!(bound.clone().trim_end(b' ').bound(), Bound::StartEnd);
+    assert_eq!(bound.trim_start(b' ').bound(), Bound::StartEnd);
+}
+
+#[test]
+fn test_trim_end_keeps_unbound_start() {
+    let (_, tail): (_, Bytes<'_>) = dangerous::input(b"ab")
+        .read_partial::<_, _, Invalid>(|r| Ok(r.take_consumed(|r| r.skip_while(|_| true))))
+        .unwrap();
+    assert_eq!(tail.bound(), Bound::None);
+    assert_eq!(tail.clone().trim_end(b' ').bound(), Bound::None);
+    assert_eq!(tail.clone().trim(b' ').bound(), Bound::None);
+    let tail = tail.into_bound_end();
+    assert_eq!(tail.clone().trim_end(b' ').bound(), Bound::End);
+    assert_eq!(tail.trim(b' ').bound(), Bound::End);
+}
+
+#[test]
+fn test_into_bound_end() {
+    let input = dangerous::input(b"1234");
+    assert!(input.bound_start());
+    assert!(!input.bound_end());
+    let input = input.into_bound_end();
+    assert_eq!(input.bound(), Bound::StartEnd);
+    assert!(input.bound_start());
+    assert!(input.bound_end());
+}
+
+#[test]
+fn test_into_bound_end_short_read_is_fatal() {
+    let input = dangerous::input(b"1234");
+    let err = input
+        .clone()
+        .read_partial::<_, _, Expected<'_>>(|r| r.take(5))
+        .unwrap_err();
+    assert!(!err.is_fatal());
+    let err = input
+        .into_bound_end()
+        .read_partial::<_, _, Expected<'_>>(|r| r.take(5))
+        .unwrap_err();
+    assert!(err.is_fatal());
+    assert_eq!(err.to_retry_requirement(), None);
+}
+
+#[test]
+fn test_into_bound_end_without_start() {
+    let (_, tail): (_, Bytes<'_>) = dangerous::input(b"ab")
+        .read_partial::<_, _, Invalid>(|r| Ok(r.take_consumed(|r| r.skip_while(|_| true))))
+        .unwrap();
+    assert_eq!(tail.bound(), Bound::None);
+    let tail = tail.into_bound_end();
+    assert_eq!(tail.bound(), Bound::End);
+    assert!(!tail.bound_start());
+    assert!(tail.bound_end());
+    let err = tail
+        .read_partial::<_, _, Invalid>(|r| r.take(1))
+        .unwrap_err();
+    assert!(err.is_fatal());
+}
@@ -0,0 +1,130 @@
+#[macro_use]
+mod common;
+
+use std::io::{self, Cursor, Read};
+
+use common::*;
+use dangerous::input::io::ReadBuffer;
+
+/// Reader that yields at most `chunk_len` bytes per read.
+struct Chunked<R> {
+    inner: R,
+    chunk_len: usize,
+    reads: usize,
+}
+
+impl<R> Chunked<R> {
+    fn new(inner: R, chunk_len: usize) -> Self {
+        Self {
+            inner,
+            chunk_len,
+            reads: 0,
+        }
+    }
+}
+
+impl<R: Read> Read for Chunked<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reads += 1;
+        let len = buf.len().min(self.chunk_len);
+        self.inner.read(&mut buf[..len])
+    }
+}
+
+fn read_message<E>(r: &mut BytesReader<'_, E>) -> Result<std::string::String, E>
+where
+    for<'i> E: Error<'i>,
+{
+    let len = r.read()?;
+    let body = r.take(len as usize)?.to_dangerous_str::<E>()?;
+    Ok(body.to_owned())
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ReadBuffer::read_streaming
+
+#[test]
+fn test_read_streaming_chunked() {
+    let stream = &mut Chunked::new(Cursor::new(b"\x05hello\x05world"), 2);
+    let buf = &mut ReadBuffer::new();
+
+    let first = buf.read_streaming(stream, read_message::<Invalid>).unwrap();
+    assert_eq!(first, Ok("hello".to_owned()));
+    let second = buf.read_streaming(stream, read_message::<Invalid>).unwrap();
+    assert_eq!(second, Ok("world".to_owned()));
+    assert!(buf.is_empty());
+    assert_eq!(stream.reads, 6);
+}
+
+#[test]
+fn test_read_streaming_keeps_trailing() {
+    let stream = &mut Cursor::new(b"\x01a\x02b");
+    let buf = &mut ReadBuffer::new();
+
+    let first = buf.read_streaming(stream, read_message::<Invalid>).unwrap();
+    assert_eq!(first, Ok("a".to_owned()));
+    assert_eq!(buf.input(), &b"\x02b"[..]);
+}
+
+#[test]
+fn test_read_streaming_invalid() {
+    let stream = &mut Chunked::new(Cursor::new(b"\x02\xff\xff"), 1);
+    let buf = &mut ReadBuffer::new();
+
+    let err = buf
+        .read_streaming(stream, read_message::<Invalid>)
+        .unwrap()
+        .unwrap_err();
+    assert!(err.is_fatal());
+}
+
+#[test]
+fn test_read_streaming_unexpected_eof() {
+    let stream = &mut Chunked::new(Cursor::new(b"\x05hel"), 1);
+    let buf = &mut ReadBuffer::new();
+
+    let err = buf
+        .read_streaming(stream, read_message::<Invalid>)
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    assert_eq!(buf.input(), &b"\x05hel"[..]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// ReadBuffer::read_more
+
+#[test]
+fn test_read_more_at_least() {
+    let stream = &mut Chunked::new(Cursor::new(b"hello world"), 2);
+    let buf = &mut ReadBuffer::new();
+
+    buf.read_more(stream, 3).unwrap();
+    assert_eq!(buf.input(), &b"hell"[..]);
+    buf.consume(2);
+    assert_eq!(buf.input(), &b"ll"[..]);
+}
+
+#[test]
+fn test_read_more_short_reads() {
+    let data: Vec<u8> = (0..3000).map(|i| i as u8).collect();
+    let stream = &mut Chunked::new(Cursor::new(&data), 7);
+    let buf = &mut ReadBuffer::new();
+
+    buf.read_more(stream, 2500).unwrap();
+    assert!(buf.len() >= 2500);
+    assert_eq!(buf.input(), &data[..buf.len()]);
+    let rest = data.len() - buf.len();
+    buf.read_more(stream, rest).unwrap();
+    assert_eq!(buf.input(), &data[..]);
+}
+
+#[test]
+fn test_read_more_overflow() {
+    let stream = &mut Cursor::new(b"hello");
+    let buf = &mut ReadBuffer::new();
+
+    buf.read_more(stream, 1).unwrap();
+    let err = buf.read_more(stream, usize::MAX).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(buf.input(), &b"hello"[..]);
+}
@@ -82,6 +82,17 @@ where
     })
 }
 
+fn parse_nom_tag<'i, E>(r: &mut BytesReader<'i, E>) -> Result<&'i [u8], E>
+where
+    E: Error<'i>,
+{
+    let tag = r.nom(nom::bytes::streaming::tag::<_, _, nom::error::Error<_>>(
+        "hello",
+    ))?;
+    r.consume(b"!")?;
+    Ok(tag)
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 
 #[test]
@@ -171,3 +182,42 @@ fn test_parse_verbose_err_retry() {
         "##}
     );
 }
+
+#[test]
+fn test_reader_nom_ok() {
+    assert_eq!(read_all_ok!(b"hello!", parse_nom_tag), b"hello");
+}
+
+#[test]
+fn test_reader_nom_err() {
+    let error = read_all_err!(b"world!", parse_nom_tag);
+    assert!(error.is_fatal());
+    assert!(format!("{:#}", error).contains("`Tag`"));
+}
+
+#[test]
+fn test_reader_nom_incomplete() {
+    let error = read_all_err!(b"hel", parse_nom_tag);
+    assert!(!error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(2));
+}
+
+#[test]
+fn test_expected_value_into_nom_error() {
+    let input = b"hello world";
+    let error = read_all_err!(&input[..], |r| {
+        r.consume(b"hello ")?;
+        r.consume(b"there")
+    });
+    let error = nom::error::Error::from(error);
+    assert_eq!(error.input, &input[6..]);
+    assert_eq!(error.code, nom::error::ErrorKind::Tag);
+}
+
+#[test]
+fn test_expected_length_into_nom_error() {
+    let error = read_all_err!(b"ab", |r| r.take(3));
+    let error = nom::error::Error::from(error);
+    assert_eq!(error.input, b"ab");
+    assert_eq!(error.code, nom::error::ErrorKind::Eof);
+}
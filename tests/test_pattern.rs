@@ -111,6 +111,21 @@ fn test_match_u8_all() {
     );
 }
 
+#[test]
+fn test_match_u8_large_buffer() {
+    // Compares the (SIMD accelerated with the `simd` feature) search against a
+    // scalar search over a buffer larger than any single SIMD block.
+    let buf: Vec<u8> = (0..64 * 1024).map(|i| (i % 251) as u8).collect();
+    for delim in [0u8, 7, 250, 255] {
+        let expected = buf.iter().position(|b| *b == delim);
+        let found = dangerous::input(&buf[..])
+            .read_partial::<_, _, Expected<'_>>(|r| r.take_until(delim))
+            .ok()
+            .map(|(v, _)| v.len());
+        assert_eq!(found, expected);
+    }
+}
+
 #[test]
 fn test_match_u8_none() {
     let _ = read_all_err!(b"hello", |r| {
@@ -285,6 +300,33 @@ fn test_match_bytes_half_end() {
     });
 }
 
+#[test]
+fn test_match_bytes_multi_byte_needle() {
+    use dangerous::input::Pattern;
+
+    let input = input!(b"<!--x-->y");
+    assert_eq!((&b"-->"[..]).find_match(&input), Some((5, 3)));
+    assert_eq!("-->".find_match(&input), Some((5, 3)));
+    assert_eq!(
+        read_all_ok!(b"<!--x-->y", |r| {
+            r.consume("<!--")?;
+            let v = r.take_until_consume(b"-->")?;
+            r.consume(b'y')?;
+            Ok(v)
+        }),
+        b"x"[..]
+    );
+}
+
+#[test]
+fn test_match_bytes_multi_byte_needle_none() {
+    use dangerous::input::Pattern;
+
+    let input = input!(b"<!--x--y");
+    assert_eq!((&b"-->"[..]).find_match(&input), None);
+    assert_eq!("-->".find_match(&input), None);
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // reject: string
 
@@ -546,3 +588,194 @@ fn test_match_string_regex_none() {
         "!!!!"[..]
     )
 }
+
+///////////////////////////////////////////////////////////////////////////////
+// until: regex
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_take_until_string_regex() {
+    let regex = regex::Regex::new("\\d+").unwrap();
+    assert_eq!(
+        read_partial_ok!("abc123def", |r| r.take_until(&regex)),
+        (input!("abc"), input!("123def"))
+    );
+    let error = read_all_err!("abcdef", |r| r.take_until(&regex));
+    assert!(error.to_string().contains("\\d+"));
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_take_until_bytes_regex() {
+    let regex = regex::bytes::Regex::new("\\d+").unwrap();
+    assert_eq!(
+        read_partial_ok!(b"abc123def", |r| r.take_until(&regex)),
+        (input!(b"abc"), input!(b"123def"))
+    );
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_take_until_anchored_regex() {
+    let regex = regex::Regex::new("^\\d+").unwrap();
+    assert_eq!(
+        read_partial_ok!("123abc", |r| r.take_until(&regex)),
+        (input!(""), input!("123abc"))
+    );
+    let _ = read_all_err!("abc123", |r| r.take_until(&regex));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// byte set: AnyOf / NoneOf
+
+#[test]
+fn test_any_of() {
+    assert_eq!(
+        read_partial_ok!(b", ;a,", |r| Ok(r.take_while(input::AnyOf(b",; ")))),
+        (input!(b", ;"), input!(b"a,"))
+    );
+    assert_eq!(
+        read_partial_ok!(b"abc;d", |r| r.take_until(input::AnyOf(b",;"))),
+        (input!(b"abc"), input!(b";d"))
+    );
+    assert_eq!(
+        read_partial_ok!(b"abc", |r| Ok(r.take_while(input::AnyOf(b"")))),
+        (input!(b""), input!(b"abc"))
+    );
+}
+
+#[test]
+fn test_none_of() {
+    assert_eq!(
+        read_partial_ok!(b"abc,d", |r| Ok(r.take_while(input::NoneOf(b",;")))),
+        (input!(b"abc"), input!(b",d"))
+    );
+    assert_eq!(
+        read_partial_ok!(b",;x", |r| {
+            r.skip_while(input::AnyOf(b",;"));
+            Ok(r.take_until_opt(input::NoneOf(b",;")))
+        }),
+        (input!(b""), input!(b"x"))
+    );
+    assert_eq!(
+        read_partial_ok!(b",;abc", |r| r.take_until(input::NoneOf(b",;"))),
+        (input!(b",;"), input!(b"abc"))
+    );
+    let _ = read_all_err!(b",;,", |r| r.take_until(input::NoneOf(b",;")));
+}
+
+#[test]
+fn test_byte_set_matches_fn_on_random_input() {
+    use dangerous::input::Pattern;
+
+    // Simple xorshift so the input is reproducible without dependencies.
+    let mut state = 0x2545_F491_u32;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state.to_le_bytes()[0]
+    };
+    let set: Vec<u8> = (0..32).map(|_| next()).collect();
+    let haystack: Vec<u8> = (0..4096).map(|_| next()).collect();
+    let in_set = |b: u8| set.contains(&b);
+    for start in 0..256 {
+        let bytes = input!(&haystack[start..]);
+        assert_eq!(
+            input::AnyOf(&set).find_match(&bytes),
+            in_set.find_match(&bytes)
+        );
+        assert_eq!(
+            input::AnyOf(&set).find_reject(&bytes),
+            in_set.find_reject(&bytes)
+        );
+        assert_eq!(
+            input::NoneOf(&set).find_match(&bytes),
+            (|b: u8| !in_set(b)).find_match(&bytes)
+        );
+        assert_eq!(
+            input::NoneOf(&set).find_reject(&bytes),
+            (|b: u8| !in_set(b)).find_reject(&bytes)
+        );
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// FnPattern
+
+fn triple<'i>() -> input::FnPattern<impl Fn(&Bytes<'i>) -> Option<(usize, usize)> + Copy> {
+    // SAFETY: the returned index and length are within the bytes.
+    unsafe {
+        input::FnPattern::new("triple", |input: &Bytes<'i>| {
+            input
+                .as_dangerous()
+                .windows(3)
+                .position(|w| w[0] == w[1] && w[1] == w[2])
+                .map(|index| (index, 3))
+        })
+    }
+}
+
+#[test]
+fn test_fn_pattern_take_until() {
+    assert_eq!(
+        read_partial_ok!(b"abcccd", |r| { r.take_until(triple()) }),
+        (input!(b"ab"), input!(b"cccd"))
+    );
+}
+
+#[test]
+fn test_fn_pattern_take_until_none() {
+    let error = read_partial_err!(b"abcd", |r| { r.take_until(triple()) });
+    assert_eq!(error.expected().unwrap().as_bytes(), b"triple");
+}
+
+#[test]
+fn test_fn_pattern_take_while() {
+    assert_eq!(
+        read_partial_ok!(b"aaabbbab", |r| { Ok(r.take_while(triple())) }),
+        (input!(b"aaabbb"), input!(b"ab"))
+    );
+    assert_eq!(
+        read_partial_ok!(b"aaabbb", |r| { Ok(r.take_while(triple())) }),
+        (input!(b"aaabbb"), input!(b""))
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// match last
+
+#[test]
+fn test_match_last_u8() {
+    use dangerous::input::Pattern;
+
+    assert_eq!(b'.'.find_match_last(&input!(b"a.b.c")), Some((3, 1)));
+    assert_eq!(b'.'.find_match_last(&input!(b"abc")), None);
+}
+
+#[test]
+fn test_match_last_bytes_overlapping() {
+    use dangerous::input::Pattern;
+
+    assert_eq!((&b"aa"[..]).find_match_last(&input!(b"aaa")), Some((1, 2)));
+}
+
+#[test]
+fn test_match_last_char() {
+    use dangerous::input::Pattern;
+
+    let input = dangerous::input("é.é.é");
+    assert_eq!('é'.find_match_last(&input), Some((6, 2)));
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_match_last_string_regex_anchored() {
+    use dangerous::input::Pattern;
+
+    let regex = regex::Regex::new("^a").unwrap();
+    assert_eq!(
+        (&regex).find_match_last(&dangerous::input("aaa")),
+        Some((0, 1))
+    );
+}
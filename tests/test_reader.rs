@@ -28,6 +28,93 @@ fn test_at_end_false() {
     );
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::offset
+
+#[test]
+fn test_offset() {
+    assert_eq!(
+        read_partial_ok!(b"hello", |r| {
+            let before = r.offset();
+            r.take(3)?;
+            Ok((before, r.offset()))
+        }),
+        ((0, 3), input!(b"lo"))
+    );
+}
+
+#[test]
+fn test_offset_within_error() {
+    assert_eq!(
+        read_partial_ok!(b"hello", |r| {
+            r.take(2)?;
+            r.error(|r: &mut BytesReader<'_, Invalid>| {
+                r.take(1)?;
+                Ok(r.offset())
+            })
+        }),
+        (3, input!(b"lo"))
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::context_region
+
+#[test]
+fn test_context_region() {
+    let input = "héllo wörld";
+    let (region, _) = read_partial_ok!(input, |r| {
+        r.take(1)?;
+        let start = r.context_span();
+        assert!(start.is_empty());
+        r.take(4)?;
+        Ok(r.context_region(start))
+    });
+    assert_eq!(region.range_of(input.as_bytes().into()), Some(1..6));
+    assert_eq!(region.of(input), Some("éllo"));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::set_bound_end
+
+#[test]
+fn test_set_bound_end() {
+    assert_eq!(
+        read_all_ok!(b"hello", |r| {
+            let before = r.bound();
+            r.set_bound_end();
+            r.skip(5)?;
+            Ok((before, r.bound()))
+        }),
+        (Bound::Start, Bound::StartEnd)
+    );
+}
+
+#[test]
+fn test_set_bound_end_fatal() {
+    let error = read_all_err!(b"hello", |r| {
+        r.set_bound_end();
+        r.take(6)
+    });
+    assert!(error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), None);
+}
+
+#[test]
+fn test_set_bound_end_keeps_start() {
+    let (_, unbound): (_, Bytes<'_>) = dangerous::input(b"ab")
+        .read_partial::<_, _, Invalid>(|r| Ok(r.take_consumed(|r| r.skip_while(|_| true))))
+        .unwrap();
+    assert_eq!(unbound.bound(), Bound::None);
+    let bound = unbound
+        .read_all::<_, _, Invalid>(|r| {
+            r.set_bound_end();
+            Ok(r.bound())
+        })
+        .unwrap();
+    assert_eq!(bound, Bound::End);
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::context
 
@@ -122,6 +209,47 @@ fn test_take_opt_false() {
     );
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::scoped
+
+#[test]
+fn test_scoped_trailing_does_not_desync() {
+    assert_eq!(
+        read_all_ok!(b"\x01\x02\x03\x04", |r| {
+            // The sub-parser reads one byte of the scope, leaving two.
+            let first = r.scoped(3, |r| r.read())?;
+            Ok((first, r.read()?))
+        }),
+        (1, 4)
+    );
+}
+
+#[test]
+fn test_scoped_cannot_read_past() {
+    let error = read_all_err!(b"\x01\x02\x03\x04", |r| {
+        r.scoped(2, |r| r.take(3))?;
+        r.take(2)
+    });
+    assert!(error.is_fatal());
+}
+
+#[test]
+fn test_scoped_too_short() {
+    let error = read_all_err!(b"\x01\x02", |r| r.scoped(3, |r| r.read()));
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+#[test]
+fn test_scoped_err_does_not_advance() {
+    assert_eq!(
+        read_all_ok!(b"\x01\x02", |r| {
+            let _ = r.scoped::<_, ()>(1, |r| r.consume(b'\x02'));
+            r.take(2)
+        }),
+        input!(b"\x01\x02")
+    );
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::take_remaining
 
@@ -142,6 +270,65 @@ fn test_take_remaining_second() {
     assert_eq!(remaining.bound(), Bound::None);
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::drain_to_vec
+
+#[test]
+fn test_drain_to_vec() {
+    let drained = read_all_ok!(b"hello", |r| {
+        r.consume(b"he")?;
+        let drained = r.drain_to_vec();
+        assert!(r.at_end());
+        Ok(drained)
+    });
+    assert_eq!(drained, b"llo");
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_chunks
+
+#[test]
+fn test_take_chunks() {
+    let chunks: Vec<_> = read_all_ok!(b"aabbcc", |r| {
+        let chunks = r.take_chunks(2).collect();
+        assert!(r.at_end());
+        Ok(chunks)
+    });
+    assert_eq!(chunks, [input!(b"aa"), input!(b"bb"), input!(b"cc")]);
+    assert!(chunks.iter().all(|chunk| chunk.bound() == Bound::StartEnd));
+}
+
+#[test]
+fn test_take_chunks_partial_unbound() {
+    let (chunks, remaining) =
+        read_partial_ok!(b"aabbc", |r| { Ok(r.take_chunks(2).collect::<Vec<_>>()) });
+    assert_eq!(chunks, [input!(b"aa"), input!(b"bb")]);
+    assert_eq!(remaining, input!(b"c"));
+}
+
+#[test]
+fn test_take_chunks_partial_bound() {
+    let chunks: Vec<_> = read_all_ok!(b"aabbc", |r| {
+        r.set_bound_end();
+        Ok(r.take_chunks(2).collect())
+    });
+    assert_eq!(chunks, [input!(b"aa"), input!(b"bb"), input!(b"c")]);
+    assert!(chunks.iter().all(|chunk| chunk.bound() == Bound::StartEnd));
+}
+
+#[test]
+fn test_take_chunks_str() {
+    let chunks: Vec<_> = read_all_ok!("♥♥ab", |r| Ok(r.take_chunks(2).collect()));
+    assert_eq!(chunks, [input!("♥♥"), input!("ab")]);
+}
+
+#[test]
+fn test_take_chunks_zero_len() {
+    let (count, remaining) = read_partial_ok!(b"aa", |r| Ok(r.take_chunks(0).count()));
+    assert_eq!(count, 0);
+    assert_eq!(remaining, input!(b"aa"));
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::take_while
 
@@ -165,6 +352,38 @@ fn test_take_while_partial() {
     assert_eq!(input.bound(), Bound::StartEnd);
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_while1
+
+#[test]
+fn test_take_while1() {
+    assert_eq!(
+        read_partial_ok!(b"abc!", |r| r
+            .take_while1("letter", |b: u8| b.is_ascii_alphabetic())),
+        (input!(b"abc"), input!(b"!"))
+    );
+    assert_eq!(
+        read_all_ok!(b"abc", |r| r
+            .take_while1("letter", |b: u8| b.is_ascii_alphabetic())),
+        input!(b"abc")
+    );
+}
+
+#[test]
+fn test_take_while1_none() {
+    let error = read_all_err!(b"!", |r| r
+        .take_while1("letter", |b: u8| b.is_ascii_alphabetic()));
+    assert!(error.is_fatal());
+    assert!(error.to_string().contains("expected letter"));
+}
+
+#[test]
+fn test_take_while1_empty() {
+    let error = read_all_err!(b"", |r| r
+        .take_while1("letter", |b: u8| b.is_ascii_alphabetic()));
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::try_take_while
 
@@ -188,6 +407,44 @@ fn test_try_take_while_partial() {
     assert_eq!(input.bound(), Bound::StartEnd);
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::with_error_context
+
+#[test]
+fn test_with_error_context_success_skips_make_context() {
+    let mut called = false;
+    let value = read_all_ok!(b"hello", |r| {
+        r.with_error_context(
+            || {
+                called = true;
+                "greeting"
+            },
+            |r| r.consume(b"hello"),
+        )
+    });
+    assert_eq!(value, ());
+    assert!(!called);
+}
+
+#[test]
+fn test_with_error_context_error() {
+    let mut called = false;
+    let error = read_all_err!(b"hello", |r| {
+        r.with_error_context(
+            || {
+                called = true;
+                "greeting"
+            },
+            |r| r.consume(b"world"),
+        )
+    });
+    assert!(called);
+    #[cfg(feature = "full-backtrace")]
+    assert!(error.to_string().contains("(expected greeting)"));
+    #[cfg(not(feature = "full-backtrace"))]
+    let _ = error;
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::take_consumed
 
@@ -269,6 +526,92 @@ fn test_try_take_consumed_partial_bound() {
     assert_eq!(consumed.bound(), Bound::StartEnd);
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::try_take_consumed_spanned
+
+#[test]
+fn test_try_take_consumed_spanned_node() {
+    #[derive(Debug)]
+    struct Node<'i> {
+        name: Bytes<'i>,
+        span: Span,
+    }
+
+    let source = b"let x = 1;";
+    let (node, _, span) = read_all_ok!(source, |r| {
+        r.consume(b"let ")?;
+        let node = r.try_take_consumed_spanned(|r| {
+            let name = r.take_while(|b: u8| b.is_ascii_alphabetic());
+            r.consume(b" = 1")?;
+            Ok(name)
+        })?;
+        r.consume(b';')?;
+        Ok(node)
+    });
+    let node = Node { name: node, span };
+    assert_eq!(node.name, b"x"[..]);
+    assert_eq!(node.span.range_of(source.as_ref().into()), Some(4..9));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::count_while
+
+#[test]
+fn test_count_while_leading_spaces() {
+    assert_eq!(
+        read_partial_ok!(b"   x", |r| Ok(r.count_while(b' '))),
+        (3, input!(b"   x"))
+    );
+}
+
+#[test]
+fn test_count_while_all() {
+    assert_eq!(read_partial_ok!(b"aaa", |r| Ok(r.count_while(b'a'))).0, 3);
+}
+
+#[test]
+fn test_count_while_none() {
+    assert_eq!(read_partial_ok!(b"xaa", |r| Ok(r.count_while(b'a'))).0, 0);
+}
+
+#[test]
+fn test_count_while_string_counts_chars() {
+    assert_eq!(
+        read_partial_ok!("ééx", |r| Ok(r.count_while(|c: char| c != 'x'))).0,
+        2
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::demand
+
+#[test]
+fn test_demand_enough() {
+    assert_eq!(
+        read_partial_ok!(b"hello", |r| r.demand(5)),
+        ((), input!(b"hello"))
+    );
+}
+
+#[test]
+fn test_demand_too_much() {
+    let error = read_all_err!(b"abc", |r| {
+        r.demand(5)?;
+        r.skip(5)
+    });
+    assert!(!error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(2));
+}
+
+#[test]
+fn test_demand_bound() {
+    let error = dangerous::input(b"abc")
+        .into_bound()
+        .read_all::<_, _, Expected<'_>>(|r| r.demand(5))
+        .unwrap_err();
+    assert!(error.is_fatal());
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::peek
 
@@ -289,6 +632,26 @@ fn test_peek_too_much() {
     });
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::peek_remaining
+
+#[test]
+fn test_peek_remaining() {
+    assert_eq!(
+        read_all_ok!(b"hello", |r| {
+            r.skip(1)?;
+            let v = r.peek_remaining().as_dangerous().ends_with(b"llo");
+            Ok((v, r.take(4)?))
+        }),
+        (true, input!(b"ello"))
+    );
+}
+
+#[test]
+fn test_peek_remaining_empty() {
+    assert!(read_all_ok!(b"", |r| Ok(r.peek_remaining().is_empty())));
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::peek_opt
 
@@ -345,6 +708,65 @@ fn test_peek_eq_longer() {
     });
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::peek_common_prefix
+
+#[test]
+fn test_peek_common_prefix() {
+    let (len, remaining) =
+        read_partial_ok!(b"helicopter", |r| { Ok(r.peek_common_prefix(b"hello")) });
+    assert_eq!(len, 3);
+    assert_eq!(remaining, input!(b"helicopter"));
+}
+
+#[test]
+fn test_peek_common_prefix_none() {
+    assert_eq!(
+        read_partial_ok!(b"abc", |r| Ok(r.peek_common_prefix(b"xyz"))).0,
+        0
+    );
+}
+
+#[test]
+fn test_peek_common_prefix_str_boundary() {
+    // 'é' and 'è' share their first UTF-8 byte.
+    let (len, _) = read_partial_ok!("aé", |r| Ok(r.peek_common_prefix("aè")));
+    assert_eq!(len, 1);
+}
+
+#[test]
+fn test_peek_common_prefix_len() {
+    let len = read_all_ok!(b"helicopter", |r| {
+        let len = r.peek(10)?.common_prefix_len(b"hello");
+        r.take_remaining();
+        Ok(len)
+    });
+    assert_eq!(len, 3);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::peek_match_len
+
+#[test]
+fn test_peek_match_len() {
+    assert_eq!(
+        read_partial_ok!(b"hello", |r| Ok(r.peek_match_len(b"he".as_ref()))),
+        (Some(2), input!(b"hello"))
+    );
+    assert_eq!(
+        read_partial_ok!(b"hello", |r| Ok(r.peek_match_len(b'h'))),
+        (Some(1), input!(b"hello"))
+    );
+    assert_eq!(
+        read_partial_ok!(b"hello", |r| Ok(r.peek_match_len(b"lo".as_ref()))),
+        (None, input!(b"hello"))
+    );
+    assert_eq!(
+        read_partial_ok!(b"", |r| Ok(r.peek_match_len(b'h'))),
+        (None, input!(b""))
+    );
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::consume
 
@@ -528,6 +950,25 @@ fn try_external_err_retry() {
     assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::checkpoint
+
+#[test]
+fn test_checkpoint_restore() {
+    assert_eq!(
+        read_all_ok!(b"hello", |r| {
+            let checkpoint = r.checkpoint();
+            r.consume(b"he")?;
+            r.consume(b"ll")?;
+            r.restore(checkpoint.clone());
+            let first = r.take(2)?;
+            r.restore(checkpoint);
+            r.take(5).map(|all| (first, all))
+        }),
+        (input!(b"he"), input!(b"hello"))
+    );
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::recover
 
@@ -557,6 +998,165 @@ fn test_recover_if_false() {
     let _ = read_all_err!(b"", |r| { r.recover_if(|r| { r.take(1) }, |_| false) });
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::recover_map
+
+#[derive(Debug, PartialEq)]
+struct Skipped(usize);
+
+#[test]
+fn test_recover_map_ok() {
+    assert_eq!(
+        read_all_ok!(b"1", |r| { r.recover_map(|r| r.read(), |_| Skipped(0)) }),
+        Ok(b'1')
+    );
+}
+
+#[test]
+fn test_recover_map_continues() {
+    let (recovered, rest) = read_all_ok!(b"hello", |r| {
+        r.set_bound_end();
+        let recovered =
+            r.recover_map(|r| r.take(10), |e| Skipped(e.backtrace().root().span.len()))?;
+        Ok((recovered, r.take(5)?))
+    });
+    assert_eq!(recovered, Err(Skipped(5)));
+    assert_eq!(rest, input!(b"hello"));
+}
+
+#[test]
+fn test_recover_map_retryable() {
+    let error = read_all_err!(b"hello", |r| {
+        r.recover_map(|r| r.take(10), |_| Skipped(0))
+    });
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(5));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::alt
+
+#[test]
+fn test_alt_second() {
+    let (value, remaining) = read_partial_ok!(b"bc!", |r| {
+        r.alt(&mut [
+            &mut |r: &mut BytesReader<'_, _>| r.consume(b"ab").map(|()| 1),
+            &mut |r: &mut BytesReader<'_, _>| r.consume(b"bc").map(|()| 2),
+            &mut |r: &mut BytesReader<'_, _>| r.consume(b"bc!").map(|()| 3),
+        ])
+    });
+    assert_eq!(value, 2);
+    assert_eq!(remaining, input!(b"!"));
+}
+
+#[test]
+fn test_alt_furthest_error() {
+    let input = b"abd";
+    let error = read_all_err!(input, |r| {
+        r.alt(&mut [
+            &mut |r: &mut BytesReader<'_, _>| r.consume(b"x"),
+            &mut |r: &mut BytesReader<'_, _>| {
+                r.consume(b"ab")?;
+                r.consume(b'c')
+            },
+            &mut |r: &mut BytesReader<'_, _>| r.consume(b"y"),
+        ])
+    });
+    let span = error.backtrace().root().span;
+    assert_eq!(span.range_of(input[..].into()), Some(2..3));
+}
+
+#[test]
+fn test_alt_empty() {
+    let error = read_all_err!(b"a", |r| r.alt::<()>(&mut []));
+    assert!(error.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::optional
+
+#[test]
+fn test_optional_some() {
+    assert_eq!(
+        read_all_ok!(b"ab", |r| { r.optional(|r| r.consume(b"ab")) }),
+        Some(())
+    );
+}
+
+#[test]
+fn test_optional_absent() {
+    assert_eq!(
+        read_partial_ok!(b"a", |r| { r.optional(|r| r.consume(b"ab")) }),
+        (None, input!(b"a"))
+    );
+}
+
+#[test]
+fn test_optional_malformed() {
+    assert!(read_all_err!(b"ac", |r| { r.optional(|r| r.consume(b"ab")) }).is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_n
+
+#[test]
+fn test_read_n() {
+    assert_eq!(
+        read_all_ok!(b"\x00\x01\x00\x02\x00\x03", |r| r
+            .read_n(3, |r| r.read_u16_be())),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        read_partial_ok!(b"a", |r| r.read_n(0, |r| r.read())),
+        (vec![], input!(b"a"))
+    );
+}
+
+#[test]
+fn test_read_n_not_restored() {
+    read_all_ok!(b"abax", |r| {
+        let result = r.read_n(2, |r| {
+            r.consume(b'a')?;
+            r.consume(b'b')
+        });
+        assert!(result.unwrap_err().is_fatal());
+        assert_eq!(r.offset(), 3);
+        r.skip(1)
+    });
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_array_of
+
+#[test]
+fn test_read_array_of() {
+    let values: [u16; 3] = read_all_ok!(b"\x00\x01\x00\x02\x00\x03", |r| r
+        .read_array_of(|r| r.read_u16_be()));
+    assert_eq!(values, [1, 2, 3]);
+}
+
+#[test]
+fn test_read_array_of_too_short() {
+    let error = read_all_err!(b"\x00\x01\x00\x02\x00", |r| r
+        .read_array_of::<_, _, 3>(|r| r.read_u16_be()));
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+#[test]
+fn test_read_array_of_drops_read_values() {
+    let value = std::rc::Rc::new(());
+    read_all_ok!(b"abax", |r| {
+        let result = r.read_array_of::<_, _, 2>(|r| {
+            r.consume(b'a')?;
+            r.consume(b'b')?;
+            Ok(value.clone())
+        });
+        assert!(result.is_err());
+        assert_eq!(r.offset(), 3);
+        r.skip(1)
+    });
+    assert_eq!(std::rc::Rc::strong_count(&value), 1);
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::error
 
@@ -76,6 +76,339 @@ fn test_consume_opt_u8_false() {
     }));
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::consume_any
+
+#[test]
+fn test_consume_any_match() {
+    assert_eq!(
+        read_all_ok!(b"false", |r| {
+            Ok(r.consume_any(&[&b"true"[..], &b"false"[..]]))
+        }),
+        Some(1)
+    );
+}
+
+#[test]
+fn test_consume_any_first_match_wins() {
+    assert_eq!(
+        read_partial_ok!(b"abc", |r| { Ok(r.consume_any(&[&b"a"[..], &b"ab"[..]])) }),
+        (Some(0), input!(b"bc"))
+    );
+}
+
+#[test]
+fn test_consume_any_none() {
+    assert_eq!(
+        read_partial_ok!(b"maybe", |r| {
+            Ok(r.consume_any(&[&b"true"[..], &b"false"[..]]))
+        }),
+        (None, input!(b"maybe"))
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::try_consume_any
+
+#[test]
+fn test_try_consume_any_match() {
+    assert_eq!(
+        read_all_ok!(b"false", |r| {
+            r.try_consume_any(&[&b"true"[..], &b"false"[..]])
+        }),
+        1
+    );
+}
+
+#[test]
+fn test_try_consume_any_fatal() {
+    let error = read_all_err!(b"maybe", |r| {
+        r.try_consume_any(&[&b"true"[..], &b"false"[..]])
+    });
+    assert!(error.is_fatal());
+}
+
+#[test]
+fn test_try_consume_any_incomplete() {
+    let error = read_all_err!(b"fal", |r| {
+        r.try_consume_any(&[&b"true"[..], &b"false"[..]])
+    });
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(2));
+}
+
+#[test]
+fn test_try_consume_any_empty() {
+    let error = read_all_err!(b"true", |r| { r.try_consume_any::<&[u8]>(&[]) });
+    assert!(error.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::bits
+
+#[test]
+fn test_bits_within_byte() {
+    assert_eq!(
+        read_all_ok!(&[0b1010_1100], |r| {
+            let mut bits = r.bits();
+            let fields = (bits.read_bits(3)?, bits.read_bits(5)?);
+            bits.finish()?;
+            Ok(fields)
+        }),
+        (0b101, 0b01100)
+    );
+}
+
+#[test]
+fn test_bits_across_bytes() {
+    assert_eq!(
+        read_partial_ok!(&[0xAB, 0xCD, 0xEF], |r| {
+            let mut bits = r.bits();
+            let fields = (bits.read_bits(4)?, bits.read_bits(8)?, bits.read_bits(4)?);
+            bits.finish()?;
+            Ok(fields)
+        }),
+        ((0xA, 0xBC, 0xD), input!(&[0xEF]))
+    );
+}
+
+#[test]
+fn test_bits_align() {
+    assert_eq!(
+        read_partial_ok!(&[0xFF, 0x01], |r| {
+            let mut bits = r.bits();
+            bits.read_bits(1)?;
+            bits.align();
+            bits.finish()?;
+            r.read()
+        }),
+        (0x01, input!(&[]))
+    );
+}
+
+#[test]
+fn test_bits_not_enough_input() {
+    let error = read_all_err!(&[0xFF], |r| {
+        let mut bits = r.bits();
+        bits.read_bits(4)?;
+        bits.read_bits(8)
+    });
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+#[test]
+fn test_bits_finish_unaligned() {
+    let error = read_all_err!(&[0xFF], |r| {
+        let mut bits = r.bits();
+        bits.read_bits(4)?;
+        bits.finish()
+    });
+    assert!(error.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_u8_in
+
+#[test]
+fn test_read_u8_in() {
+    assert_eq!(
+        read_all_ok!(b"5", |r| { r.read_u8_in("digit", b'0'..=b'9') }),
+        b'5'
+    );
+}
+
+#[test]
+fn test_read_u8_in_reject() {
+    let input = b"ax";
+    let error = read_all_err!(input, |r| {
+        r.skip(1)?;
+        r.read_u8_in("digit", b'0'..=b'9')
+    });
+    assert!(error.is_fatal());
+    let span = error.backtrace().root().span;
+    assert_eq!(span.range_of(input[..].into()), Some(1..2));
+}
+
+#[test]
+fn test_read_u8_in_empty() {
+    let error = read_all_err!(b"", |r| { r.read_u8_in("digit", b'0'..=b'9') });
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_u8_map
+
+#[derive(Debug, PartialEq)]
+enum Tag {
+    Null,
+    Bool,
+}
+
+fn tag(b: u8) -> Option<Tag> {
+    match b {
+        0x00 => Some(Tag::Null),
+        0x01 => Some(Tag::Bool),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_read_u8_map() {
+    assert_eq!(
+        read_partial_ok!(&[0x01, 0xFF], |r| r.read_u8_map("tag", tag)),
+        (Tag::Bool, input!(&[0xFF]))
+    );
+}
+
+#[test]
+fn test_read_u8_map_reject() {
+    let input = &[0x00, 0xFF];
+    let error = read_all_err!(input, |r| {
+        assert_eq!(r.read_u8_map("tag", tag)?, Tag::Null);
+        r.read_u8_map("tag", tag)
+    });
+    assert!(error.is_fatal());
+    let span = error.backtrace().root().span;
+    assert_eq!(span.range_of(input[..].into()), Some(1..2));
+}
+
+#[test]
+fn test_read_u8_map_empty() {
+    let error = read_all_err!(b"", |r| r.read_u8_map("tag", tag));
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_length_prefixed
+
+#[test]
+fn test_read_length_prefixed() {
+    assert_eq!(
+        read_partial_ok!(b"\x05hello world", |r| {
+            r.read_length_prefixed(|r| r.read_u8(), |r| r.take_remaining_str())
+        }),
+        (input!("hello"), input!(b" world"))
+    );
+}
+
+#[test]
+fn test_read_length_prefixed_u16() {
+    assert_eq!(
+        read_all_ok!(b"\x00\x02hi", |r| {
+            r.read_length_prefixed(|r| r.read_u16_be(), |r| r.take_remaining_str())
+        }),
+        input!("hi")
+    );
+}
+
+#[test]
+fn test_read_length_prefixed_u32() {
+    assert_eq!(
+        read_all_ok!(b"\x00\x00\x00\x02hi", |r| {
+            r.read_length_prefixed(|r| r.read_u32_be(), |r| r.take_remaining_str())
+        }),
+        input!("hi")
+    );
+}
+
+#[test]
+fn test_read_length_prefixed_u64() {
+    assert_eq!(
+        read_all_ok!(b"\x02\x00\x00\x00\x00\x00\x00\x00hi", |r| {
+            r.read_length_prefixed(|r| r.read_u64_le(), |r| r.take_remaining_str())
+        }),
+        input!("hi")
+    );
+    let _ = read_all_err!(b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFFhi", |r| {
+        r.read_length_prefixed(|r| r.read_u64_le(), |r| r.take_remaining_str())
+    });
+}
+
+#[test]
+fn test_read_length_prefixed_not_enough_input() {
+    let error = read_all_err!(b"\x05hel", |r| {
+        r.read_length_prefixed(|r| r.read_u8(), |r| r.take_remaining_str())
+    });
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(2));
+}
+
+#[test]
+fn test_read_length_prefixed_inner_overrun() {
+    let error = read_all_err!(b"\x02hello", |r| {
+        r.read_length_prefixed(|r| r.read_u8(), |r| r.take(3))?;
+        r.take(3)
+    });
+    assert!(error.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_balanced
+
+#[test]
+fn test_take_balanced_nested() {
+    assert_eq!(
+        read_partial_ok!(b"(a(b)c)d", |r| r.take_balanced(b'(', b')')),
+        (input!(b"a(b)c"), input!(b"d"))
+    );
+}
+
+#[test]
+fn test_take_balanced_empty() {
+    assert_eq!(
+        read_all_ok!(b"{}", |r| r.take_balanced(b'{', b'}')),
+        input!(b"")
+    );
+}
+
+#[test]
+fn test_take_balanced_not_open() {
+    let error = read_all_err!(b"a(b)", |r| r.take_balanced(b'(', b')'));
+    assert!(error.is_fatal());
+}
+
+#[test]
+fn test_take_balanced_unterminated() {
+    let error = read_all_err!(b"(a(b)c", |r| r.take_balanced(b'(', b')'));
+    assert!(!error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_cstr
+
+#[test]
+fn test_read_cstr() {
+    let (s, remaining) = read_partial_ok!(b"abc\0rest", |r| r.read_cstr());
+    assert_eq!(s, input!(b"abc"));
+    assert_eq!(s.bound(), Bound::StartEnd);
+    assert_eq!(remaining, input!(b"rest"));
+}
+
+#[test]
+fn test_read_cstr_empty() {
+    assert_eq!(read_all_ok!(b"\0", |r| r.read_cstr()), input!(b""));
+}
+
+#[test]
+fn test_read_cstr_unterminated() {
+    let error = read_all_err!(b"abc", |r| r.read_cstr());
+    assert!(!error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+#[test]
+fn test_read_cstr_str() {
+    assert_eq!(
+        read_partial_ok!(b"caf\xc3\xa9\0!", |r| r.read_cstr_str()),
+        (input!("caf\u{e9}"), input!(b"!"))
+    );
+}
+
+#[test]
+fn test_read_cstr_str_invalid() {
+    let error = read_all_err!(b"\xff\0", |r| r.read_cstr_str());
+    assert!(error.is_fatal());
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::take_array
 
@@ -92,6 +425,52 @@ fn test_array_err() {
     let _ = read_all_err!(&[0, 1], |r| { r.take_array::<3>() });
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_byte_array
+
+#[test]
+fn test_read_byte_array_into_array() {
+    let (array, remaining) = input!(&[1, 2, 3, 4, 5])
+        .read_partial::<_, _, Expected<'_>>(|r| r.read_byte_array::<4>())
+        .unwrap();
+    let array: ByteArray<'_, 4> = array;
+    assert_eq!(array, [1, 2, 3, 4][..]);
+    assert_eq!(array.into_array(), [1, 2, 3, 4]);
+    assert_eq!(remaining, [5][..]);
+}
+
+#[test]
+fn test_read_byte_array_borrows_input() {
+    let bytes = [1, 2, 3, 4];
+    let array = read_all_ok!(&bytes, |r| r.read_byte_array::<4>());
+    assert!(core::ptr::eq(array.as_dangerous(), &bytes));
+}
+
+#[test]
+fn test_read_byte_array_short() {
+    let error = read_all_err!(&[1, 2, 3], |r| r.read_byte_array::<4>());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_array
+
+#[test]
+fn test_read_array_uuid() {
+    let uuid = [
+        0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0,
+        0xc8,
+    ];
+    assert_eq!(read_all_ok!(uuid, |r| r.read_array::<16>()), uuid);
+}
+
+#[test]
+fn test_read_array_short() {
+    let error = read_all_err!([0; 15], |r| r.read_array::<16>());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+    assert!(error.to_string().contains("read an array of bytes"));
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::take_array_opt
 
@@ -113,6 +492,320 @@ fn test_array_opt_none() {
     );
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_{num}
+
+#[test]
+fn test_read_num() {
+    assert_eq!(read_all_ok!(b"\x01", |r| r.read_u8()), 1);
+    assert_eq!(read_all_ok!(b"\xff", |r| r.read_i8()), -1);
+    assert_eq!(read_all_ok!(b"\x01\x02", |r| r.read_u16_le()), 0x0201);
+    assert_eq!(read_all_ok!(b"\x01\x02", |r| r.read_u16_be()), 0x0102);
+    assert_eq!(read_all_ok!(b"\xfe\xff\xff\xff", |r| r.read_i32_le()), -2);
+    assert_eq!(
+        read_all_ok!(b"\x01\x02\x03\x04\x05\x06\x07\x08", |r| r.read_u64_be()),
+        0x0102_0304_0506_0708
+    );
+    assert_eq!(read_all_ok!([0xff; 16], |r| r.read_i128_be()), -1);
+    assert_eq!(
+        read_all_ok!(1.5_f32.to_le_bytes(), |r| r.read_f32_le()),
+        1.5
+    );
+    assert_eq!(
+        read_all_ok!(1.5_f64.to_be_bytes(), |r| r.read_f64_be()),
+        1.5
+    );
+}
+
+#[test]
+fn test_read_num_needs_more() {
+    let error = read_all_err!(b"\x01", |r| r.read_u32_le());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(3));
+    assert!(error.to_string().contains("read u32 (little endian)"));
+    assert_eq!(
+        read_all_err!(b"", |r| r.read_u8()).to_retry_requirement(),
+        RetryRequirement::new(1)
+    );
+    assert_eq!(
+        read_all_err!(b"\x01\x02", |r| r.read_u128_be()).to_retry_requirement(),
+        RetryRequirement::new(14)
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_{u,s}leb128
+
+#[test]
+fn test_read_uleb128_u64() {
+    assert_eq!(read_all_ok!([0x00], |r| r.read_uleb128_u64()), 0);
+    assert_eq!(read_all_ok!([0x7F], |r| r.read_uleb128_u64()), 127);
+    assert_eq!(read_all_ok!([0x80, 0x01], |r| r.read_uleb128_u64()), 128);
+    assert_eq!(
+        read_partial_ok!([0xE5, 0x8E, 0x26, 0xFF], |r| r.read_uleb128_u64()),
+        (624_485, input!([0xFF]))
+    );
+    assert_eq!(
+        read_all_ok!(
+            [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01],
+            |r| r.read_uleb128_u64()
+        ),
+        u64::MAX
+    );
+}
+
+#[test]
+fn test_read_sleb128_i64() {
+    assert_eq!(read_all_ok!([0x7F], |r| r.read_sleb128_i64()), -1);
+    assert_eq!(read_all_ok!([0x3F], |r| r.read_sleb128_i64()), 63);
+    assert_eq!(read_all_ok!([0xC0, 0x00], |r| r.read_sleb128_i64()), 64);
+    assert_eq!(
+        read_all_ok!([0xC0, 0xBB, 0x78], |r| r.read_sleb128_i64()),
+        -123_456
+    );
+    assert_eq!(
+        read_all_ok!(
+            [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F],
+            |r| r.read_sleb128_i64()
+        ),
+        i64::MIN
+    );
+}
+
+#[test]
+fn test_read_leb128_needs_more() {
+    assert_eq!(
+        read_all_err!([], |r| r.read_uleb128_u64()).to_retry_requirement(),
+        RetryRequirement::new(1)
+    );
+    assert_eq!(
+        read_all_err!([0x80, 0x80], |r| r.read_sleb128_i64()).to_retry_requirement(),
+        RetryRequirement::new(1)
+    );
+}
+
+#[test]
+fn test_read_leb128_overflow() {
+    let error = read_all_err!([0x80; 11], |r| r.read_uleb128_u64());
+    assert!(error.is_fatal());
+    assert!(error.to_string().contains("read a LEB128 varint"));
+    assert!(read_all_err!(
+        [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02],
+        |r| r.read_uleb128_u64()
+    )
+    .is_fatal());
+    assert!(read_all_err!(
+        [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x40],
+        |r| r.read_sleb128_i64()
+    )
+    .is_fatal());
+    // The final bit is set without being sign extended.
+    assert!(read_all_err!(
+        [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01],
+        |r| r.read_sleb128_i64()
+    )
+    .is_fatal());
+}
+
+#[test]
+fn test_read_leb128_final_byte() {
+    assert_eq!(
+        read_all_ok!(
+            [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01],
+            |r| r.read_uleb128_u64()
+        ),
+        1 << 63
+    );
+    assert_eq!(
+        read_all_ok!(
+            [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F],
+            |r| r.read_sleb128_i64()
+        ),
+        i64::MIN
+    );
+    assert_eq!(
+        read_all_ok!(
+            [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00],
+            |r| r.read_sleb128_i64()
+        ),
+        i64::MAX
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_while_into
+
+#[test]
+fn test_take_while_into() {
+    let mut buf = [0; 5];
+    let (len, remaining) = read_partial_ok!(b"hello!", |r| {
+        r.take_while_into(&mut buf, |b: u8| b.is_ascii_alphabetic())
+    });
+    assert_eq!(&buf[..len], b"hello");
+    assert_eq!(remaining, input!(b"!"));
+}
+
+#[test]
+fn test_take_while_into_exceeds_capacity() {
+    let mut buf = [0; 4];
+    let err = read_partial_err!(b"hello!", |r| {
+        r.take_while_into(&mut buf, |b: u8| b.is_ascii_alphabetic())
+    });
+    assert!(err.is_fatal());
+    assert_eq!(buf, [0; 4]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_until_any
+
+#[test]
+fn test_take_until_any() {
+    let ((taken, delimiter), remaining) =
+        read_partial_ok!(b"a,b;c", |r| { r.take_until_any(b";,") });
+    assert_eq!(taken, b"a"[..]);
+    assert_eq!(delimiter, b',');
+    assert_eq!(remaining, input!(b",b;c"));
+}
+
+#[test]
+fn test_take_until_any_none() {
+    let err = read_partial_err!(b"abc", |r| { r.take_until_any(b";,") });
+    assert!(err.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_until_last
+
+#[test]
+fn test_take_until_last() {
+    let (taken, remaining) = read_partial_ok!(b"a.b.c", |r| { r.take_until_last(b'.') });
+    assert_eq!(taken, b"a.b"[..]);
+    assert_eq!(remaining, input!(b".c"));
+}
+
+#[test]
+fn test_take_until_last_none() {
+    let err = read_partial_err!(b"abc", |r| { r.take_until_last(b'.') });
+    assert!(err.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_until_inclusive
+
+#[test]
+fn test_take_until_inclusive() {
+    let (taken, remaining) =
+        read_partial_ok!(b"line1\nline2", |r| { r.take_until_inclusive(b'\n') });
+    assert_eq!(taken, b"line1\n"[..]);
+    assert_eq!(remaining, input!(b"line2"));
+}
+
+#[test]
+fn test_take_until_inclusive_multi_byte() {
+    let (taken, remaining) =
+        read_partial_ok!(b"a\r\nb", |r| { r.take_until_inclusive(&b"\r\n"[..]) });
+    assert_eq!(taken, b"a\r\n"[..]);
+    assert_eq!(remaining, input!(b"b"));
+}
+
+#[test]
+fn test_take_until_inclusive_none() {
+    let err = read_partial_err!(b"line1", |r| { r.take_until_inclusive(b'\n') });
+    assert!(err.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::skip_until_any
+
+#[test]
+fn test_skip_until_any() {
+    let (delimiter, remaining) = read_partial_ok!(b"ab\nc;", |r| { r.skip_until_any(b";\n") });
+    assert_eq!(delimiter, b'\n');
+    assert_eq!(remaining, input!(b"\nc;"));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::consume_eol
+
+#[test]
+fn test_consume_eol() {
+    assert_eq!(read_all_ok!(b"\n", |r| r.consume_eol()), Eol::Lf);
+    assert_eq!(read_all_ok!(b"\r\n", |r| r.consume_eol()), Eol::CrLf);
+    assert_eq!(
+        read_partial_ok!(b"\n\r\n", |r| r.consume_eol()),
+        (Eol::Lf, input!(b"\r\n"))
+    );
+}
+
+#[test]
+fn test_consume_eol_needs_more() {
+    assert_eq!(
+        read_all_err!(b"", |r| r.consume_eol()).to_retry_requirement(),
+        RetryRequirement::new(1)
+    );
+    assert_eq!(
+        read_all_err!(b"\r", |r| r.consume_eol()).to_retry_requirement(),
+        RetryRequirement::new(1)
+    );
+}
+
+#[test]
+fn test_consume_eol_invalid() {
+    assert!(read_all_err!(b"a", |r| r.consume_eol()).is_fatal());
+    assert!(read_all_err!(b"\ra", |r| r.consume_eol()).is_fatal());
+    assert!(read_all_err!(b"\r", |r| {
+        r.set_bound_end();
+        r.consume_eol()
+    })
+    .is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::consume_newline
+
+#[test]
+fn test_consume_newline() {
+    read_all_ok!(b"\n", |r| r.consume_newline());
+    read_all_ok!(b"\r\n", |r| r.consume_newline());
+    assert_eq!(
+        read_partial_ok!(b"\r\n\n", |r| r.consume_newline()),
+        ((), input!(b"\n"))
+    );
+}
+
+#[test]
+fn test_consume_newline_lone_cr() {
+    assert_eq!(
+        read_all_err!(b"\r", |r| r.consume_newline()).to_retry_requirement(),
+        RetryRequirement::new(1)
+    );
+    assert!(read_all_err!(b"\ra", |r| r.consume_newline()).is_fatal());
+    assert!(read_all_err!(b"\r", |r| {
+        r.set_bound_end();
+        r.consume_newline()
+    })
+    .is_fatal());
+}
+
+#[test]
+fn test_consume_newline_opt() {
+    assert_eq!(
+        read_partial_ok!(b"\r\na", |r| Ok(r.consume_newline_opt())),
+        (true, input!(b"a"))
+    );
+    assert_eq!(
+        read_partial_ok!(b"\na", |r| Ok(r.consume_newline_opt())),
+        (true, input!(b"a"))
+    );
+    assert_eq!(
+        read_partial_ok!(b"\ra", |r| Ok(r.consume_newline_opt())),
+        (false, input!(b"\ra"))
+    );
+    assert_eq!(
+        read_partial_ok!(b"\r", |r| Ok(r.consume_newline_opt())),
+        (false, input!(b"\r"))
+    );
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::peek_read
 
@@ -137,6 +830,40 @@ fn test_peek_read_opt() {
     }));
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_remaining_str
+
+#[test]
+fn test_take_remaining_str() {
+    assert_eq!(
+        read_all_ok!(b"h\xE2\x99\xA5llo", |r| r.take_remaining_str()),
+        "h\u{2665}llo"[..]
+    );
+}
+
+#[test]
+fn test_take_remaining_str_incomplete() {
+    let error = read_all_err!(b"h\xE2\x99", |r| r.take_remaining_str());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+#[test]
+fn test_take_remaining_str_invalid() {
+    assert!(read_all_err!(b"h\xFFllo", |r| r.take_remaining_str()).is_fatal());
+}
+
+#[test]
+fn test_take_remaining_str_recover() {
+    assert_eq!(
+        read_all_ok!(b"h\xFF", |r| {
+            let taken = r.recover(|r| r.take_remaining_str());
+            r.skip(2)?;
+            Ok(taken)
+        }),
+        None
+    );
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::skip_str_while
 
@@ -164,6 +891,29 @@ fn test_take_str_while() {
     );
 }
 
+#[test]
+fn test_take_str_while_bound_before_end() {
+    let (taken, remaining) = read_partial_ok!(b"hello!", |r| {
+        r.take_str_while(|c| c.is_ascii_alphabetic())
+    });
+    assert_eq!(taken, "hello");
+    assert_eq!(taken.bound(), Bound::StartEnd);
+    assert_eq!(remaining, input!(b"!"));
+}
+
+#[test]
+fn test_take_str_while_invalid_utf8_span() {
+    let input = b"hello world\xC2 ";
+    let error = read_all_err!(input, |r| r.take_str_while(|_| true));
+    let root = error.backtrace().root();
+    assert_eq!(root.span.range_of(input[..].into()), Some(11..12));
+    assert_eq!(
+        root.expected,
+        error::CoreExpected::Valid("utf-8 code point")
+    );
+    assert!(error.is_fatal());
+}
+
 #[test]
 fn test_take_str_while_utf8_retry() {
     // Length 1
@@ -208,3 +958,46 @@ fn test_try_take_str_while() {
         "hello"[..]
     );
 }
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_hex_into
+
+#[test]
+fn test_read_hex_into() {
+    let mut buf = [0; 2];
+    read_all_ok!(b"fF00", |r| r.read_hex_into(&mut buf));
+    assert_eq!(buf, [0xff, 0x00]);
+}
+
+#[test]
+fn test_read_hex_into_invalid_digit() {
+    let mut buf = [0; 2];
+    let input = b"0g00";
+    let err = read_all_err!(input, |r| r.read_hex_into(&mut buf));
+    assert!(err.is_fatal());
+    let span = err.backtrace().root().span;
+    assert_eq!(span.range_of(input[..].into()), Some(1..2));
+}
+
+#[test]
+fn test_read_hex_into_too_short() {
+    let mut buf = [0; 2];
+    let err = read_all_err!(b"ff0", |r| r.read_hex_into(&mut buf));
+    assert_eq!(err.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_hex_bytes
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_read_hex_bytes() {
+    assert_eq!(read_all_ok!(b"ff00", |r| r.read_hex_bytes(4)), [0xff, 0x00]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_read_hex_bytes_odd() {
+    let err = read_all_err!(b"fff", |r| r.read_hex_bytes(3));
+    assert!(err.is_fatal());
+}
@@ -35,6 +35,160 @@ fn test_string_input_pretty_debug() {
     })
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Reader::char_offset
+
+#[test]
+fn test_char_offset() {
+    assert_eq!(
+        read_partial_ok!("héllo", |r| {
+            r.take(2)?;
+            Ok((r.offset(), r.char_offset()))
+        }),
+        ((3, 2), input!("llo"))
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::skip_line
+
+#[test]
+fn test_skip_line() {
+    assert_eq!(
+        read_partial_ok!("abc\ndef", |r| Ok(r.skip_line())),
+        (true, input!("def"))
+    );
+    assert_eq!(
+        read_partial_ok!("\r\n", |r| Ok(r.skip_line())),
+        (true, input!(""))
+    );
+    assert_eq!(
+        read_partial_ok!("abc", |r| Ok(r.skip_line())),
+        (false, input!(""))
+    );
+}
+
+#[test]
+fn test_skip_line_recover_after_error() {
+    let values = read_all_ok!("1\nx\n3\n", |r| {
+        let mut values = Vec::new();
+        while !r.at_end() {
+            if let Some(value) = r.recover(|r| {
+                let value = r.read_u64_radix(10)?;
+                r.consume('\n')?;
+                Ok(value)
+            }) {
+                values.push(value);
+            } else {
+                assert!(r.skip_line());
+            }
+        }
+        Ok(values)
+    });
+    assert_eq!(values, [1, 3]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::skip_whitespace
+
+#[test]
+fn test_skip_whitespace() {
+    assert_eq!(
+        read_partial_ok!(" \t\r\n\u{a0}\u{3000}x ", |r| {
+            r.skip_whitespace();
+            Ok(())
+        }),
+        ((), input!("x "))
+    );
+    assert_eq!(
+        read_partial_ok!("x", |r| {
+            r.skip_whitespace();
+            Ok(())
+        }),
+        ((), input!("x"))
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::try_take_while_indexed
+
+fn is_ident_char<E>(i: usize, c: char) -> Result<bool, E> {
+    Ok(c == '_' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit()))
+}
+
+#[test]
+fn test_try_take_while_indexed_ident() {
+    assert_eq!(
+        read_partial_ok!("ab1 c", |r| r.try_take_while_indexed(is_ident_char)),
+        (input!("ab1"), input!(" c"))
+    );
+}
+
+#[test]
+fn test_try_take_while_indexed_leading_digit() {
+    assert_eq!(
+        read_partial_ok!("1ab", |r| r.try_take_while_indexed(is_ident_char)),
+        (input!(""), input!("1ab"))
+    );
+}
+
+#[test]
+fn test_try_take_while_indexed_char_index() {
+    let mut indices = Vec::new();
+    assert_eq!(
+        read_all_ok!("éé", |r| r.try_take_while_indexed(|i, _| {
+            indices.push(i);
+            Ok(true)
+        })),
+        input!("éé")
+    );
+    assert_eq!(indices, [0, 1]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_char_in
+
+#[test]
+fn test_read_char_in() {
+    assert_eq!(
+        read_all_ok!("é", |r| { r.read_char_in("latin-1 letter", 'À'..='ÿ') }),
+        'é'
+    );
+}
+
+#[test]
+fn test_read_char_in_reject() {
+    let input = "aé";
+    let error = read_all_err!(input, |r| { r.read_char_in("digit", '0'..='9') });
+    assert!(error.is_fatal());
+    let span = error.backtrace().root().span;
+    assert_eq!(span.range_of(input.as_bytes().into()), Some(0..1));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_char_map
+
+#[test]
+fn test_read_char_map() {
+    assert_eq!(
+        read_partial_ok!("é!", |r| r
+            .read_char_map("accent", |c| c.to_uppercase().next())),
+        ('É', input!("!"))
+    );
+}
+
+#[test]
+fn test_read_char_map_reject() {
+    let input = "aé";
+    let error = read_all_err!(input, |r| {
+        r.skip(1)?;
+        r.read_char_map("digit", |c| c.to_digit(10))
+    });
+    assert!(error.is_fatal());
+    let span = error.backtrace().root().span;
+    assert_eq!(span.range_of(input.as_bytes().into()), Some(1..3));
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::consume (char)
 
@@ -88,6 +242,22 @@ fn test_peek_read() {
     }));
 }
 
+#[test]
+fn test_peek_read_multi_byte() {
+    assert_eq!(
+        read_partial_ok!("🦀!", |r| r.peek_read()),
+        ('🦀', input!("🦀!"))
+    );
+}
+
+#[test]
+fn test_peek_read_end() {
+    assert_eq!(
+        read_all_err!("", |r| r.peek_read()).to_retry_requirement(),
+        RetryRequirement::new(1)
+    );
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Reader::peek_read_opt
 
@@ -99,3 +269,273 @@ fn test_peek_read_opt() {
         Ok(v)
     }));
 }
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::drain_to_string
+
+#[test]
+fn test_drain_to_string() {
+    let drained = read_all_ok!("h♥llo", |r| {
+        r.consume('h')?;
+        let drained = r.drain_to_string();
+        assert!(r.at_end());
+        Ok(drained)
+    });
+    assert_eq!(drained, "♥llo");
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::parse_remaining
+
+#[test]
+fn test_parse_remaining_ipv4_addr() {
+    assert_eq!(
+        read_all_ok!("1.2.3.4", |r| r
+            .parse_remaining::<std::net::Ipv4Addr>("ipv4 addr")),
+        std::net::Ipv4Addr::new(1, 2, 3, 4)
+    );
+}
+
+#[test]
+fn test_parse_remaining_invalid() {
+    let input = "ip=1.2.3.x";
+    let error = read_all_err!(input, |r| {
+        r.consume("ip=")?;
+        r.parse_remaining::<std::net::Ipv4Addr>("ipv4 addr")
+    });
+    assert!(error.is_fatal());
+    let span = error.backtrace().root().span;
+    assert_eq!(span.range_of(input.as_bytes().into()), Some(3..10));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_percent_decoded
+
+#[test]
+fn test_read_percent_decoded() {
+    assert_eq!(
+        read_all_ok!("a%20b", |r| r.read_percent_decoded('&', false)),
+        b"a b"
+    );
+}
+
+#[test]
+fn test_read_percent_decoded_until() {
+    assert_eq!(
+        read_partial_ok!("a%2Fb&c", |r| r.read_percent_decoded('&', false)),
+        (b"a/b".to_vec(), input!("&c"))
+    );
+}
+
+#[test]
+fn test_read_percent_decoded_plus() {
+    assert_eq!(
+        read_all_ok!("a+b", |r| r.read_percent_decoded('&', false)),
+        b"a+b"
+    );
+    assert_eq!(
+        read_all_ok!("a+b", |r| r.read_percent_decoded('&', true)),
+        b"a b"
+    );
+}
+
+#[test]
+fn test_read_percent_decoded_malformed() {
+    let input = "a%G1";
+    let error = read_all_err!(input, |r| r.read_percent_decoded('&', false));
+    assert!(error.is_fatal());
+    let span = error.backtrace().root().span;
+    assert_eq!(span.of(input), Some("%G1"));
+}
+
+#[test]
+fn test_read_percent_decoded_truncated_at_end() {
+    let error = read_all_err!("%2", |r| r.read_percent_decoded('&', false));
+    assert!(!error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+#[test]
+fn test_read_percent_decoded_truncated_by_until() {
+    let error = read_all_err!("%2&", |r| r.read_percent_decoded('&', false));
+    assert!(error.is_fatal());
+}
+
+#[test]
+fn test_read_percent_decoded_into() {
+    let mut buf = [0; 4];
+    let len = read_all_ok!("%41%42c", |r| r
+        .read_percent_decoded_into(&mut buf, '&', false));
+    assert_eq!(&buf[..len], b"ABc");
+}
+
+#[test]
+fn test_read_percent_decoded_into_too_small() {
+    let mut buf = [0; 2];
+    let error = read_all_err!("%41%42c", |r| {
+        r.read_percent_decoded_into(&mut buf, '&', false)
+    });
+    assert!(error.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_u64_radix
+
+#[test]
+fn test_read_u64_radix() {
+    let (value, remaining) = read_partial_ok!("255x", |r| r.read_u64_radix(10));
+    assert_eq!(value, 255);
+    assert_eq!(remaining, input!("x"));
+    assert_eq!(read_all_ok!("fF", |r| r.read_u64_radix(16)), 255);
+    assert_eq!(
+        read_all_ok!("18446744073709551615", |r| r.read_u64_radix(10)),
+        u64::MAX
+    );
+}
+
+#[test]
+fn test_read_u64_radix_overflow() {
+    let input = "123456789012345678901234567890";
+    let error = read_all_err!(input, |r| r.read_u64_radix(10));
+    assert!(error.is_fatal());
+    let span = error.backtrace().root().span;
+    assert_eq!(span.range_of(input.as_bytes().into()), Some(0..30));
+}
+
+#[test]
+fn test_read_u64_radix_no_digit() {
+    let error = read_all_err!("x", |r| r.read_u64_radix(10));
+    assert!(error.is_fatal());
+    let error = read_all_err!("-1", |r| r.read_u64_radix(10));
+    assert!(error.is_fatal());
+    let error = read_all_err!("", |r| r.read_u64_radix(10));
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_i64_radix
+
+#[test]
+fn test_read_i64_radix() {
+    assert_eq!(read_all_ok!("-42", |r| r.read_i64_radix(10)), -42);
+    assert_eq!(read_all_ok!("+42", |r| r.read_i64_radix(10)), 42);
+    assert_eq!(
+        read_all_ok!("-9223372036854775808", |r| r.read_i64_radix(10)),
+        i64::MIN
+    );
+    assert_eq!(
+        read_all_ok!("9223372036854775807", |r| r.read_i64_radix(10)),
+        i64::MAX
+    );
+}
+
+#[test]
+fn test_read_i64_radix_overflow() {
+    let error = read_all_err!("9223372036854775808", |r| r.read_i64_radix(10));
+    assert!(error.is_fatal());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read_ascii_f64
+
+#[test]
+fn test_read_ascii_f64() {
+    let (value, remaining) = read_partial_ok!("-1.5e3,x", |r| r.read_ascii_f64("float"));
+    assert_eq!(value, -1500.0);
+    assert_eq!(remaining, ",x");
+    assert_eq!(read_all_ok!(".5", |r| r.read_ascii_f64("float")), 0.5);
+}
+
+#[test]
+fn test_read_ascii_f64_exponent_without_digits() {
+    let (value, remaining) = read_partial_ok!("2e+", |r| r.read_ascii_f64("float"));
+    assert_eq!(value, 2.0);
+    assert_eq!(remaining, "e+");
+}
+
+#[test]
+fn test_read_ascii_f64_exponent_rest() {
+    let (value, remaining) = read_partial_ok!("3.14e2rest", |r| r.read_ascii_f64("float"));
+    assert_eq!(value, 314.0);
+    assert_eq!(remaining, "rest");
+}
+
+#[test]
+fn test_read_ascii_f64_trailing_e() {
+    let (value, remaining) = read_partial_ok!("1.5e", |r| r.read_ascii_f64("float"));
+    assert_eq!(value, 1.5);
+    assert_eq!(remaining, "e");
+    let (value, remaining) = read_partial_ok!("1ex", |r| r.read_ascii_f64("float"));
+    assert_eq!(value, 1.0);
+    assert_eq!(remaining, "ex");
+}
+
+#[test]
+fn test_read_ascii_f64_lone_dot() {
+    let error = read_all_err!(".x", |r| r.read_ascii_f64("float"));
+    assert_eq!(error.to_retry_requirement(), None);
+    let error = read_all_err!("-.e1", |r| r.read_ascii_f64("float"));
+    assert_eq!(error.to_retry_requirement(), None);
+    let error = input!(".")
+        .into_bound()
+        .read_all::<_, _, Expected<'_>>(|r| r.read_ascii_f64("float"))
+        .unwrap_err();
+    assert!(error.is_fatal());
+}
+
+#[test]
+fn test_read_ascii_f64_invalid() {
+    let error = read_all_err!("-x", |r| r.read_ascii_f64("float"));
+    assert_eq!(error.to_retry_requirement(), None);
+    assert!(error.to_string().contains("float"));
+}
+
+#[test]
+fn test_read_ascii_f64_needs_more() {
+    let error = read_all_err!("-", |r| r.read_ascii_f64("float"));
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take_digits_grouped
+
+#[test]
+fn test_take_digits_grouped() {
+    assert_eq!(
+        read_all_ok!("1_000_000", |r| r.take_digits_grouped('_')),
+        "1_000_000"[..]
+    );
+    assert_eq!(
+        read_partial_ok!("12,345 x", |r| r.take_digits_grouped(',')),
+        (input!("12,345"), input!(" x"))
+    );
+    assert_eq!(
+        read_partial_ok!("1.5", |r| r.take_digits_grouped(',')),
+        (input!("1"), input!(".5"))
+    );
+}
+
+#[test]
+fn test_take_digits_grouped_misplaced() {
+    let span_of = |input: &'static str| {
+        let error = read_all_err!(input, |r| r.take_digits_grouped('_'));
+        let span = error.backtrace().root().span;
+        span.range_of(input.as_bytes().into())
+    };
+    // Leading
+    assert_eq!(span_of("_1"), Some(0..1));
+    // Doubled
+    assert_eq!(span_of("1__0"), Some(2..3));
+    // Trailing
+    assert_eq!(span_of("1_x"), Some(2..3));
+    // No digits
+    assert_eq!(span_of("x"), Some(0..1));
+}
+
+#[test]
+fn test_take_digits_grouped_needs_more() {
+    let error = read_all_err!("1_", |r| r.take_digits_grouped('_'));
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+    let error = read_all_err!("", |r| r.take_digits_grouped('_'));
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
@@ -0,0 +1,141 @@
+#[macro_use]
+mod common;
+
+use common::*;
+
+// "a😀" with the emoji encoded as the surrogate pair D83D DE00.
+const A_EMOJI_LE: &[u8] = b"a\0\x3D\xD8\x00\xDE";
+const A_EMOJI_BE: &[u8] = b"\0a\xD8\x3D\xDE\x00";
+
+fn utf16_le(bytes: &'static [u8]) -> Utf16<'static> {
+    Utf16::from_le_bytes::<Invalid>(dangerous::input(bytes)).unwrap()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Test debug
+
+#[test]
+fn test_utf16_input_debug() {
+    let input = utf16_le(b"h\0i\0");
+    assert_eq!(
+        format!("{:?}", input),
+        "Utf16 { bound: Start, big_endian: false, value: [68 00 69 00] }"
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Utf16::from_le_bytes / Utf16::from_be_bytes
+
+#[test]
+fn test_from_le_and_be_bytes() {
+    let le = utf16_le(A_EMOJI_LE);
+    let be = Utf16::from_be_bytes::<Invalid>(dangerous::input(A_EMOJI_BE)).unwrap();
+    assert_eq!(le.num_units(), 3);
+    assert_eq!(le, [0x61, 0xD83D, 0xDE00]);
+    assert_eq!(be, [0x61, 0xD83D, 0xDE00]);
+    assert_eq!(le, be);
+    assert!(be.is_big_endian());
+}
+
+#[test]
+fn test_from_bytes_odd_length() {
+    let error = Utf16::from_le_bytes::<Expected<'_>>(dangerous::input(b"a\0b")).unwrap_err();
+    assert!(!error.is_fatal());
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(1));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Input::into_string
+
+#[test]
+fn test_into_string_le() {
+    let error = utf16_le(b"h\0i\0")
+        .into_string::<Expected<'_>>()
+        .unwrap_err();
+    assert!(error.is_fatal());
+}
+
+#[test]
+fn test_into_string_be() {
+    let error = Utf16::from_be_bytes::<Invalid>(dangerous::input(b"\0h\0i"))
+        .unwrap()
+        .into_string::<Expected<'_>>()
+        .unwrap_err();
+    assert!(error.is_fatal());
+}
+
+#[test]
+fn test_into_string_empty() {
+    assert_eq!(utf16_le(b"").into_string::<Invalid>().unwrap(), "");
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::read
+
+#[test]
+fn test_read_first_code_unit() {
+    let (unit, remaining) = utf16_le(A_EMOJI_LE)
+        .read_partial::<_, _, Expected<'_>>(|r| r.read())
+        .unwrap();
+    assert_eq!(unit, 0x61);
+    assert_eq!(remaining, [0xD83D, 0xDE00]);
+}
+
+#[test]
+fn test_read_empty() {
+    let error = utf16_le(b"")
+        .read_all::<_, _, Expected<'_>>(|r| r.read())
+        .unwrap_err();
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(2));
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::take
+
+#[test]
+fn test_take_too_much() {
+    let error = utf16_le(A_EMOJI_LE)
+        .read_all::<_, _, Expected<'_>>(|r| r.take(4))
+        .unwrap_err();
+    assert_eq!(error.to_retry_requirement(), RetryRequirement::new(2));
+}
+
+#[test]
+fn test_take_while_not_surrogate() {
+    let (taken, remaining) = utf16_le(A_EMOJI_LE)
+        .read_partial::<_, _, Expected<'_>>(|r| {
+            Ok(r.take_while(|unit: u16| !(0xD800..=0xDFFF).contains(&unit)))
+        })
+        .unwrap();
+    assert_eq!(taken, [0x61]);
+    assert_eq!(remaining, [0xD83D, 0xDE00]);
+}
+
+#[test]
+fn test_skip_until_code_unit() {
+    let remaining = utf16_le(A_EMOJI_LE)
+        .read_partial::<_, _, Expected<'_>>(|r| Ok(r.skip_until_opt(0xDE00_u16)))
+        .unwrap()
+        .1;
+    assert_eq!(remaining, [0xDE00]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Input::split_at / Input::split_at_byte
+
+#[test]
+fn test_split_at_surrogate_pair_boundary() {
+    let (head, tail) = utf16_le(A_EMOJI_LE).split_at::<Invalid>(2).unwrap();
+    assert_eq!(head, [0x61, 0xD83D]);
+    assert_eq!(tail, [0xDE00]);
+    assert_eq!(head.byte_len(), 4);
+    assert_eq!(head.bound(), Bound::StartEnd);
+}
+
+#[test]
+fn test_split_at_byte_within_code_unit() {
+    let input = utf16_le(A_EMOJI_LE);
+    assert!(input.clone().split_at_byte::<Expected<'_>>(4).is_ok());
+    let error = input.split_at_byte::<Expected<'_>>(3).unwrap_err();
+    assert!(error.is_fatal());
+}
@@ -0,0 +1,80 @@
+#[macro_use]
+mod common;
+
+use common::*;
+use serde_json::json;
+
+///////////////////////////////////////////////////////////////////////////////
+// Span
+
+#[test]
+fn test_span_serialize_in() {
+    let root = &b"hello world"[..];
+    let span = Span::from(&root[6..]);
+
+    assert_eq!(
+        serde_json::to_value(span.serialize_in(root.into())).unwrap(),
+        json!({ "start": 6, "len": 5 })
+    );
+}
+
+#[test]
+fn test_span_serialize_not_within_root() {
+    let root = &b"hello world"[..];
+    let other = &b"world"[..];
+
+    assert!(serde_json::to_value(Span::from(other).serialize_in(root.into())).is_err());
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Details
+
+#[test]
+fn test_serialize_expected_value() {
+    let error = input!(b"hello world")
+        .read_all::<_, _, Expected<'_>>(|r| {
+            r.context("greeting", |r| {
+                r.consume(b"hello")?;
+                r.consume(b" there")
+            })
+        })
+        .unwrap_err();
+
+    assert_eq!(
+        serde_json::to_value(SerializeDetails::new(&error)).unwrap(),
+        json!({
+            "description": "found a different value to the exact expected",
+            "expected": [32, 116, 104, 101, 114, 101],
+            "retry_requirement": null,
+            "span": { "start": 5, "len": 6 },
+            "backtrace": [
+                { "operation": "read all input", "expected": null },
+                { "operation": "<context>", "expected": "greeting" },
+                { "operation": "consume input", "expected": "exact value" },
+            ],
+        })
+    );
+}
+
+#[test]
+fn test_serialize_expected_str_value() {
+    let error = input!("hello")
+        .read_all::<_, _, Expected<'_>>(|r| r.consume("world"))
+        .unwrap_err();
+
+    let value = serde_json::to_value(SerializeDetails::new(&error)).unwrap();
+
+    assert_eq!(value["expected"], json!("world"));
+    assert_eq!(value["span"], json!({ "start": 0, "len": 5 }));
+}
+
+#[test]
+fn test_serialize_retry_requirement() {
+    let error = input!(b"hel")
+        .read_all::<_, _, Expected<'_>>(|r| r.consume(b"hello"))
+        .unwrap_err();
+
+    let value = serde_json::to_value(SerializeDetails::new(&error)).unwrap();
+
+    assert_eq!(value["retry_requirement"], json!(2));
+}
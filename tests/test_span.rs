@@ -56,6 +56,29 @@ fn test_of_input_string() {
     assert_eq!(non_span.of(parent), None);
 }
 
+#[test]
+fn test_slice_of_failed_consume() {
+    let input = &b"hello world"[..];
+    let parent = dangerous::input(input);
+    let error = read_all_err!(input, |r| {
+        r.consume(b"hello ")?;
+        r.consume(b"there")
+    });
+    let span = error.backtrace().root().span;
+    let sub = span.slice_of(&parent).unwrap();
+    assert_eq!(sub, b"world"[..]);
+    assert_eq!(sub.span(), span);
+}
+
+#[test]
+fn test_slice_of_string_char_boundary() {
+    let parent = dangerous::input("♥♥");
+    let span = Span::from(&parent.as_dangerous().as_bytes()[0..1]);
+    assert_eq!(span.slice_of(&parent), None);
+    let span = Span::from(&parent.as_dangerous()[3..]);
+    assert_eq!(span.slice_of(&parent).unwrap(), "♥");
+}
+
 #[test]
 fn test_of_input_string_invalid() {
     let parent = dangerous::input("♥♥");
@@ -66,3 +89,119 @@ fn test_of_input_string_invalid() {
     let non_span = Span::from(&parent.as_dangerous().as_bytes()[0..1]);
     assert_eq!(non_span.of(parent), None);
 }
+
+#[test]
+fn test_line_column_in_start() {
+    let parent = dangerous::input("hello\nworld");
+
+    let span = parent.span().start();
+    assert_eq!(span.line_column_in(&parent), Some((1, 1)));
+}
+
+#[test]
+fn test_line_column_in_after_trailing_newline() {
+    let parent = dangerous::input("hello\n");
+
+    let span = parent.span().end();
+    assert_eq!(span.line_column_in(&parent), Some((2, 1)));
+}
+
+#[test]
+fn test_line_column_in_string_counts_chars() {
+    let parent = dangerous::input("a\n♥♥b");
+
+    let sub = &parent.as_dangerous()[8..];
+    assert_eq!(Span::from(sub).line_column_in(&parent), Some((2, 3)));
+}
+
+#[test]
+fn test_line_column_in_bytes_counts_bytes() {
+    let parent = dangerous::input("a\n♥♥b".as_bytes());
+
+    let sub = &parent.as_dangerous()[8..];
+    assert_eq!(Span::from(sub).line_column_in(&parent), Some((2, 7)));
+}
+
+#[test]
+fn test_line_column_in_invalid() {
+    let parent = dangerous::input("♥♥");
+
+    let non_boundary = Span::from(&parent.as_dangerous().as_bytes()[1..]);
+    assert_eq!(non_boundary.line_column_in(&parent), None);
+
+    let non_span = Span::from("♥♥♥");
+    assert_eq!(non_span.line_column_in(&parent), None);
+}
+
+#[test]
+fn test_offset_in() {
+    let parent = input!(b"hello world");
+    let span = Span::from(&parent.as_dangerous()[6..]);
+    assert_eq!(span.offset_in(&parent), Some(6));
+    assert_eq!(Span::from(&b"other"[..]).offset_in(&parent), None);
+}
+
+#[test]
+fn test_as_range_usize() {
+    let parent = input!(b"hello world");
+    let span = Span::from(&parent.as_dangerous()[1..5]);
+    assert_eq!(span.as_range_usize(&parent), Some(1..5));
+    assert_eq!(
+        format!("{:?}", span.as_range_usize(&parent).unwrap()),
+        "1..5"
+    );
+    assert_eq!(Span::from(&b"other"[..]).as_range_usize(&parent), None);
+}
+
+#[test]
+fn test_display() {
+    let bytes = &b"hello"[..];
+    let span = Span::from(&bytes[1..5]);
+    assert_eq!(
+        span.to_string(),
+        format!("(ptr: {}, len: 4)", bytes[1..].as_ptr() as usize)
+    );
+}
+
+#[test]
+fn test_union_adjacent() {
+    let bytes = b"abcdef";
+    let a = Span::from(&bytes[0..3]);
+    let b = Span::from(&bytes[3..6]);
+    assert_eq!(a.union(b), Span::from(&bytes[..]));
+    assert_eq!(b.union(a), Span::from(&bytes[..]));
+}
+
+#[test]
+fn test_union_overlapping() {
+    let bytes = b"abcdef";
+    let a = Span::from(&bytes[1..4]);
+    let b = Span::from(&bytes[2..5]);
+    assert_eq!(a.union(b), Span::from(&bytes[1..5]));
+    assert_eq!(a.union(a), a);
+}
+
+#[test]
+fn test_intersection_adjacent() {
+    let bytes = b"abcdef";
+    let a = Span::from(&bytes[0..3]);
+    let b = Span::from(&bytes[3..6]);
+    assert_eq!(a.intersection(b), Some(Span::from(&bytes[3..3])));
+}
+
+#[test]
+fn test_intersection_overlapping() {
+    let bytes = b"abcdef";
+    let a = Span::from(&bytes[1..4]);
+    let b = Span::from(&bytes[2..5]);
+    assert_eq!(a.intersection(b), Some(Span::from(&bytes[2..4])));
+    assert_eq!(b.intersection(a), Some(Span::from(&bytes[2..4])));
+}
+
+#[test]
+fn test_intersection_disjoint() {
+    let bytes = b"abcdef";
+    let a = Span::from(&bytes[0..2]);
+    let b = Span::from(&bytes[4..6]);
+    assert_eq!(a.intersection(b), None);
+}
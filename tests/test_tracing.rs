@@ -0,0 +1,152 @@
+#[macro_use]
+mod common;
+
+use common::*;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+///////////////////////////////////////////////////////////////////////////////
+// Span recorder
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct ContextSpan {
+    name: &'static str,
+    operation: std::string::String,
+    expected: Option<std::string::String>,
+    offset: Option<u64>,
+}
+
+impl Visit for ContextSpan {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "offset" {
+            self.offset = Some(value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "operation" => self.operation = format!("{:?}", value),
+            "expected" => self.expected = Some(format!("{:?}", value)),
+            _ => {}
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+struct Recorder {
+    spans: Arc<Mutex<Vec<ContextSpan>>>,
+}
+
+impl Recorder {
+    fn spans(&self) -> Vec<ContextSpan> {
+        self.spans.lock().unwrap().clone()
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+        let mut span = ContextSpan {
+            name: attrs.metadata().name(),
+            ..ContextSpan::default()
+        };
+        attrs.record(&mut span);
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(span);
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, id: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        values.record(&mut spans[id.into_u64() as usize - 1]);
+    }
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn record<F: FnOnce()>(f: F) -> Vec<ContextSpan> {
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), f);
+    recorder.spans()
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::context
+
+#[test]
+fn test_context_spans_nested() {
+    let spans = record(|| {
+        read_all_ok!(b"ab", |r| {
+            r.context("outer", |r| {
+                r.read()?;
+                r.context("inner", |r| r.read())
+            })
+        });
+    });
+    assert_eq!(
+        spans,
+        [
+            ContextSpan {
+                name: "context",
+                operation: "read all input".into(),
+                expected: None,
+                offset: Some(0),
+            },
+            ContextSpan {
+                name: "context",
+                operation: "<context>".into(),
+                expected: Some("outer".into()),
+                offset: Some(0),
+            },
+            ContextSpan {
+                name: "context",
+                operation: "<context>".into(),
+                expected: Some("inner".into()),
+                offset: Some(1),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_context_span_without_expected() {
+    let context = ExternalContext::<_, &str> {
+        operation: Some("decode header"),
+        expected: None,
+    };
+    let spans = record(|| {
+        read_all_ok!(b"a", |r| r.context(context, |r| r.read()));
+    });
+    assert_eq!(spans.len(), 2);
+    assert_eq!(spans[1].operation, "decode header");
+    assert_eq!(spans[1].expected, None);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+// Reader::peek_context
+
+#[test]
+fn test_peek_context_span() {
+    let spans = record(|| {
+        read_all_ok!(b"a", |r| {
+            r.peek_context("peek", |r| r.peek_read())?;
+            r.read()
+        });
+    });
+    assert_eq!(spans.len(), 2);
+    assert_eq!(spans[1].expected.as_deref(), Some("peek"));
+    assert_eq!(spans[1].offset, Some(0));
+}